@@ -0,0 +1,83 @@
+use soroban_sdk::Env;
+
+use crate::storage;
+use crate::types::ConfigUpdate;
+
+// ============================================================================
+// Configuration Updates
+// ============================================================================
+// Shared by `lib::update_config` (single-admin) and `proposals::execute`'s
+// `ProposalAction::UpdateConfig` (FP-weighted vote) - the two paths differ
+// only in how they're authorized, not in what they do to `Config`.
+
+/// Apply `update`'s fields to the stored `Config`, leaving any `None` field
+/// unchanged.
+pub(crate) fn apply_update(env: &Env, update: &ConfigUpdate) {
+    let mut config = storage::get_config(env);
+
+    if let Some(blnd) = &update.new_blnd_token {
+        config.blnd_token = blnd.clone();
+    }
+    if let Some(usdc) = &update.new_usdc_token {
+        config.usdc_token = usdc.clone();
+    }
+    if let Some(duration) = update.new_epoch_duration {
+        config.epoch_duration = duration;
+    }
+    if let Some(reserve_ids) = &update.new_reserve_token_ids {
+        config.reserve_token_ids = reserve_ids.clone();
+    }
+    if let Some(slippage_bps) = update.new_slippage_bps {
+        config.slippage_bps = slippage_bps;
+    }
+    if let Some(unbonding_epochs) = update.new_unbonding_epochs {
+        config.unbonding_epochs = unbonding_epochs;
+    }
+    if let Some(min_deposit) = update.new_min_deposit {
+        config.min_deposit = min_deposit;
+    }
+    if let Some(min_deposit_add) = update.new_min_deposit_add {
+        config.min_deposit_add = min_deposit_add;
+    }
+    if let Some(max_total_deposited) = update.new_max_total_deposited {
+        config.max_total_deposited = max_total_deposited;
+    }
+    if let Some(late_deposit_cutoff_bps) = update.new_late_deposit_cutoff_bps {
+        config.late_deposit_cutoff_bps = late_deposit_cutoff_bps;
+    }
+    if let Some(price_staleness_window) = update.new_price_staleness_window {
+        config.price_staleness_window = price_staleness_window;
+    }
+    if let Some(cycle_bounty_bps) = update.new_cycle_bounty_bps {
+        config.cycle_bounty_bps = cycle_bounty_bps;
+    }
+    if let Some(max_players) = update.new_max_players {
+        config.max_players = max_players;
+    }
+    if let Some(min_cycle_interval) = update.new_min_cycle_interval {
+        config.min_cycle_interval = min_cycle_interval;
+    }
+    if let Some(max_price_deviation_bps) = update.new_max_price_deviation_bps {
+        config.max_price_deviation_bps = max_price_deviation_bps;
+    }
+    if let Some(min_standings_balance) = update.new_min_standings_balance {
+        config.min_standings_balance = min_standings_balance;
+    }
+    if let Some(epoch_quorum) = update.new_epoch_quorum {
+        config.epoch_quorum = epoch_quorum;
+    }
+    if let Some(vesting_epochs) = update.new_vesting_epochs {
+        config.vesting_epochs = vesting_epochs;
+    }
+    if let Some(leaderboard_size) = update.new_leaderboard_size {
+        config.leaderboard_size = leaderboard_size;
+    }
+    if let Some(dispute_window_secs) = update.new_dispute_window_secs {
+        config.dispute_window_secs = dispute_window_secs;
+    }
+    if let Some(game_auto_slash_threshold) = update.new_game_auto_slash_threshold {
+        config.game_auto_slash_threshold = game_auto_slash_threshold;
+    }
+
+    storage::set_config(env, &config);
+}
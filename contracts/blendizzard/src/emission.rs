@@ -0,0 +1,117 @@
+use soroban_sdk::Env;
+
+use crate::errors::Error;
+use crate::storage;
+
+// ============================================================================
+// Emission Schedule
+// ============================================================================
+// The per-epoch reward bonus on top of swapped BLND -> USDC yield. Expressed
+// as an annual rate that halves every `halving_interval_epochs`, so a fixed
+// front-loaded incentive tapers off deterministically as epochs pass. Since
+// an epoch is a fixed number of seconds rather than a fixed number of
+// calendar days, its bonus is `annual_reward_after_halving * epoch_seconds /
+// (days_in_year * seconds_per_day)` - computed per calendar year and summed
+// across however many years the epoch's seconds span, so the leap-year day
+// count (365 vs 366) used is always the one in effect for the seconds
+// actually being priced.
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Per-epoch emission bonus for `epoch_id`, on top of swapped yield.
+///
+/// # Errors
+/// * `EmissionNotConfigured` - If no `EmissionConfig` has been set
+/// * `EpochNotFinalized` - If the epoch doesn't exist yet
+pub(crate) fn get_epoch_emission(env: &Env, epoch_id: u32) -> Result<i128, Error> {
+    let emission_config = storage::get_emission_config(env).ok_or(Error::EmissionNotConfigured)?;
+    let epoch_info = storage::get_epoch(env, epoch_id).ok_or(Error::EpochNotFinalized)?;
+    let config = storage::get_config(env);
+
+    let halved_reward = halve(
+        emission_config.annual_reward,
+        epoch_id,
+        emission_config.halving_interval_epochs,
+    );
+    if halved_reward == 0 {
+        return Ok(0);
+    }
+
+    Ok(prorated_reward(
+        halved_reward,
+        epoch_info.start_time,
+        config.epoch_duration,
+    ))
+}
+
+/// Halve `amount` once per `halving_interval_epochs` epochs that have
+/// elapsed by `epoch_id`. `halving_interval_epochs == 0` disables halving.
+fn halve(amount: i128, epoch_id: u32, halving_interval_epochs: u32) -> i128 {
+    if halving_interval_epochs == 0 {
+        return amount;
+    }
+    let halvings = epoch_id / halving_interval_epochs;
+    // Beyond ~127 halvings the reward is already zero; cap the shift so it
+    // can't panic on a very large epoch id.
+    amount >> halvings.min(127)
+}
+
+/// `annual_reward * epoch_seconds / (days_in_year * seconds_per_day)`, split
+/// at each calendar year boundary the epoch's seconds span so each segment
+/// is priced against its own year's day count.
+fn prorated_reward(annual_reward: i128, start_time: u64, epoch_duration: u64) -> i128 {
+    let mut remaining = epoch_duration as i64;
+    let mut cursor = start_time as i64;
+    let mut total = 0i128;
+
+    while remaining > 0 {
+        let (year, _, _) = civil_from_days(cursor.div_euclid(SECONDS_PER_DAY));
+        let next_year_start = days_from_civil(year + 1, 1, 1) * SECONDS_PER_DAY;
+        let segment = remaining.min((next_year_start - cursor).max(1));
+
+        let days = days_in_year(year) as i128;
+        total += annual_reward * segment as i128 / (days * SECONDS_PER_DAY as i128);
+
+        cursor += segment;
+        remaining -= segment;
+    }
+
+    total
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_year(year: i64) -> u64 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Howard Hinnant's `civil_from_days`: unix day count -> (year, month, day).
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// Howard Hinnant's `days_from_civil`: (year, month, day) -> unix day count.
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let mp = if m > 2 { m as i64 - 3 } else { m as i64 + 9 }; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
@@ -0,0 +1,322 @@
+use soroban_sdk::{token, Address, Env};
+
+use crate::emission;
+use crate::errors::Error;
+use crate::events::{emit_cycle_bounty_paid, emit_epoch_rotated, emit_yield_swap_deferred, EpochFinalized};
+use crate::rewards;
+use crate::storage;
+use crate::types::{
+    Config, EpochInfo, EpochSummary, Faction, FinalizationCursor, FinalizationPhase, PointValue,
+    BPS_DENOM,
+};
+
+// ============================================================================
+// Epoch Lifecycle
+// ============================================================================
+
+/// Create and store epoch 0, started at the current ledger time.
+pub(crate) fn initialize_first_epoch(env: &Env, _epoch_duration: u64) {
+    let epoch_info = blank_epoch(env, 0);
+    storage::set_epoch(env, 0, &epoch_info).expect("epoch 0 cannot already be sealed");
+    storage::set_current_epoch(env, 0);
+}
+
+fn blank_epoch(env: &Env, epoch_id: u32) -> EpochInfo {
+    EpochInfo {
+        epoch_id,
+        start_time: env.ledger().timestamp(),
+        finalized: false,
+        winning_faction: None,
+        faction_standings: [0u128; Faction::COUNT as usize],
+        reward_pool: PointValue::ZERO,
+    }
+}
+
+/// Get epoch information
+///
+/// # Errors
+/// * `EpochNotFinalized` - If requested epoch doesn't exist
+pub(crate) fn get_epoch(env: &Env, epoch: Option<u32>) -> Result<EpochInfo, Error> {
+    let epoch_id = epoch.unwrap_or_else(|| storage::get_current_epoch(env));
+    storage::get_epoch(env, epoch_id).ok_or(Error::EpochNotFinalized)
+}
+
+/// Get a finalized epoch's archived summary (winner, pool, per-faction
+/// totals, participant count) - unlike `get_epoch`, this reads from
+/// permanent storage, so it stays available long after `EpochInfo`'s
+/// temporary entry for the same epoch could have expired.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If `epoch_number` hasn't finalized yet (or doesn't exist)
+pub(crate) fn get_epoch_summary(env: &Env, epoch_number: u32) -> Result<EpochSummary, Error> {
+    storage::get_epoch_summary(env, epoch_number).ok_or(Error::EpochNotFinalized)
+}
+
+/// Whether an epoch is due to end: `ledger.timestamp >= epoch_start + epoch_duration`,
+/// and - if `config.min_cycle_interval` is set - at least that many seconds
+/// have also elapsed since `epoch_start`. The latter is a floor under
+/// `epoch_duration` rather than an independent timer, guarding against a
+/// misconfigured/too-low `epoch_duration` letting `cycle_epoch` be called
+/// more often than intended.
+///
+/// Shared by `cycle_epoch` (to decide whether it may act) and anyone probing
+/// `get_epoch` client-side to know when it's worth calling.
+pub(crate) fn should_end_epoch(env: &Env, epoch: &EpochInfo, config: &Config) -> bool {
+    if epoch.finalized {
+        return false;
+    }
+    let now = env.ledger().timestamp();
+    let min_elapsed = config.epoch_duration.max(config.min_cycle_interval);
+    now >= epoch.start_time + min_elapsed
+}
+
+/// Maximum `FinalizationPhase` steps `cycle_epoch` advances in one call.
+/// There are only four real phases (`TallyingFactions`, `WithdrawingYield`,
+/// `Swapping`/`SettingPool` depending on whether yield was claimed), so this
+/// comfortably finishes a finalization that starts and ends in the same
+/// transaction - the case every existing small-scale caller relies on -
+/// while still leaving `cycle_epoch_step` available for a keeper that wants
+/// to spread a heavier finalization across several transactions instead.
+const CYCLE_EPOCH_STEP_BUDGET: u32 = 8;
+
+/// Convenience wrapper around `cycle_epoch_step`: runs its phases
+/// back-to-back within `CYCLE_EPOCH_STEP_BUDGET`, so a straightforward
+/// caller doesn't need to know about `FinalizationCursor` at all.
+///
+/// Permissionless - anyone may call this once `should_end_epoch` is true, and
+/// `caller` earns `config.cycle_bounty_bps` of the swapped reward pool for
+/// doing so (see `FinalizationPhase::SettingPool`), so timely cycling
+/// doesn't depend on the admin being online.
+///
+/// # Errors
+/// * `EpochNotReady` - If `should_end_epoch` is false and no finalization is already in progress
+/// * `EpochAlreadyFinalized` - If current epoch is already finalized
+/// * `EpochFinalizationInProgress` - If finalization didn't reach `Done` within this call's budget
+/// * `FeeVaultError` - If fee-vault operations fail
+/// * `SwapError` - If the BLND -> USDC swap fails
+pub(crate) fn cycle_epoch(env: &Env, caller: &Address) -> Result<u32, Error> {
+    let still_in_progress = cycle_epoch_step(env, caller, CYCLE_EPOCH_STEP_BUDGET)?;
+    if still_in_progress {
+        return Err(Error::EpochFinalizationInProgress);
+    }
+    Ok(storage::get_current_epoch(env))
+}
+
+/// Advance the current epoch's finalization by at most `max_items`
+/// `FinalizationPhase` steps, so a keeper can spread a finalization across
+/// multiple transactions instead of risking it exhausting a single
+/// transaction's budget.
+///
+/// Starts a fresh `FinalizationCursor` at `TallyingFactions` if
+/// `should_end_epoch` is true and no cursor is already in flight, or resumes
+/// the existing one otherwise. (1) snapshots the current
+/// `get_faction_standings` into the finalized, immutable `EpochInfo`, (2)
+/// determines the winning faction and freezes its `PointValue` reward round,
+/// (3) opens the next epoch, whose fresh `EpochPlayer` records implicitly
+/// reset every `epoch_faction` lock so pending `select_faction` choices take
+/// effect, and (4) emits `EpochRotated`. Claims against the finished epoch
+/// then read this frozen snapshot rather than live state, so
+/// `claim_epoch_reward` is deterministic no matter when it's called.
+///
+/// Returns whether a cursor is still in flight after this call - `true`
+/// means finalization hasn't reached `FinalizationPhase::Done` yet and this
+/// must be called again (by anyone; the cursor doesn't lock to `caller`) to
+/// keep advancing it.
+///
+/// # Errors
+/// * `EpochNotReady` - If `should_end_epoch` is false and no finalization is already in progress
+/// * `EpochAlreadyFinalized` - If current epoch is already finalized
+/// * `FeeVaultError` - If fee-vault operations fail
+/// * `SwapError` - If the BLND -> USDC swap fails
+pub(crate) fn cycle_epoch_step(env: &Env, caller: &Address, max_items: u32) -> Result<bool, Error> {
+    caller.require_auth();
+
+    let mut cursor = match storage::get_finalization_cursor(env) {
+        Some(cursor) => cursor,
+        None => {
+            let current_epoch_id = storage::get_current_epoch(env);
+            let current =
+                storage::get_epoch(env, current_epoch_id).ok_or(Error::EpochNotFinalized)?;
+            if current.finalized {
+                return Err(Error::EpochAlreadyFinalized);
+            }
+
+            let config = storage::get_config(env);
+            if !should_end_epoch(env, &current, &config) {
+                return Err(Error::EpochNotReady);
+            }
+
+            FinalizationCursor {
+                epoch_id: current_epoch_id,
+                phase: FinalizationPhase::TallyingFactions,
+            }
+        }
+    };
+
+    for _ in 0..max_items {
+        if cursor.phase == FinalizationPhase::Done {
+            break;
+        }
+        advance_phase(env, &mut cursor, caller)?;
+    }
+
+    let still_in_progress = cursor.phase != FinalizationPhase::Done;
+    if still_in_progress {
+        storage::set_finalization_cursor(env, &cursor);
+    } else {
+        storage::clear_finalization_cursor(env);
+    }
+
+    Ok(still_in_progress)
+}
+
+/// Run one `FinalizationPhase` of `cursor` forward, advancing `cursor.phase`
+/// to whatever comes next.
+fn advance_phase(env: &Env, cursor: &mut FinalizationCursor, caller: &Address) -> Result<(), Error> {
+    let config = storage::get_config(env);
+    let mut epoch = storage::get_epoch(env, cursor.epoch_id).ok_or(Error::EpochNotFinalized)?;
+
+    cursor.phase = match &cursor.phase {
+        FinalizationPhase::TallyingFactions => {
+            let standings = storage::get_faction_standings(env, cursor.epoch_id);
+            epoch.faction_standings = standings;
+
+            // Below `config.epoch_quorum` distinct roster participants,
+            // don't let whoever showed up declare a winner - this is what
+            // closes the "attack of the clones" gap a quorum-less standings
+            // race leaves open even with
+            // `faction_points::standings_weight_bps` damping individual
+            // contributions.
+            let quorum_met = storage::roster_len(env, cursor.epoch_id) >= config.epoch_quorum;
+            epoch.winning_faction = if quorum_met { winning_faction_of(&standings) } else { None };
+            storage::set_epoch(env, cursor.epoch_id, &epoch)?;
+
+            FinalizationPhase::WithdrawingYield
+        }
+        FinalizationPhase::WithdrawingYield => {
+            match rewards::withdraw_yield(env, &config, cursor.epoch_id)? {
+                Some((reference_price, yield_claimed)) => FinalizationPhase::Swapping {
+                    reference_price,
+                    yield_claimed,
+                },
+                None => {
+                    emit_yield_swap_deferred(env, cursor.epoch_id);
+                    FinalizationPhase::SettingPool { reward_pool_usdc: 0 }
+                }
+            }
+        }
+        FinalizationPhase::Swapping {
+            reference_price,
+            yield_claimed,
+        } => {
+            let reward_pool_usdc =
+                rewards::swap_withdrawn_yield(env, &config, *reference_price, *yield_claimed)?;
+            FinalizationPhase::SettingPool { reward_pool_usdc }
+        }
+        FinalizationPhase::SettingPool { reward_pool_usdc } => {
+            settle_reward_pool(env, &config, &mut epoch, *reward_pool_usdc, caller)?;
+            rotate_to_next_epoch(env, &epoch)?;
+            FinalizationPhase::Done
+        }
+        FinalizationPhase::Done => FinalizationPhase::Done,
+    };
+
+    Ok(())
+}
+
+/// Pay `caller`'s cycle bounty, fold in emission/dust, and freeze the reward
+/// pool for `epoch` with whatever's left - the first half of
+/// `FinalizationPhase::SettingPool`.
+fn settle_reward_pool(
+    env: &Env,
+    config: &Config,
+    epoch: &mut EpochInfo,
+    reward_pool_usdc: i128,
+    caller: &Address,
+) -> Result<(), Error> {
+    let bounty = reward_pool_usdc
+        .checked_mul(config.cycle_bounty_bps as i128)
+        .and_then(|v| v.checked_div(BPS_DENOM as i128))
+        .ok_or(Error::OverflowError)?;
+    if bounty > 0 {
+        token::Client::new(env, &config.usdc_token).transfer(
+            &env.current_contract_address(),
+            caller,
+            &bounty,
+        );
+        emit_cycle_bounty_paid(env, caller, bounty);
+    }
+
+    let carried_dust = storage::take_pending_dust_carry(env);
+    // An unconfigured emission schedule just contributes no bonus - it's an
+    // optional top-up on swapped yield, not a precondition for cycling.
+    let emission_bonus = emission::get_epoch_emission(env, epoch.epoch_id).unwrap_or(0);
+    let total_rewards = reward_pool_usdc - bounty + carried_dust + emission_bonus;
+
+    epoch.reward_pool = match epoch.winning_faction {
+        Some(faction) => PointValue {
+            rewards: total_rewards,
+            points: epoch.faction_standings[faction as usize],
+        },
+        // No winner (nobody contributed, or quorum wasn't met) - nobody
+        // could ever pass `claim_epoch_reward`'s `winning_faction` check
+        // against this epoch, so rather than leaving `total_rewards` stuck
+        // unclaimable in this epoch's frozen pool, carry it forward the same
+        // way rounding dust already is, to be picked up by whichever future
+        // epoch finalizes with an actual winner.
+        None => {
+            storage::add_pending_dust_carry(env, total_rewards);
+            PointValue::ZERO
+        }
+    };
+    epoch.finalized = true;
+    storage::set_epoch(env, epoch.epoch_id, epoch)?;
+
+    let summary = EpochSummary {
+        epoch_number: epoch.epoch_id,
+        winning_faction: epoch.winning_faction,
+        reward_pool: epoch.reward_pool.rewards,
+        faction_standings: epoch.faction_standings,
+        participant_count: storage::roster_len(env, epoch.epoch_id),
+    };
+    storage::set_epoch_summary(env, epoch.epoch_id, &summary);
+
+    EpochFinalized {
+        epoch_number: summary.epoch_number,
+        winning_faction: summary.winning_faction,
+        reward_pool: summary.reward_pool,
+        faction_standings: summary.faction_standings,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Seal `epoch` (now finalized) and open the next blank epoch - the second
+/// half of `FinalizationPhase::SettingPool`.
+fn rotate_to_next_epoch(env: &Env, epoch: &EpochInfo) -> Result<(), Error> {
+    storage::seal_epoch(env, epoch.epoch_id);
+
+    let next_epoch_id = epoch.epoch_id + 1;
+    let next = blank_epoch(env, next_epoch_id);
+    storage::set_epoch(env, next_epoch_id, &next)?;
+    storage::set_current_epoch(env, next_epoch_id);
+
+    emit_epoch_rotated(env, epoch.epoch_id, next_epoch_id, epoch.winning_faction);
+
+    Ok(())
+}
+
+/// Faction with the highest FP standing, or `None` if nobody contributed.
+fn winning_faction_of(standings: &[u128; Faction::COUNT as usize]) -> Option<u32> {
+    let mut best: Option<(u32, u128)> = None;
+    for (i, &points) in standings.iter().enumerate() {
+        if points == 0 {
+            continue;
+        }
+        if best.map_or(true, |(_, best_points)| points > best_points) {
+            best = Some((i as u32, points));
+        }
+    }
+    best.map(|(faction, _)| faction)
+}
@@ -0,0 +1,124 @@
+use soroban_sdk::contracterror;
+
+/// Contract error codes
+///
+/// Returned from fallible entrypoints via `Result<_, Error>`. Authorization
+/// failures (e.g. a non-admin calling an admin function) are raised by
+/// `require_auth()` itself and therefore never surface as `Error::NotAdmin` -
+/// that variant exists for completeness/documentation of the auth boundary.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotAdmin = 2,
+    InvalidFaction = 3,
+    PlayerNotFound = 4,
+    FactionNotSelected = 5,
+    GameNotWhitelisted = 6,
+    SessionAlreadyExists = 7,
+    InvalidAmount = 8,
+    InsufficientFactionPoints = 9,
+    ContractPaused = 10,
+    SessionNotFound = 11,
+    InvalidSessionState = 12,
+    InvalidGameOutcome = 13,
+    ProofVerificationFailed = 14,
+    EpochNotReady = 15,
+    EpochAlreadyFinalized = 16,
+    FeeVaultError = 17,
+    SwapError = 18,
+    EpochNotFinalized = 19,
+    RewardAlreadyClaimed = 20,
+    NotWinningFaction = 21,
+    NoRewardsAvailable = 22,
+    InsufficientBalance = 23,
+    OverflowError = 24,
+    OracleKeyNotAuthorized = 25,
+    DuplicateOracleSigner = 26,
+    ReferencePriceNotSet = 27,
+    InvalidPartitionRange = 28,
+    EmissionNotConfigured = 29,
+    EpochSealed = 30,
+    UnbondingQueueFull = 31,
+    NoMaturedWithdrawals = 32,
+    DepositBelowMinimum = 33,
+    VaultCapacityExceeded = 34,
+    UnsupportedAsset = 35,
+    DepositLocked = 36,
+    RewardRootAlreadySet = 37,
+    RewardRootNotSet = 38,
+    InvalidMerkleProof = 39,
+    MaxPlayersReached = 40,
+    /// A fresh oracle reference price deviated from the BLND/USDC TWAP by
+    /// more than `Config.max_price_deviation_bps` - see
+    /// `oracle::check_twap_deviation`.
+    PriceDeviatesFromTwap = 41,
+    /// Caller is not one of the admin signers configured at construction -
+    /// see `governance::propose_action`/`approve_action`.
+    NotAdminSigner = 42,
+    /// `governance::consume_approved_action` was called (or `approve_action`)
+    /// for an `action_hash` nobody has proposed yet.
+    ActionNotProposed = 43,
+    /// A proposed action hasn't collected `admin_threshold` approvals yet -
+    /// see `governance::consume_approved_action`.
+    ActionNotApproved = 44,
+    /// A proposed, fully-approved action's `admin_timelock` hasn't elapsed
+    /// since it was proposed yet - see `governance::consume_approved_action`.
+    TimelockNotElapsed = 45,
+    /// An `action_hash` has already been consumed by
+    /// `governance::consume_approved_action` - each approved action executes
+    /// at most once.
+    ActionAlreadyExecuted = 46,
+    /// The `action_hash` a multisig-gated entrypoint was called with doesn't
+    /// match the hash of the arguments it was actually called with - see
+    /// `governance::hash_add_game`/`hash_update_critical_config`.
+    ActionHashMismatch = 47,
+    /// `admin_threshold` passed to `__constructor` is `0` or exceeds the
+    /// number of `admin_signers`.
+    InvalidThreshold = 48,
+    /// `proposals::vote`/`execute`/`cancel` was called with a `proposal_id`
+    /// nobody has `proposals::propose`'d.
+    ProposalNotFound = 49,
+    /// `proposals::vote` was called after the proposal's `voting_deadline`.
+    VotingClosed = 50,
+    /// `proposals::vote`/`execute` was called on a proposal the admin has
+    /// `proposals::cancel`'d.
+    ProposalCanceled = 51,
+    /// `proposals::vote` was called twice by the same address on the same
+    /// proposal.
+    AlreadyVoted = 52,
+    /// `proposals::vote` was called by an address with no
+    /// `EpochPlayer.total_fp_contributed` at the proposal's `snapshot_epoch`.
+    NoVotingPower = 53,
+    /// `proposals::execute` was called before voting closed with quorum and
+    /// majority met - see `proposals::get_proposal_status`.
+    ProposalNotPassed = 54,
+    /// `proposals::execute` was called on a proposal that already executed.
+    ProposalAlreadyExecuted = 55,
+    /// `game::start_game_multi`/`rewards::claim_epoch_reward` was called
+    /// against an epoch `epoch::cycle_epoch_step` is still mid-flight on -
+    /// see `FinalizationCursor`.
+    EpochFinalizationInProgress = 56,
+    /// `rewards::claim_epochs` was called with an empty epoch list or more
+    /// than `MAX_CLAIM_EPOCHS_PER_CALL` epochs at once.
+    InvalidEpochBatch = 57,
+    /// `game::finalize_game` was called on a `SessionStatus::Provisional`
+    /// session before its `finalize_after_ts`.
+    DisputeWindowNotElapsed = 58,
+    /// `game::dispute_game` was called on a `SessionStatus::Provisional`
+    /// session after its `finalize_after_ts` - it's eligible for
+    /// `finalize_game` instead.
+    DisputeWindowElapsed = 59,
+    /// `game::dispute_game` was called with a `game_id` that doesn't match
+    /// the stored session's `GameSession.game_id`.
+    GameSessionMismatch = 60,
+    /// A player's schema version tag doesn't match any layout
+    /// `migrate::upgrade_player`/`upgrade_epoch_player` knows how to read -
+    /// see `storage::diagnose_player`, which can be checked ahead of time to
+    /// avoid hitting this.
+    CorruptStorage = 61,
+}
+
+// `claim_yield` reuses `Error::NoRewardsAvailable` for "nothing claimable" -
+// same meaning as in `rewards.rs`, no need for a dedicated variant.
@@ -0,0 +1,192 @@
+use soroban_sdk::{contractevent, symbol_short, Address, BytesN, Env};
+
+use crate::types::Faction;
+
+// ============================================================================
+// Structured Events
+// ============================================================================
+// Typed counterparts to the tuple-topic events below, for state transitions
+// an off-chain indexer needs to reconstruct history from rather than just
+// observe live - see `epoch::cycle_epoch_step`, `faction::select_faction`, and
+// `game::end_game`. Published directly at their call sites (`Event { .. }.publish(&env)`)
+// rather than through a wrapper function here, matching `number-guess`'s events.
+
+/// A finalized epoch's outcome - also archived via `storage::set_epoch_summary`
+/// so `get_epoch_summary` can serve it back without replaying events.
+#[contractevent]
+pub struct EpochFinalized {
+    pub epoch_number: u32,
+    pub winning_faction: Option<u32>,
+    pub reward_pool: i128,
+    pub faction_standings: [u128; Faction::COUNT as usize],
+}
+
+/// A player locking in (or changing) their persistent faction preference -
+/// see `faction::select_faction`. `stake_weight` is their effective vault
+/// balance at the moment of selection, for display only; it does not itself
+/// determine FP weighting.
+#[contractevent]
+pub struct FactionSelected {
+    pub player: Address,
+    pub faction: u32,
+    pub stake_weight: i128,
+}
+
+/// A game session settling - see `game::end_game`. `winner` is `None` for a
+/// `Draw` result or a multi-recipient `Split`; `fp_awarded` is the total FP
+/// credited to whoever won (`0` for a `Draw`, which refunds wagers instead).
+#[contractevent]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub winner: Option<Address>,
+    pub fp_awarded: i128,
+}
+
+// ============================================================================
+// Event Emission
+// ============================================================================
+// Thin wrappers around env.events().publish() so call sites stay readable and
+// topic/payload shapes stay consistent across the contract.
+
+pub(crate) fn emit_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
+    env.events()
+        .publish((symbol_short!("admin"),), (old_admin.clone(), new_admin.clone()));
+}
+
+pub(crate) fn emit_config_updated(env: &Env, admin: &Address) {
+    env.events()
+        .publish((symbol_short!("config"),), admin.clone());
+}
+
+pub(crate) fn emit_deposit(env: &Env, user: &Address, amount: i128, total_deposited: i128) {
+    env.events()
+        .publish((symbol_short!("deposit"), user.clone()), (amount, total_deposited));
+}
+
+pub(crate) fn emit_withdraw(env: &Env, user: &Address, amount: i128, total_deposited: i128, reset: bool) {
+    env.events().publish(
+        (symbol_short!("withdraw"), user.clone()),
+        (amount, total_deposited, reset),
+    );
+}
+
+pub(crate) fn emit_withdraw_requested(env: &Env, user: &Address, amount: i128, unlock_epoch: u32) {
+    env.events().publish(
+        (symbol_short!("unb_req"), user.clone()),
+        (amount, unlock_epoch),
+    );
+}
+
+pub(crate) fn emit_withdraw_unbonded(env: &Env, user: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("unb_claim"), user.clone()), amount);
+}
+
+pub(crate) fn emit_yield_distributed(env: &Env, amount: i128) {
+    env.events().publish((symbol_short!("yield_dst"),), amount);
+}
+
+pub(crate) fn emit_yield_claimed(env: &Env, player: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("yield_clm"), player.clone()), amount);
+}
+
+pub(crate) fn emit_faction_locked(env: &Env, player: &Address, epoch: u32, faction: u32) {
+    env.events()
+        .publish((symbol_short!("fac_lock"), player.clone()), (epoch, faction));
+}
+
+pub(crate) fn emit_yield_swap_deferred(env: &Env, epoch_id: u32) {
+    env.events()
+        .publish((symbol_short!("yld_defer"),), epoch_id);
+}
+
+pub(crate) fn emit_cycle_bounty_paid(env: &Env, caller: &Address, amount: i128) {
+    env.events()
+        .publish((symbol_short!("cyc_bnty"), caller.clone()), amount);
+}
+
+pub(crate) fn emit_epoch_rotated(
+    env: &Env,
+    finished_epoch: u32,
+    next_epoch: u32,
+    winning_faction: Option<u32>,
+) {
+    env.events().publish(
+        (symbol_short!("ep_rotate"),),
+        (finished_epoch, next_epoch, winning_faction),
+    );
+}
+
+pub(crate) fn emit_action_proposed(env: &Env, proposer: &Address, action_hash: &BytesN<32>) {
+    env.events()
+        .publish((symbol_short!("act_prop"), proposer.clone()), action_hash.clone());
+}
+
+pub(crate) fn emit_action_approved(
+    env: &Env,
+    signer: &Address,
+    action_hash: &BytesN<32>,
+    approvals: u32,
+    threshold: u32,
+) {
+    env.events().publish(
+        (symbol_short!("act_appr"), signer.clone()),
+        (action_hash.clone(), approvals, threshold),
+    );
+}
+
+pub(crate) fn emit_action_executed(env: &Env, action_hash: &BytesN<32>) {
+    env.events()
+        .publish((symbol_short!("act_exec"),), action_hash.clone());
+}
+
+pub(crate) fn emit_proposal_created(env: &Env, proposal_id: u32, proposer: &Address) {
+    env.events()
+        .publish((symbol_short!("prop_new"), proposer.clone()), proposal_id);
+}
+
+pub(crate) fn emit_proposal_voted(
+    env: &Env,
+    proposal_id: u32,
+    voter: &Address,
+    support: bool,
+    weight: u128,
+) {
+    env.events().publish(
+        (symbol_short!("prop_vote"), voter.clone()),
+        (proposal_id, support, weight),
+    );
+}
+
+pub(crate) fn emit_proposal_executed(env: &Env, proposal_id: u32) {
+    env.events()
+        .publish((symbol_short!("prop_exec"),), proposal_id);
+}
+
+pub(crate) fn emit_proposal_canceled(env: &Env, proposal_id: u32) {
+    env.events()
+        .publish((symbol_short!("prop_can"),), proposal_id);
+}
+
+/// `epoch` is `Some` for a shift in that epoch's top-N leaderboard, or
+/// `None` for a shift in the all-time one - see
+/// `leaderboard::record_contribution`.
+pub(crate) fn emit_leaderboard_changed(env: &Env, epoch: Option<u32>) {
+    env.events().publish((symbol_short!("lb_chg"),), epoch);
+}
+
+/// A `Provisional` session reverted before it could settle - see
+/// `game::dispute_game`.
+pub(crate) fn emit_game_disputed(env: &Env, game_id: &Address, session_id: u32) {
+    env.events()
+        .publish((symbol_short!("gm_disp"), game_id.clone()), session_id);
+}
+
+/// A game contract removed from the allow-list for repeated misbehavior -
+/// see `game::slash_game`. `invalidated` is how many still-provisional
+/// sessions it took down with it.
+pub(crate) fn emit_game_slashed(env: &Env, game_id: &Address, invalidated: u32) {
+    env.events()
+        .publish((symbol_short!("gm_slash"), game_id.clone()), invalidated);
+}
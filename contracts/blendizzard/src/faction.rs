@@ -1,9 +1,10 @@
 use soroban_sdk::{Address, Env};
 
 use crate::errors::Error;
-use crate::events::{emit_faction_locked, emit_faction_selected};
+use crate::events::{emit_faction_locked, FactionSelected};
 use crate::storage;
 use crate::types::{EpochPlayer, Faction};
+use crate::vault;
 
 // ============================================================================
 // Faction Selection
@@ -32,6 +33,7 @@ use crate::types::{EpochPlayer, Faction};
 ///
 /// # Errors
 /// * `InvalidFaction` - If faction ID is not 0, 1, or 2
+/// * `MaxPlayersReached` - If this would be a new player and `config.max_players` is already met
 pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Result<(), Error> {
     // Validate faction
     if !Faction::is_valid(faction) {
@@ -41,13 +43,25 @@ pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Resul
     // Authenticate player
     player.require_auth();
 
+    let existing = storage::get_player(env, player)?;
+
+    // This is the protocol's only "joining" point - enforce max_players here
+    // against brand-new players, before creating their record.
+    if existing.is_none() {
+        let max_players = storage::get_config(env).max_players;
+        let total_players = storage::get_total_players(env);
+        if max_players > 0 && total_players >= max_players {
+            return Err(Error::MaxPlayersReached);
+        }
+        storage::set_total_players(env, total_players + 1);
+    }
+
     // Get or create player data
-    let mut player_data =
-        storage::get_player(env, player).unwrap_or_else(|| crate::types::Player {
-            selected_faction: faction,
-            time_multiplier_start: 0,
-            last_epoch_balance: 0,
-        });
+    let mut player_data = existing.unwrap_or_else(|| crate::types::Player {
+        selected_faction: faction,
+        time_multiplier_start: env.ledger().timestamp(),
+        last_epoch_balance: 0,
+    });
 
     // Update faction selection (always allowed - affects future epochs)
     player_data.selected_faction = faction;
@@ -56,7 +70,12 @@ pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Resul
     storage::set_player(env, player, &player_data);
 
     // Emit event
-    emit_faction_selected(env, player, faction);
+    FactionSelected {
+        player: player.clone(),
+        faction,
+        stake_weight: vault::get_effective_vault_balance(env, player),
+    }
+    .publish(env);
 
     Ok(())
 }
@@ -81,18 +100,19 @@ pub(crate) fn select_faction(env: &Env, player: &Address, faction: u32) -> Resul
 /// # Errors
 /// * `FactionNotSelected` - If player hasn't explicitly selected a faction
 /// * `FactionAlreadyLocked` - If faction is already locked for this epoch
+/// * `EpochSealed` - If `current_epoch` has been sealed
 pub(crate) fn lock_epoch_faction(
     env: &Env,
     player: &Address,
     current_epoch: u32,
 ) -> Result<u32, Error> {
     // Get player's selected faction - player MUST have explicitly selected one
-    let player_data = storage::get_player(env, player).ok_or(Error::FactionNotSelected)?;
+    let player_data = storage::get_player(env, player)?.ok_or(Error::FactionNotSelected)?;
     let selected_faction = player_data.selected_faction;
 
     // Get or create epoch player data
     let mut epoch_player =
-        storage::get_epoch_player(env, current_epoch, player).unwrap_or(EpochPlayer {
+        storage::get_epoch_player(env, current_epoch, player)?.unwrap_or(EpochPlayer {
             epoch_faction: None,
             epoch_balance_snapshot: 0, // Will be set when FP is calculated
             available_fp: 0,
@@ -108,7 +128,7 @@ pub(crate) fn lock_epoch_faction(
     epoch_player.epoch_faction = Some(selected_faction);
 
     // Save epoch player data
-    storage::set_epoch_player(env, current_epoch, player, &epoch_player);
+    storage::set_epoch_player(env, current_epoch, player, &epoch_player)?;
 
     // Emit event
     emit_faction_locked(env, player, current_epoch, selected_faction);
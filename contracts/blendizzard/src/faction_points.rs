@@ -0,0 +1,201 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::storage;
+use crate::types::{LockupKind, BPS_DENOM};
+use crate::vault;
+
+// ============================================================================
+// Faction Points
+// ============================================================================
+// FP accrual is integer-only and deterministic: the time multiplier is
+// expressed in basis points (10_000 == 1.0x) rather than as a floating-point
+// factor, so every node computes byte-identical FP for a given balance and
+// elapsed time.
+
+/// Base multiplier (1.0x) applied the instant a deposit's time-multiplier
+/// window starts.
+const BASE_MULTIPLIER_BPS: u64 = 10_000;
+
+/// Cap the multiplier ramps toward (2.0x), reached once a deposit has sat
+/// for `MULTIPLIER_RAMP_SECONDS` without a qualifying (>50%) withdrawal.
+const MAX_MULTIPLIER_BPS: u64 = 20_000;
+
+/// Time (in seconds) over which the multiplier ramps linearly from base to
+/// cap. Set to one epoch (4 days) so a deposit held for a full epoch earns
+/// the maximum boost.
+const MULTIPLIER_RAMP_SECONDS: u64 = 345_600;
+
+/// Time multiplier, in basis points, for a deposit that has been sitting for
+/// `elapsed_seconds` since its last reset.
+pub(crate) fn time_multiplier_bps(elapsed_seconds: u64) -> u64 {
+    let capped_elapsed = elapsed_seconds.min(MULTIPLIER_RAMP_SECONDS);
+    let bonus = (MAX_MULTIPLIER_BPS - BASE_MULTIPLIER_BPS) * capped_elapsed
+        / MULTIPLIER_RAMP_SECONDS;
+    BASE_MULTIPLIER_BPS + bonus
+}
+
+/// Calculate a player's current faction points.
+///
+/// Balance is split into two tranches to stop a last-second deposit from
+/// capturing a full share of rewards it never sat through the epoch for:
+/// - `carried`, the portion already present as of `last_epoch_balance`
+///   (the vault balance snapshotted at the end of the previous epoch),
+///   which is boosted by the normal time multiplier ramp since
+///   `time_multiplier_start`: `carried * multiplier_bps / BPS_DENOM`.
+/// - `fresh`, any balance above that (deposited sometime during the current
+///   epoch, exact moment unknown), which is instead weighted down by how
+///   much of the epoch is left to run: `fresh * (epoch_end - now) / epoch_duration`.
+///   A deposit made right before `cycle_epoch` is therefore weighted close
+///   to zero, while one made early in the epoch counts close to in full.
+///
+/// All arithmetic stays in integers - there is no floating point anywhere in
+/// this path, so the result is reproducible across every validator.
+///
+/// # Errors
+/// * `FactionNotSelected` - If the player has never selected a faction
+pub(crate) fn calculate_faction_points(env: &Env, player: &Address) -> Result<u128, Error> {
+    let player_data = storage::get_player(env, player)?.ok_or(Error::FactionNotSelected)?;
+
+    let balance = vault::get_effective_vault_balance(env, player);
+    if balance <= 0 {
+        return Ok(0);
+    }
+    let balance = balance as u128;
+
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(player_data.time_multiplier_start);
+    let multiplier_bps = time_multiplier_bps(elapsed) as u128;
+
+    let carried = balance.min(player_data.last_epoch_balance.max(0) as u128);
+    let fresh = balance.saturating_sub(carried);
+
+    let carried_fp = carried * multiplier_bps / BPS_DENOM as u128;
+    let fresh_fp = fresh * fresh_deposit_weight_bps(env, now) as u128 / BPS_DENOM as u128;
+
+    Ok(carried_fp + fresh_fp + lockup_bonus_fp(env, player, balance, now))
+}
+
+/// Extra FP awarded on top of the normal time-multiplier FP above, for
+/// whatever portion of `balance` is currently locked under
+/// `vault::record_locked_deposit`'s lockup - `locked_amount *
+/// lockup_bonus_bps(remaining/max) / BPS_DENOM`, so a freshly-committed
+/// lockup is worth up to a full extra `balance` of FP (i.e. ~2x total,
+/// matching `weight = amount * (1 + remaining/max)`) decaying linearly to 0
+/// as it approaches expiry. An unlocked or `LockupKind::None` commitment
+/// contributes nothing here - that balance already earns its normal FP
+/// above, unboosted.
+fn lockup_bonus_fp(env: &Env, player: &Address, balance: u128, now: u64) -> u128 {
+    let lockup = storage::get_lockup(env, player);
+    if lockup.amount <= 0 || vault::locked_balance(env, player) <= 0 {
+        return 0;
+    }
+
+    let locked = (lockup.amount as u128).min(balance);
+    let bonus_bps = lockup_bonus_bps(&lockup.kind, lockup.created_ts, now) as u128;
+    locked * bonus_bps / BPS_DENOM as u128
+}
+
+/// Bonus multiplier, in basis points, for a lockup commitment created at
+/// `created_ts` as of `now` - `BASE_MULTIPLIER_BPS` (1.0x bonus, doubling
+/// the locked portion's effective weight) right after creation, decaying
+/// linearly to `0` at expiry.
+fn lockup_bonus_bps(kind: &LockupKind, created_ts: u64, now: u64) -> u64 {
+    let (max_lock_seconds, remaining_lock_seconds) = match *kind {
+        LockupKind::None => return 0,
+        LockupKind::Cliff { release_ts } => (
+            release_ts.saturating_sub(created_ts).max(1),
+            release_ts.saturating_sub(now),
+        ),
+        LockupKind::Constant { duration } => (
+            duration.max(1),
+            created_ts.saturating_add(duration).saturating_sub(now),
+        ),
+    };
+
+    remaining_lock_seconds.min(max_lock_seconds) * BASE_MULTIPLIER_BPS / max_lock_seconds
+}
+
+/// Weight, in basis points, applied to balance above `last_epoch_balance` -
+/// i.e. deposited sometime during the current epoch. Ramps from
+/// `BASE_MULTIPLIER_BPS` down to `0` as `now` approaches the epoch's end, so
+/// a deposit first observed right before `cycle_epoch` barely counts.
+///
+/// Once `now` crosses `config.late_deposit_cutoff_bps` of the way through
+/// the epoch, the weight is hard-zeroed rather than left to the ramp - a
+/// deposit that lands after the cutoff contributes nothing to this epoch's
+/// standings at all, and only starts earning once `commit_epoch_balance`
+/// folds it into next epoch's `last_epoch_balance`. This stops a large
+/// deposit timed just before `cycle_epoch` from claiming even the ramp's
+/// residual sliver of a winning faction's reward pool.
+fn fresh_deposit_weight_bps(env: &Env, now: u64) -> u64 {
+    let current_epoch = storage::get_current_epoch(env);
+    let epoch_start = storage::get_epoch(env, current_epoch)
+        .map(|e| e.start_time)
+        .unwrap_or(now);
+    let config = storage::get_config(env);
+    let epoch_duration = config.epoch_duration.max(1);
+
+    let elapsed = now.saturating_sub(epoch_start);
+    let cutoff_elapsed = epoch_duration * config.late_deposit_cutoff_bps as u64 / BPS_DENOM as u64;
+    if elapsed >= cutoff_elapsed {
+        return 0;
+    }
+
+    let remaining = (epoch_start + config.epoch_duration).saturating_sub(now);
+    remaining.min(epoch_duration) * BASE_MULTIPLIER_BPS / epoch_duration
+}
+
+/// Snapshot a player's current vault balance into `last_epoch_balance`.
+///
+/// Called once, when a player's `EpochPlayer` record is first created for an
+/// epoch (right after `calculate_faction_points` has used the *previous*
+/// snapshot as this epoch's `carried` baseline), so next epoch's calculation
+/// treats this epoch's balance as fully proven rather than `fresh`.
+pub(crate) fn commit_epoch_balance(env: &Env, player: &Address) -> Result<(), Error> {
+    let mut player_data = storage::get_player(env, player)?.ok_or(Error::FactionNotSelected)?;
+    player_data.last_epoch_balance = vault::get_effective_vault_balance(env, player);
+    storage::set_player(env, player, &player_data);
+    Ok(())
+}
+
+// ============================================================================
+// Sybil-Resistant Standings Weight
+// ============================================================================
+// `game::credit_player` only applies this once `config.min_standings_balance`
+// is configured - see the field's doc comment in `types::Config`.
+
+/// Weight, in basis points, applied to FP credited toward `faction_standings`
+/// for a player with `balance` effective vault balance - `sqrt(balance) /
+/// sqrt(reference)`, so a balance exactly at `reference` (i.e.
+/// `config.min_standings_balance`) gets a 1.0x weight and larger balances
+/// are weighted up from there by the square root of their size rather than
+/// linearly.
+///
+/// This is what makes splitting one balance across many clones a losing
+/// trade rather than a neutral one: since `sqrt` is concave,
+/// `sqrt(balance)` for `N` clones each holding `balance / N` sums to only
+/// `sqrt(balance) / sqrt(N)` of a single account's weight, so the "attack of
+/// the clones" strictly loses combined standings weight the more it
+/// subdivides, on top of whatever clones `min_standings_balance` excludes
+/// outright.
+pub(crate) fn standings_weight_bps(balance: i128, reference: i128) -> u64 {
+    let reference_sqrt = isqrt(reference.max(1) as u128).max(1);
+    let balance_sqrt = isqrt(balance.max(0) as u128);
+    (balance_sqrt * BPS_DENOM as u128 / reference_sqrt) as u64
+}
+
+/// Integer square root via Newton's method, truncating down - deterministic
+/// and allocation-free, unlike a floating-point `sqrt`.
+fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
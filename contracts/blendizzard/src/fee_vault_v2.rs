@@ -0,0 +1,24 @@
+//! External contract type definition for fee-vault-v2.
+//!
+//! We only declare the entrypoints Blendizzard actually calls; the rest of
+//! the vault's interface is irrelevant to this crate.
+
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+#[contractclient(name = "Client")]
+pub trait FeeVaultV2Trait {
+    /// Deposit `amount` of the vault's underlying asset on behalf of `from`,
+    /// returning shares minted.
+    fn deposit(env: Env, from: Address, amount: i128) -> i128;
+
+    /// Withdraw `amount` of underlying on behalf of `from`, returning the
+    /// underlying amount actually withdrawn.
+    fn withdraw(env: Env, from: Address, amount: i128) -> i128;
+
+    /// Current underlying-denominated balance for `user`.
+    fn get_underlying_balance(env: Env, user: Address) -> i128;
+
+    /// Claim BLND emissions for the given reserve token IDs, returning the
+    /// amount of BLND claimed.
+    fn claim_emissions(env: Env, reserve_token_ids: Vec<u32>) -> i128;
+}
@@ -0,0 +1,677 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+use crate::epoch;
+use crate::errors::Error;
+use crate::events;
+use crate::events::GameEnded;
+use crate::faction;
+use crate::faction_points;
+use crate::governance;
+use crate::leaderboard;
+use crate::storage;
+use crate::types::{
+    EpochPlayer, GameOutcome, GameSession, MatchResult, PendingAction, SessionStatus, BPS_DENOM,
+    DENOM,
+};
+use crate::vault;
+
+// ============================================================================
+// Game Registry
+// ============================================================================
+
+/// Add a game contract to the approved list
+///
+/// Multisig-gated - see `governance::hash_add_game`/`consume_approved_action`.
+///
+/// # Errors
+/// * `ActionHashMismatch` - If `action_hash` doesn't match `id`
+/// * `ActionNotProposed` / `ActionNotApproved` / `TimelockNotElapsed` / `ActionAlreadyExecuted` - See `governance::consume_approved_action`
+pub(crate) fn add_game(env: &Env, id: &Address, action_hash: &BytesN<32>) -> Result<(), Error> {
+    let expected_hash = governance::hash_add_game(env, id);
+    if &expected_hash != action_hash {
+        return Err(Error::ActionHashMismatch);
+    }
+    governance::consume_approved_action(env, action_hash)?;
+
+    storage::add_game_to_whitelist(env, id);
+    Ok(())
+}
+
+/// Remove a game contract from the approved list
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn remove_game(env: &Env, id: &Address) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::remove_game_from_whitelist(env, id);
+    Ok(())
+}
+
+/// Check if a contract is an approved game
+pub(crate) fn is_game(env: &Env, id: &Address) -> bool {
+    storage::is_game_whitelisted(env, id)
+}
+
+// ============================================================================
+// Game Lifecycle
+// ============================================================================
+
+/// Start a new 1v1 game session
+///
+/// Convenience wrapper around `start_game_multi` for the common two-player
+/// case.
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If game_id is not approved
+/// * `SessionAlreadyExists` - If session_id already exists
+/// * `InvalidAmount` - If either wager is <= 0
+/// * `InsufficientFactionPoints` - If either player doesn't have enough fp
+pub(crate) fn start_game(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_wager: i128,
+    player2_wager: i128,
+) -> Result<(), Error> {
+    let players = Vec::from_array(env, [player1.clone(), player2.clone()]);
+    let wagers = Vec::from_array(env, [player1_wager, player2_wager]);
+    start_game_multi(env, game_id, session_id, players, wagers)
+}
+
+/// Start a new multi-participant game session (free-for-all or team match)
+///
+/// Locks each player's faction (on their first game this epoch) and reserves
+/// their wager FP from their available balance. `players` and `wagers` must
+/// be the same length and describe at least two participants.
+///
+/// If the current epoch's play window has already ended, this cycles the
+/// epoch first so the session is scored against the new epoch's fresh
+/// accumulators rather than a stale one whose `faction_standings` are about
+/// to be frozen - otherwise a game played after the cutoff but before anyone
+/// gets around to calling `cycle_epoch` could still mutate standings that
+/// feed a settled epoch's reward math. If a prior finalization is still
+/// mid-flight (see `epoch::cycle_epoch_step`) and can't finish within
+/// `cycle_epoch`'s own budget, this fails with
+/// `EpochFinalizationInProgress` rather than scoring the game against an
+/// epoch that's still being torn down.
+///
+/// # Errors
+/// * `GameNotWhitelisted` - If game_id is not approved
+/// * `SessionAlreadyExists` - If session_id already exists
+/// * `InvalidAmount` - If players/wagers lengths mismatch, fewer than 2
+///   participants, or any wager is <= 0
+/// * `InsufficientFactionPoints` - If any player doesn't have enough fp
+/// * `EpochFinalizationInProgress` - If the epoch cycle didn't complete within its budget
+/// * `FeeVaultError` / `SwapError` - If cycling the epoch's yield harvest fails
+pub(crate) fn start_game_multi(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    players: Vec<Address>,
+    wagers: Vec<i128>,
+) -> Result<(), Error> {
+    game_id.require_auth();
+
+    if !storage::is_game_whitelisted(env, game_id) {
+        return Err(Error::GameNotWhitelisted);
+    }
+
+    if storage::has_session(env, session_id) {
+        return Err(Error::SessionAlreadyExists);
+    }
+
+    if players.len() != wagers.len() || players.len() < 2 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let current_epoch = roll_to_fresh_epoch(env, game_id)?;
+
+    for (player, wager) in players.iter().zip(wagers.iter()) {
+        if wager <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        reserve_wager(env, &player, current_epoch, wager)?;
+    }
+
+    let session = GameSession {
+        game_id: game_id.clone(),
+        epoch: current_epoch,
+        players,
+        wagers,
+        status: SessionStatus::Pending,
+        pending_actions: Vec::new(env),
+        winner: None,
+        fp_awarded: 0,
+    };
+    storage::set_session(env, session_id, &session);
+    storage::add_game_open_session(env, game_id, session_id);
+
+    Ok(())
+}
+
+/// Cycle the current epoch first if its play window has already ended,
+/// returning whichever epoch id games should now be scored against.
+/// `caller` is credited the cycle bounty (see `epoch::cycle_epoch`) on
+/// whichever call actually finishes the finalization.
+fn roll_to_fresh_epoch(env: &Env, caller: &Address) -> Result<u32, Error> {
+    let current_epoch_id = storage::get_current_epoch(env);
+    let current_epoch_info = epoch::get_epoch(env, Some(current_epoch_id))?;
+    let config = storage::get_config(env);
+
+    if epoch::should_end_epoch(env, &current_epoch_info, &config) {
+        return epoch::cycle_epoch(env, caller);
+    }
+
+    Ok(current_epoch_id)
+}
+
+/// Lock a player's faction (if needed) and reserve `wager` FP for a session
+fn reserve_wager(env: &Env, player: &Address, epoch: u32, wager: i128) -> Result<(), Error> {
+    faction::lock_epoch_faction(env, player, epoch)?;
+
+    let mut epoch_player = match storage::get_epoch_player(env, epoch, player)? {
+        Some(existing) => existing,
+        None => {
+            let total_fp = faction_points::calculate_faction_points(env, player)?;
+            faction_points::commit_epoch_balance(env, player)?;
+            EpochPlayer {
+                epoch_faction: None,
+                epoch_balance_snapshot: 0,
+                available_fp: total_fp,
+                total_fp_contributed: 0,
+            }
+        }
+    };
+
+    let wager_fp = wager as u128;
+    if epoch_player.available_fp < wager_fp {
+        return Err(Error::InsufficientFactionPoints);
+    }
+    epoch_player.available_fp -= wager_fp;
+
+    storage::set_epoch_player(env, epoch, player, &epoch_player)?;
+    Ok(())
+}
+
+/// Length of the proof payload `NumberGuessContract` builds: winning number
+/// (1 byte), both revealed guesses (1 byte each), both revealed salts (32
+/// bytes each) - see `verify_number_guess_proof`.
+const NUMBER_GUESS_PROOF_LEN: u32 = 1 + 1 + 1 + 32 + 32;
+
+/// Recompute `NumberGuessContract`'s commit-reveal outcome from its proof
+/// payload and check it agrees with the `MatchResult` the game reported,
+/// rather than trusting the game's own distance comparison blindly.
+///
+/// This couples `end_game`'s verification to one game's proof layout -
+/// there's only one whitelisted game integrated today, so a non-empty proof
+/// is assumed to be this shape. A second game with a different outcome rule
+/// would need its own proof schema and branch here rather than reusing this.
+///
+/// # Errors
+/// * `InvalidGameOutcome` - If the proof is malformed or doesn't agree with `result`
+fn verify_number_guess_proof(env: &Env, proof: &Bytes, result: &MatchResult) -> Result<(), Error> {
+    if proof.len() != NUMBER_GUESS_PROOF_LEN {
+        return Err(Error::InvalidGameOutcome);
+    }
+
+    let winning_number = proof.get_unchecked(0);
+    let guess1 = proof.get_unchecked(1);
+    let guess2 = proof.get_unchecked(2);
+
+    let mut salt1 = [0u8; 32];
+    let mut salt2 = [0u8; 32];
+    for i in 0..32u32 {
+        salt1[i as usize] = proof.get_unchecked(3 + i);
+        salt2[i as usize] = proof.get_unchecked(35 + i);
+    }
+
+    let mut combined = Bytes::from_array(env, &salt1);
+    combined.append(&Bytes::from_array(env, &salt2));
+    let digest: BytesN<32> = env.crypto().sha256(&combined).into();
+    let digest_u64 = u64::from_be_bytes(digest.to_array()[0..8].try_into().unwrap());
+    let expected_winning_number = (digest_u64 % 10) as u8 + 1;
+    if winning_number != expected_winning_number {
+        return Err(Error::InvalidGameOutcome);
+    }
+
+    let distance1 = if guess1 > winning_number {
+        guess1 - winning_number
+    } else {
+        winning_number - guess1
+    };
+    let distance2 = if guess2 > winning_number {
+        guess2 - winning_number
+    } else {
+        winning_number - guess2
+    };
+    let expected_result = if distance1 == distance2 {
+        MatchResult::Draw
+    } else if distance1 < distance2 {
+        MatchResult::Player1
+    } else {
+        MatchResult::Player2
+    };
+
+    if *result != expected_result {
+        return Err(Error::InvalidGameOutcome);
+    }
+
+    Ok(())
+}
+
+/// End a game session with outcome verification
+///
+/// Requires the game contract's authorization. The pot is distributed per
+/// `outcome`, computed here as a list of `PendingAction`s rather than applied
+/// directly - only the recipients' shares contribute to their faction's
+/// standings for the epoch once applied, except a `Winner` outcome's `Draw`
+/// result, which refunds each player their own wager instead of crediting
+/// anyone's standings. A non-empty `proof` on a `Winner` outcome is
+/// independently recomputed via `verify_number_guess_proof` rather than
+/// trusting the game's reported result - an empty proof skips this (for
+/// whitelisted games that don't yet produce one).
+///
+/// If `config.dispute_window_secs` is `0`, the pending actions are applied
+/// immediately and the session goes straight to `Settled`, unchanged from
+/// before dispute windows existed. Otherwise the session becomes
+/// `Provisional` and nothing is applied - or credited to faction standings -
+/// until `finalize_game` (after the window elapses) or reverted by
+/// `dispute_game`/`slash_game` instead.
+///
+/// # Errors
+/// * `SessionNotFound` - If session doesn't exist
+/// * `InvalidSessionState` - If session is not Pending
+/// * `InvalidGameOutcome` - If outcome data, or a non-empty proof, doesn't match the session
+pub(crate) fn end_game(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    proof: &Bytes,
+    outcome: &GameOutcome,
+) -> Result<(), Error> {
+    game_id.require_auth();
+
+    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    if session.status != SessionStatus::Pending {
+        return Err(Error::InvalidSessionState);
+    }
+
+    let pot: i128 = session.wagers.iter().sum();
+    let mut pending_actions: Vec<PendingAction> = Vec::new(env);
+
+    let (winner, fp_awarded) = match outcome {
+        GameOutcome::Winner {
+            game_id: outcome_game_id,
+            session_id: outcome_session_id,
+            player1,
+            player2,
+            result,
+        } => {
+            if session.players.len() != 2
+                || *outcome_game_id != session.game_id
+                || *outcome_session_id != session_id
+                || *player1 != session.players.get_unchecked(0)
+                || *player2 != session.players.get_unchecked(1)
+            {
+                return Err(Error::InvalidGameOutcome);
+            }
+            if !proof.is_empty() {
+                verify_number_guess_proof(env, proof, result)?;
+            }
+            match result {
+                MatchResult::Player1 => {
+                    pending_actions.push_back(PendingAction::Credit {
+                        player: player1.clone(),
+                        amount: pot,
+                    });
+                    (Some(player1.clone()), pot)
+                }
+                MatchResult::Player2 => {
+                    pending_actions.push_back(PendingAction::Credit {
+                        player: player2.clone(),
+                        amount: pot,
+                    });
+                    (Some(player2.clone()), pot)
+                }
+                MatchResult::Draw => {
+                    let wager1 = session.wagers.get_unchecked(0);
+                    let wager2 = session.wagers.get_unchecked(1);
+                    pending_actions.push_back(PendingAction::Refund {
+                        player: player1.clone(),
+                        amount: wager1,
+                    });
+                    pending_actions.push_back(PendingAction::Refund {
+                        player: player2.clone(),
+                        amount: wager2,
+                    });
+                    (None, 0)
+                }
+            }
+        }
+        GameOutcome::Split(shares) => {
+            let share_sum: u32 = shares.iter().map(|(_, bps)| bps).sum();
+            if share_sum != DENOM {
+                return Err(Error::InvalidGameOutcome);
+            }
+
+            let mut distributed: i128 = 0;
+            for (player, share_bps) in shares.iter() {
+                if !session.players.contains(&player) {
+                    return Err(Error::InvalidGameOutcome);
+                }
+                let payout = pot * (share_bps as i128) / (DENOM as i128);
+                distributed += payout;
+                pending_actions.push_back(PendingAction::Credit {
+                    player,
+                    amount: payout,
+                });
+            }
+            // Any remainder left by basis-point truncation simply stays
+            // uncredited - there is no pot to return it to once the session
+            // settles, so it is dust the protocol keeps.
+            (None, distributed)
+        }
+    };
+
+    let config = storage::get_config(env);
+    if config.dispute_window_secs == 0 {
+        apply_pending_actions(env, session.epoch, &pending_actions)?;
+        session.status = SessionStatus::Settled;
+        session.pending_actions = Vec::new(env);
+        storage::set_session(env, session_id, &session);
+        storage::increment_game_settled_count(env, game_id);
+        storage::remove_game_open_session(env, game_id, session_id);
+
+        GameEnded {
+            session_id,
+            winner,
+            fp_awarded,
+        }
+        .publish(env);
+    } else {
+        let finalize_after_ts = env.ledger().timestamp() + config.dispute_window_secs;
+        session.status = SessionStatus::Provisional { finalize_after_ts };
+        session.pending_actions = pending_actions;
+        session.winner = winner;
+        session.fp_awarded = fp_awarded;
+        storage::set_session(env, session_id, &session);
+    }
+
+    Ok(())
+}
+
+/// Apply every computed `PendingAction` to storage - the effects `end_game`
+/// deferred, applied either immediately (no dispute window) or by
+/// `finalize_game` once a `Provisional` session's window elapses.
+fn apply_pending_actions(env: &Env, epoch: u32, actions: &Vec<PendingAction>) -> Result<(), Error> {
+    for action in actions.iter() {
+        match action {
+            PendingAction::Credit { player, amount } => credit_player(env, epoch, &player, amount)?,
+            PendingAction::Refund { player, amount } => refund_wager(env, epoch, &player, amount)?,
+        }
+    }
+    Ok(())
+}
+
+/// Settle a `Provisional` session once its dispute window has elapsed,
+/// applying its `pending_actions` and emitting `GameEnded`. Callable by
+/// anyone, like `epoch::cycle_epoch` - there's nothing discretionary left to
+/// decide once the window has passed.
+///
+/// Settlement is applied against the session's original epoch via
+/// `storage::set_epoch_player_settlement` even if that epoch has since
+/// sealed - a long dispute window shouldn't be able to strand both players'
+/// wagers just because `epoch::rotate_to_next_epoch` ran in the meantime.
+///
+/// # Errors
+/// * `SessionNotFound` - If session doesn't exist
+/// * `InvalidSessionState` - If session is not `Provisional`
+/// * `DisputeWindowNotElapsed` - If `finalize_after_ts` hasn't passed yet
+pub(crate) fn finalize_game(env: &Env, session_id: u32) -> Result<(), Error> {
+    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+
+    let finalize_after_ts = match session.status {
+        SessionStatus::Provisional { finalize_after_ts } => finalize_after_ts,
+        _ => return Err(Error::InvalidSessionState),
+    };
+    if env.ledger().timestamp() < finalize_after_ts {
+        return Err(Error::DisputeWindowNotElapsed);
+    }
+
+    apply_pending_actions(env, session.epoch, &session.pending_actions)?;
+
+    let game_id = session.game_id.clone();
+    let winner = session.winner.clone();
+    let fp_awarded = session.fp_awarded;
+
+    session.status = SessionStatus::Settled;
+    session.pending_actions = Vec::new(env);
+    storage::set_session(env, session_id, &session);
+    storage::increment_game_settled_count(env, &game_id);
+    storage::remove_game_open_session(env, &game_id, session_id);
+
+    GameEnded {
+        session_id,
+        winner,
+        fp_awarded,
+    }
+    .publish(env);
+
+    Ok(())
+}
+
+/// Revert a still-`Provisional` session, discarding its `pending_actions`
+/// before they were ever applied and returning both players' wagers by
+/// refunding them - as if the session had settled as a `Draw`. Admin (or
+/// governance, via the same single-admin gate `remove_game` uses) only.
+///
+/// If this pushes `game_id`'s `GameDisputeCount` to or past
+/// `Config.game_auto_slash_threshold`, `slash_game` runs automatically.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+/// * `SessionNotFound` - If session doesn't exist
+/// * `GameSessionMismatch` - If `game_id` doesn't own `session_id`
+/// * `InvalidSessionState` - If session is not `Provisional`
+/// * `DisputeWindowElapsed` - If `finalize_after_ts` has already passed
+pub(crate) fn dispute_game(env: &Env, game_id: &Address, session_id: u32) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    revert_provisional_session(env, game_id, session_id, true)?;
+
+    let dispute_count = storage::increment_game_dispute_count(env, game_id);
+    events::emit_game_disputed(env, game_id, session_id);
+
+    let config = storage::get_config(env);
+    if config.game_auto_slash_threshold > 0 && dispute_count >= config.game_auto_slash_threshold {
+        slash_game(env, game_id)?;
+    }
+
+    Ok(())
+}
+
+/// Revert one still-`Provisional` session belonging to `game_id`: refund
+/// every participant's original wager and mark it `Disputed` - shared by
+/// `dispute_game` (one session, time-gated via `require_window_open`) and
+/// `slash_game` (every open session, ungated - a slashed game's sessions are
+/// invalidated regardless of whether `finalize_game` has since become
+/// callable on them).
+fn revert_provisional_session(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    require_window_open: bool,
+) -> Result<(), Error> {
+    let mut session = storage::get_session(env, session_id).ok_or(Error::SessionNotFound)?;
+    if session.game_id != *game_id {
+        return Err(Error::GameSessionMismatch);
+    }
+
+    let finalize_after_ts = match session.status {
+        SessionStatus::Provisional { finalize_after_ts } => finalize_after_ts,
+        _ => return Err(Error::InvalidSessionState),
+    };
+    if require_window_open && env.ledger().timestamp() >= finalize_after_ts {
+        return Err(Error::DisputeWindowElapsed);
+    }
+
+    for (player, wager) in session.players.iter().zip(session.wagers.iter()) {
+        refund_wager(env, session.epoch, &player, wager)?;
+    }
+
+    session.status = SessionStatus::Disputed;
+    session.pending_actions = Vec::new(env);
+    storage::set_session(env, session_id, &session);
+    storage::remove_game_open_session(env, game_id, session_id);
+
+    Ok(())
+}
+
+/// Remove `game_id` from the allow-list and invalidate every one of its
+/// still-`Provisional` sessions in one pass, refunding each one's
+/// participants - see `storage::get_game_open_sessions`. Admin (or
+/// governance) only; also called automatically by `dispute_game` once
+/// `Config.game_auto_slash_threshold` is reached.
+///
+/// `Pending` sessions (outcome not yet reported) are left alone - the game
+/// is no longer whitelisted for new sessions, but an already-open one can
+/// still be ended and disputed individually if it later misbehaves too.
+///
+/// One session failing to revert doesn't abort the sweep - each session is
+/// isolated so a single bad entry can't leave the rest of the game's open
+/// sessions stuck.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn slash_game(env: &Env, game_id: &Address) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::remove_game_from_whitelist(env, game_id);
+
+    let open_sessions = storage::get_game_open_sessions(env, game_id);
+    let mut invalidated: u32 = 0;
+    for session_id in open_sessions.iter() {
+        if let Some(session) = storage::get_session(env, session_id) {
+            if matches!(session.status, SessionStatus::Provisional { .. })
+                && revert_provisional_session(env, game_id, session_id, false).is_ok()
+            {
+                storage::increment_game_dispute_count(env, game_id);
+                invalidated += 1;
+            }
+        }
+    }
+
+    events::emit_game_slashed(env, game_id, invalidated);
+    Ok(())
+}
+
+/// Credit `amount` FP to `player`'s epoch contribution, their faction's live
+/// standings, and the `leaderboard` (per-epoch and all-time).
+///
+/// The player's own `total_fp_contributed` (what their payout share is
+/// computed from) always gets the full `amount`, and that's also what feeds
+/// `leaderboard::record_contribution` - unlike `faction_standings` below, it
+/// is never gated by `min_standings_balance`. What reaches the shared
+/// `faction_standings` - the thing a clone-stuffing whale actually wants to
+/// inflate - is gated and scaled by `config.min_standings_balance` instead:
+/// below it, a faction selection contributes nothing to standings at all;
+/// at or above it, the contribution is scaled by
+/// `faction_points::standings_weight_bps` so splitting one balance across
+/// many small accounts is a net loss rather than neutral. Leaving
+/// `min_standings_balance` at `0` (the default) disables both and credits
+/// standings in full, unchanged from before this gate existed.
+fn credit_player(env: &Env, epoch: u32, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let mut epoch_player =
+        storage::get_epoch_player(env, epoch, player)?.ok_or(Error::SessionNotFound)?;
+    epoch_player.total_fp_contributed = epoch_player
+        .total_fp_contributed
+        .saturating_add(amount as u128);
+    storage::set_epoch_player_settlement(env, epoch, player, &epoch_player);
+
+    let config = storage::get_config(env);
+    leaderboard::record_contribution(
+        env,
+        epoch,
+        player,
+        amount,
+        epoch_player.total_fp_contributed,
+        &config,
+    );
+
+    if let Some(faction) = epoch_player.epoch_faction {
+        let standings_amount = if config.min_standings_balance > 0 {
+            let balance = vault::get_effective_vault_balance(env, player);
+            if balance < config.min_standings_balance {
+                0
+            } else {
+                let weight_bps = faction_points::standings_weight_bps(balance, config.min_standings_balance);
+                (amount as u128) * weight_bps as u128 / BPS_DENOM as u128
+            }
+        } else {
+            amount as u128
+        };
+        if standings_amount > 0 {
+            if storage::is_epoch_sealed(env, epoch) {
+                // The epoch's live `FactionStandings` no longer feed
+                // anything - they were already copied into
+                // `reward_pool.points` (the fixed denominator every
+                // `rewards::claimable_share` divides by) at seal time. This
+                // settlement still grows the credited player's own
+                // `total_fp_contributed` above, though, which *is* a
+                // numerator in that same division - so if `faction` is the
+                // epoch's winning faction, bump `reward_pool.points` by the
+                // identical amount to keep numerator and denominator in
+                // lockstep. A non-winning faction's standings were never
+                // going to be divided by anything, so there's nothing to
+                // reconcile.
+                let winning_faction =
+                    storage::get_epoch(env, epoch).and_then(|info| info.winning_faction);
+                if winning_faction == Some(faction) {
+                    storage::credit_sealed_epoch_reward_points(env, epoch, standings_amount);
+                }
+            } else {
+                // Seal can race this call (another settlement or
+                // `cycle_epoch` sealing between the read above and here) -
+                // treat that exactly like the already-sealed case instead
+                // of dropping the credit, rather than fail the whole
+                // settlement over a timing race.
+                if storage::add_faction_points(env, epoch, faction, standings_amount).is_err() {
+                    let winning_faction =
+                        storage::get_epoch(env, epoch).and_then(|info| info.winning_faction);
+                    if winning_faction == Some(faction) {
+                        storage::credit_sealed_epoch_reward_points(env, epoch, standings_amount);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Refund `amount` FP to `player`'s available balance for `epoch`, undoing a
+/// wager reservation (see `reserve_wager`) without crediting anyone's
+/// faction standings - used when a session settles as a `MatchResult::Draw`.
+fn refund_wager(env: &Env, epoch: u32, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let mut epoch_player =
+        storage::get_epoch_player(env, epoch, player)?.ok_or(Error::SessionNotFound)?;
+    epoch_player.available_fp = epoch_player.available_fp.saturating_add(amount as u128);
+    storage::set_epoch_player_settlement(env, epoch, player, &epoch_player);
+
+    Ok(())
+}
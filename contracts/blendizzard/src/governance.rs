@@ -0,0 +1,201 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+use crate::errors::Error;
+use crate::events;
+use crate::storage;
+use crate::types::{ActionStatus, YieldSourceKind, SwapRouterKind};
+
+// ============================================================================
+// Multisig / Timelocked Governance
+// ============================================================================
+// An m-of-n admin signer set, fixed at `__constructor`, stands in for the
+// single `admin` address on the entrypoints risky enough that one
+// compromised key shouldn't be able to act alone - `game::add_game` and
+// `update_critical_config` (oracle set/threshold, yield source, swap
+// router). High-frequency, non-destructive calls (deposits, faction
+// selection, reads, and the rest of `update_config`) stay on the plain
+// single-`admin` path.
+//
+// A signer `propose_action`s an opaque `action_hash` (which also counts as
+// their own approval), other signers `approve_action` it, and once
+// `admin_threshold` signers have approved *and* `admin_timelock` seconds
+// have elapsed since it was proposed, anyone may call the gated entrypoint
+// with matching arguments - the entrypoint recomputes the hash from what it
+// was actually called with (`hash_add_game`/`hash_update_critical_config`)
+// and rejects a mismatch, so an approval can't be replayed against
+// different arguments than what the signers actually signed off on.
+
+/// Whether `who` is one of the configured admin signers
+pub(crate) fn is_admin_signer(env: &Env, who: &Address) -> bool {
+    storage::get_admin_signers(env).contains(who)
+}
+
+/// A proposed action's current approval/timelock/execution state
+pub(crate) fn get_action_status(env: &Env, action_hash: &BytesN<32>) -> ActionStatus {
+    let proposed_at = storage::get_action_proposed_at(env, action_hash);
+    let timelock = storage::get_admin_timelock(env);
+    ActionStatus {
+        proposed_at,
+        approvals: storage::get_action_approval_count(env, action_hash),
+        threshold: storage::get_admin_threshold(env),
+        executable_at: if proposed_at > 0 { proposed_at + timelock } else { 0 },
+        executed: storage::is_action_executed(env, action_hash),
+    }
+}
+
+/// Propose `action_hash` for multisig approval - a no-op if it's already
+/// been proposed. Counts as `proposer`'s own approval either way.
+///
+/// # Errors
+/// * `NotAdminSigner` - If `proposer` isn't a configured admin signer
+pub(crate) fn propose_action(env: &Env, proposer: &Address, action_hash: &BytesN<32>) -> Result<(), Error> {
+    proposer.require_auth();
+    if !is_admin_signer(env, proposer) {
+        return Err(Error::NotAdminSigner);
+    }
+
+    if storage::get_action_proposed_at(env, action_hash) == 0 {
+        storage::set_action_proposed_at(env, action_hash, env.ledger().timestamp());
+        events::emit_action_proposed(env, proposer, action_hash);
+    }
+
+    approve_action(env, proposer, action_hash)
+}
+
+/// Record `signer`'s approval of an already-proposed `action_hash` - a
+/// no-op if `signer` already approved it.
+///
+/// # Errors
+/// * `NotAdminSigner` - If `signer` isn't a configured admin signer
+/// * `ActionNotProposed` - If nobody has `propose_action`'d this hash yet
+pub(crate) fn approve_action(env: &Env, signer: &Address, action_hash: &BytesN<32>) -> Result<(), Error> {
+    signer.require_auth();
+    if !is_admin_signer(env, signer) {
+        return Err(Error::NotAdminSigner);
+    }
+    if storage::get_action_proposed_at(env, action_hash) == 0 {
+        return Err(Error::ActionNotProposed);
+    }
+
+    if !storage::has_action_approval(env, action_hash, signer) {
+        storage::record_action_approval(env, action_hash, signer);
+        events::emit_action_approved(
+            env,
+            signer,
+            action_hash,
+            storage::get_action_approval_count(env, action_hash),
+            storage::get_admin_threshold(env),
+        );
+    }
+
+    Ok(())
+}
+
+/// Verify `action_hash` has cleared threshold approval and its timelock,
+/// then permanently mark it executed - called by every multisig-gated
+/// entrypoint immediately before applying its mutation, after it has
+/// already confirmed `action_hash` matches its own arguments.
+///
+/// # Errors
+/// * `ActionNotProposed` - If nobody has proposed this hash
+/// * `ActionNotApproved` - If it hasn't collected `admin_threshold` approvals
+/// * `TimelockNotElapsed` - If `admin_timelock` seconds haven't passed since proposal
+/// * `ActionAlreadyExecuted` - If this hash was already consumed
+pub(crate) fn consume_approved_action(env: &Env, action_hash: &BytesN<32>) -> Result<(), Error> {
+    if storage::is_action_executed(env, action_hash) {
+        return Err(Error::ActionAlreadyExecuted);
+    }
+
+    let proposed_at = storage::get_action_proposed_at(env, action_hash);
+    if proposed_at == 0 {
+        return Err(Error::ActionNotProposed);
+    }
+
+    let threshold = storage::get_admin_threshold(env);
+    if storage::get_action_approval_count(env, action_hash) < threshold {
+        return Err(Error::ActionNotApproved);
+    }
+
+    let timelock = storage::get_admin_timelock(env);
+    if env.ledger().timestamp() < proposed_at + timelock {
+        return Err(Error::TimelockNotElapsed);
+    }
+
+    storage::set_action_executed(env, action_hash);
+    events::emit_action_executed(env, action_hash);
+    Ok(())
+}
+
+// ============================================================================
+// Action Hashes
+// ============================================================================
+// Canonical `sha256(selector_tag || xdr(args...))` commitments, mirroring
+// `merkle::leaf_hash`'s xdr-then-hash approach - what signers actually sign
+// off on via `propose_action`/`approve_action`, and what the gated
+// entrypoint recomputes from its real arguments to check against.
+
+/// Action hash for `game::add_game(game_id)`
+pub(crate) fn hash_add_game(env: &Env, game_id: &Address) -> BytesN<32> {
+    let mut bytes = Bytes::from_slice(env, b"add_game");
+    bytes.append(&game_id.to_xdr(env));
+    env.crypto().sha256(&bytes).into()
+}
+
+/// Action hash for `update_critical_config`'s six fields, in declaration
+/// order - each field contributes a `0`/`1` presence tag before its encoded
+/// value (or nothing, for `None`), so two calls that only differ in which
+/// fields are set hash differently.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn hash_update_critical_config(
+    env: &Env,
+    new_fee_vault: &Option<Address>,
+    new_soroswap_router: &Option<Address>,
+    new_oracle_keys: &Option<Vec<BytesN<32>>>,
+    new_oracle_threshold: &Option<u32>,
+    new_yield_source_kind: &Option<YieldSourceKind>,
+    new_swap_router_kind: &Option<SwapRouterKind>,
+) -> BytesN<32> {
+    let mut bytes = Bytes::from_slice(env, b"update_critical_config");
+
+    match new_fee_vault {
+        Some(addr) => {
+            bytes.extend_from_array(&[1]);
+            bytes.append(&addr.to_xdr(env));
+        }
+        None => bytes.extend_from_array(&[0]),
+    }
+    match new_soroswap_router {
+        Some(addr) => {
+            bytes.extend_from_array(&[1]);
+            bytes.append(&addr.to_xdr(env));
+        }
+        None => bytes.extend_from_array(&[0]),
+    }
+    match new_oracle_keys {
+        Some(keys) => {
+            bytes.extend_from_array(&[1]);
+            bytes.extend_from_array(&(keys.len()).to_be_bytes());
+            for key in keys.iter() {
+                bytes.extend_from_array(&key.to_array());
+            }
+        }
+        None => bytes.extend_from_array(&[0]),
+    }
+    match new_oracle_threshold {
+        Some(threshold) => {
+            bytes.extend_from_array(&[1]);
+            bytes.extend_from_array(&threshold.to_be_bytes());
+        }
+        None => bytes.extend_from_array(&[0]),
+    }
+    match new_yield_source_kind {
+        Some(YieldSourceKind::FeeVaultV2) => bytes.extend_from_array(&[1, 0]),
+        None => bytes.extend_from_array(&[0]),
+    }
+    match new_swap_router_kind {
+        Some(SwapRouterKind::Soroswap) => bytes.extend_from_array(&[1, 0]),
+        None => bytes.extend_from_array(&[0]),
+    }
+
+    env.crypto().sha256(&bytes).into()
+}
@@ -0,0 +1,124 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::events;
+use crate::storage;
+use crate::types::Config;
+
+// ============================================================================
+// Leaderboard
+// ============================================================================
+// Maintains two bounded, sorted-descending standings: one per epoch (by that
+// epoch's `total_fp_contributed`) and one cumulative across every epoch (by
+// lifetime contribution). Both are kept in sync incrementally from
+// `game::credit_player` rather than recomputed from a full scan, since
+// there's no bound on how many players an epoch (or the contract's history)
+// may ever have.
+
+/// Record `delta` more FP credited to `player` in `epoch` (see
+/// `game::credit_player`), updating both the epoch's top-N leaderboard and
+/// the all-time cumulative one. `epoch_total` is the player's already-updated
+/// `EpochPlayer.total_fp_contributed` for this epoch, used directly as the
+/// epoch leaderboard's sort key instead of re-deriving it.
+///
+/// A `Config.leaderboard_size` of `0` disables tracking entirely - neither
+/// board is touched and no event is published.
+pub(crate) fn record_contribution(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+    delta: i128,
+    epoch_total: u128,
+    config: &Config,
+) {
+    if config.leaderboard_size == 0 {
+        return;
+    }
+
+    update_board(
+        env,
+        storage::get_epoch_leaderboard(env, epoch),
+        player,
+        epoch_total as i128,
+        config.leaderboard_size,
+        |board| storage::set_epoch_leaderboard(env, epoch, board),
+        Some(epoch),
+    );
+
+    let alltime_score = storage::get_alltime_score(env, player) + delta;
+    storage::set_alltime_score(env, player, alltime_score);
+
+    update_board(
+        env,
+        storage::get_alltime_leaderboard(env),
+        player,
+        alltime_score,
+        config.leaderboard_size,
+        |board| storage::set_alltime_leaderboard(env, board),
+        None,
+    );
+}
+
+/// Insert/update `player` at `score` within `board`, keeping it sorted
+/// descending and capped at `capacity` entries, persist the result via
+/// `store`, and emit `leaderboard_changed` if membership or ranking order
+/// shifted as a result.
+fn update_board(
+    env: &Env,
+    board: Vec<(Address, i128)>,
+    player: &Address,
+    score: i128,
+    capacity: u32,
+    store: impl FnOnce(&Vec<(Address, i128)>),
+    epoch: Option<u32>,
+) {
+    let before = ranking_of(env, &board);
+    let updated = insert_sorted(board, player, score, capacity);
+    if ranking_of(env, &updated) != before {
+        events::emit_leaderboard_changed(env, epoch);
+    }
+    store(&updated);
+}
+
+/// Insert `player` at `score` into `board` in descending-sorted position,
+/// replacing any existing entry for them, then drop the lowest entry if the
+/// result exceeds `capacity`.
+fn insert_sorted(
+    mut board: Vec<(Address, i128)>,
+    player: &Address,
+    score: i128,
+    capacity: u32,
+) -> Vec<(Address, i128)> {
+    for i in 0..board.len() {
+        let (existing_player, _) = board.get_unchecked(i);
+        if existing_player == *player {
+            board.remove(i);
+            break;
+        }
+    }
+
+    let mut insert_at = board.len();
+    for i in 0..board.len() {
+        let (_, existing_score) = board.get_unchecked(i);
+        if score > existing_score {
+            insert_at = i;
+            break;
+        }
+    }
+    board.insert(insert_at, (player.clone(), score));
+
+    if board.len() > capacity {
+        board.remove(board.len() - 1);
+    }
+
+    board
+}
+
+/// The ordered addresses in `board`, used to detect a top-N membership or
+/// ranking shift without comparing scores (which always change on a credit).
+fn ranking_of(env: &Env, board: &Vec<(Address, i128)>) -> Vec<Address> {
+    let mut ranking = Vec::new(env);
+    for (player, _) in board.iter() {
+        ranking.push_back(player);
+    }
+    ranking
+}
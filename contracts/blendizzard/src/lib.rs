@@ -23,19 +23,35 @@ mod events;
 mod storage;
 mod types;
 
+mod config;
+mod emission;
 mod epoch;
 mod faction;
 mod faction_points;
 mod game;
+mod governance;
+mod leaderboard;
+mod merkle;
+mod migrate;
+mod proposals;
 mod rewards;
+mod swap_router;
 mod vault;
+mod vault_backend;
+mod yield_source;
+
+mod oracle;
 
 // External contract type definitions
 mod fee_vault_v2;
+mod pool;
 mod router;
 
 use errors::Error;
-use types::{Config, EpochInfo, GameOutcome};
+use types::{
+    Config, EmissionConfig, EpochInfo, EpochSummary, GameOutcome, Lockup, Proposal,
+    ProposalAction, ProposalStatus, SwapRouterKind, YieldSourceKind, BPS_DENOM,
+};
 
 // ============================================================================
 // Contract Definition
@@ -62,9 +78,19 @@ impl Blendizzard {
     /// * `usdc_token` - USDC token address
     /// * `epoch_duration` - Duration of each epoch in seconds (default: 345,600 = 4 days)
     /// * `reserve_token_ids` - Reserve token IDs for claiming BLND emissions (e.g., vec![&env, 1] for reserve 0 b-tokens)
+    /// * `max_slippage_bps` - Max allowed slippage (in basis points) between the oracle reference price and the Soroswap swap during epoch cycling (e.g. 100 = 1%)
+    /// * `admin_signers` - The m-of-n admin signer set for multisig-gated entrypoints (`add_game`, `update_critical_config`) - see `governance`
+    /// * `admin_threshold` - Approvals required out of `admin_signers` before a proposed action may execute
+    /// * `admin_timelock` - Seconds a fully-approved action must sit before it may execute, on top of `admin_threshold` approvals (`0` disables the delay)
+    /// * `governance_quorum_bps` - Minimum yes-vote share (bps of the snapshot epoch's total FP) a player-proposed `Proposal` needs to pass - see `proposals`
+    /// * `governance_majority_bps` - Minimum yes-vote share (bps of votes cast) a `Proposal` needs to pass
+    /// * `governance_voting_period` - Seconds a `Proposal` stays open for voting after `propose`
+    /// * `governance_timelock` - Seconds a passed `Proposal` must sit past its voting deadline before `execute` may dispatch it (`0` disables the delay)
     ///
     /// # Errors
     /// * `AlreadyInitialized` - If contract has already been initialized
+    /// * `InvalidThreshold` - If `admin_threshold` is `0` or exceeds `admin_signers.len()`
+    #[allow(clippy::too_many_arguments)]
     pub fn __constructor(
         env: Env,
         admin: Address,
@@ -74,13 +100,28 @@ impl Blendizzard {
         usdc_token: Address,
         epoch_duration: u64,
         reserve_token_ids: Vec<u32>,
+        max_slippage_bps: u32,
+        admin_signers: Vec<Address>,
+        admin_threshold: u32,
+        admin_timelock: u64,
+        governance_quorum_bps: u32,
+        governance_majority_bps: u32,
+        governance_voting_period: u64,
+        governance_timelock: u64,
     ) -> Result<(), Error> {
         // Check if already initialized
         if storage::is_initialized(&env) {
             return Err(Error::AlreadyInitialized);
         }
 
+        if admin_threshold == 0 || admin_threshold > admin_signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+
         // Create config (admin and pause state stored separately)
+        // Oracle set starts empty - admin must register keys and a threshold
+        // via `update_config` before `submit_prices`/epoch cycling can use
+        // a signed reference price.
         let config = Config {
             fee_vault,
             soroswap_router,
@@ -88,11 +129,39 @@ impl Blendizzard {
             usdc_token,
             epoch_duration,
             reserve_token_ids,
+            oracle_keys: Vec::new(&env),
+            oracle_threshold: 0,
+            slippage_bps: max_slippage_bps,
+            cycle_bounty_bps: 0, // no keeper bounty until the admin configures one
+            unbonding_epochs: 1, // 1 epoch (4 days) default cooldown
+            min_deposit: 0, // no floor until the admin configures one
+            min_deposit_add: 0,
+            max_total_deposited: i128::MAX, // no cap until the admin configures one
+            max_players: 0, // unlimited until the admin configures one
+            late_deposit_cutoff_bps: BPS_DENOM, // matches the unmodified ramp until configured
+            price_staleness_window: 0, // disabled until the admin configures one
+            min_cycle_interval: 0, // epoch_duration alone gates cycling until configured
+            max_price_deviation_bps: 0, // disabled until the admin configures a TWAP band
+            yield_source_kind: YieldSourceKind::FeeVaultV2,
+            swap_router_kind: SwapRouterKind::Soroswap,
+            min_standings_balance: 0, // standings gate/sqrt-weighting off until the admin configures one
+            epoch_quorum: 0, // no quorum required until the admin configures one
+            vesting_epochs: 0, // rewards pay out in full on first claim until the admin configures vesting
+            leaderboard_size: 0, // leaderboard tracking off until the admin configures a size
+            dispute_window_secs: 0, // sessions settle immediately until the admin configures a window
+            game_auto_slash_threshold: 0, // no auto-slashing until the admin configures a threshold
         };
 
         // Save config, admin, and pause state (all stored separately for single source of truth)
         storage::set_config(&env, &config);
         storage::set_admin(&env, &admin);
+        storage::set_admin_signers(&env, &admin_signers);
+        storage::set_admin_threshold(&env, admin_threshold);
+        storage::set_admin_timelock(&env, admin_timelock);
+        storage::set_governance_quorum_bps(&env, governance_quorum_bps);
+        storage::set_governance_majority_bps(&env, governance_majority_bps);
+        storage::set_governance_voting_period(&env, governance_voting_period);
+        storage::set_governance_timelock(&env, governance_timelock);
         storage::set_pause_state(&env, false); // Contract starts unpaused
 
         // Extend instance TTL for contract-wide data
@@ -137,64 +206,255 @@ impl Blendizzard {
     /// Allows admin to update specific configuration parameters.
     /// Only updates parameters that are provided (non-None).
     ///
+    /// The six most sensitive fields - `fee_vault`, `soroswap_router`,
+    /// `oracle_keys`, `oracle_threshold`, `yield_source_kind`,
+    /// `swap_router_kind` - no longer live here; they route through the
+    /// multisig-gated `update_critical_config` instead, since a compromised
+    /// admin key turning any one of them could redirect funds or whitelist
+    /// a malicious yield/swap path unilaterally. See `governance`.
+    ///
     /// # Arguments
-    /// * `new_fee_vault` - New fee-vault-v2 contract address (optional)
-    /// * `new_soroswap_router` - New Soroswap router contract address (optional)
     /// * `new_blnd_token` - New BLND token address (optional)
     /// * `new_usdc_token` - New USDC token address (optional)
     /// * `new_epoch_duration` - New epoch duration in seconds (optional)
     /// * `new_reserve_token_ids` - New reserve token IDs for claiming BLND emissions (optional)
+    /// * `new_slippage_bps` - New max allowed slippage vs. the oracle reference price, in bps (optional)
+    /// * `new_unbonding_epochs` - New cooldown, in epochs, for `request_withdraw` (optional)
+    /// * `new_min_deposit` - New minimum for a player's first tracked deposit (optional)
+    /// * `new_min_deposit_add` - New minimum for a player's subsequent tracked deposits (optional)
+    /// * `new_max_total_deposited` - New cap on total tracked deposits (optional)
+    /// * `new_late_deposit_cutoff_bps` - New point in an epoch (bps of `epoch_duration`) past which fresh deposits earn no FP that epoch (optional)
+    /// * `new_price_staleness_window` - New max age, in seconds, an oracle reference price stays usable for swapping yield (`0` disables the check) (optional)
+    /// * `new_cycle_bounty_bps` - New share (bps) of the swapped reward pool paid to whoever calls `cycle_epoch` (`0` disables the bounty) (optional)
+    /// * `new_max_players` - New cap on distinct players who may ever select a faction (`0` disables the cap) (optional)
+    /// * `new_min_cycle_interval` - New floor, in seconds, under `epoch_duration` before `cycle_epoch` may act (`0` disables the floor) (optional)
+    /// * `new_max_price_deviation_bps` - New max allowed deviation (bps) between a fresh oracle price and the BLND/USDC TWAP (`0` disables the check) (optional)
+    /// * `new_min_standings_balance` - New minimum effective vault balance for a game's FP to count toward `faction_standings` (`0` disables the gate) (optional)
+    /// * `new_epoch_quorum` - New minimum distinct roster participants required for `cycle_epoch` to declare a winner (`0` disables the check) (optional)
+    /// * `new_vesting_epochs` - New number of epochs a reward share linearly unlocks over (`0` disables vesting, paying in full on first claim) (optional)
+    /// * `new_leaderboard_size` - New cap on entries kept in the per-epoch and all-time leaderboards (`0` disables tracking) (optional)
+    /// * `new_dispute_window_secs` - New length, in seconds, a settled game session stays `Provisional` before it may be finalized (`0` disables the window, settling immediately) (optional)
+    /// * `new_game_auto_slash_threshold` - New number of a game's disputed sessions that triggers automatic `slash_game` (`0` disables auto-slashing) (optional)
     ///
     /// # Errors
     /// * `NotAdmin` - If caller is not the admin
+    #[allow(clippy::too_many_arguments)]
     pub fn update_config(
         env: Env,
-        new_fee_vault: Option<Address>,
-        new_soroswap_router: Option<Address>,
         new_blnd_token: Option<Address>,
         new_usdc_token: Option<Address>,
         new_epoch_duration: Option<u64>,
         new_reserve_token_ids: Option<Vec<u32>>,
+        new_slippage_bps: Option<u32>,
+        new_unbonding_epochs: Option<u32>,
+        new_min_deposit: Option<i128>,
+        new_min_deposit_add: Option<i128>,
+        new_max_total_deposited: Option<i128>,
+        new_late_deposit_cutoff_bps: Option<u32>,
+        new_price_staleness_window: Option<u64>,
+        new_cycle_bounty_bps: Option<u32>,
+        new_max_players: Option<u32>,
+        new_min_cycle_interval: Option<u64>,
+        new_max_price_deviation_bps: Option<u32>,
+        new_min_standings_balance: Option<i128>,
+        new_epoch_quorum: Option<u32>,
+        new_vesting_epochs: Option<u32>,
+        new_leaderboard_size: Option<u32>,
+        new_dispute_window_secs: Option<u64>,
+        new_game_auto_slash_threshold: Option<u32>,
     ) -> Result<(), Error> {
         let admin = storage::get_admin(&env);
         admin.require_auth();
 
+        config::apply_update(
+            &env,
+            &types::ConfigUpdate {
+                new_blnd_token,
+                new_usdc_token,
+                new_epoch_duration,
+                new_reserve_token_ids,
+                new_slippage_bps,
+                new_unbonding_epochs,
+                new_min_deposit,
+                new_min_deposit_add,
+                new_max_total_deposited,
+                new_late_deposit_cutoff_bps,
+                new_price_staleness_window,
+                new_cycle_bounty_bps,
+                new_max_players,
+                new_min_cycle_interval,
+                new_max_price_deviation_bps,
+                new_min_standings_balance,
+                new_epoch_quorum,
+                new_vesting_epochs,
+                new_leaderboard_size,
+                new_dispute_window_secs,
+                new_game_auto_slash_threshold,
+            },
+        );
+
+        // Emit config updated event
+        events::emit_config_updated(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Update the six most sensitive configuration fields - fee vault,
+    /// Soroswap router, oracle key set/threshold, yield source adapter, and
+    /// swap router adapter - gated behind the `admin_signers` multisig
+    /// rather than the single `admin` key, since any one of them could
+    /// redirect funds or whitelist a malicious yield/swap path outright.
+    /// Only updates parameters that are provided (non-None).
+    ///
+    /// `action_hash` must match `hash_update_critical_config_action` computed
+    /// over these exact arguments, and must already have cleared
+    /// `admin_threshold` approvals and `admin_timelock` via
+    /// `propose_action`/`approve_action` - see `governance`.
+    ///
+    /// # Arguments
+    /// * `new_fee_vault` - New fee-vault-v2 contract address (optional)
+    /// * `new_soroswap_router` - New Soroswap router contract address (optional)
+    /// * `new_oracle_keys` - New set of authorized oracle ed25519 public keys (optional)
+    /// * `new_oracle_threshold` - New number of oracle signatures required (optional)
+    /// * `new_yield_source_kind` - New adapter `rewards::withdraw_yield` claims epoch yield from (optional)
+    /// * `new_swap_router_kind` - New adapter `rewards::swap_withdrawn_yield` swaps epoch yield through (optional)
+    ///
+    /// # Errors
+    /// * `ActionHashMismatch` - If `action_hash` doesn't match these arguments
+    /// * `ActionNotProposed` / `ActionNotApproved` / `TimelockNotElapsed` / `ActionAlreadyExecuted` - See `governance::consume_approved_action`
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_critical_config(
+        env: Env,
+        action_hash: BytesN<32>,
+        new_fee_vault: Option<Address>,
+        new_soroswap_router: Option<Address>,
+        new_oracle_keys: Option<Vec<BytesN<32>>>,
+        new_oracle_threshold: Option<u32>,
+        new_yield_source_kind: Option<YieldSourceKind>,
+        new_swap_router_kind: Option<SwapRouterKind>,
+    ) -> Result<(), Error> {
+        let expected_hash = governance::hash_update_critical_config(
+            &env,
+            &new_fee_vault,
+            &new_soroswap_router,
+            &new_oracle_keys,
+            &new_oracle_threshold,
+            &new_yield_source_kind,
+            &new_swap_router_kind,
+        );
+        if expected_hash != action_hash {
+            return Err(Error::ActionHashMismatch);
+        }
+        governance::consume_approved_action(&env, &action_hash)?;
+
         let mut config = storage::get_config(&env);
 
-        // Update fee vault if provided
         if let Some(vault) = new_fee_vault {
             config.fee_vault = vault;
         }
-
-        // Update soroswap router if provided
         if let Some(router) = new_soroswap_router {
             config.soroswap_router = router;
         }
-
-        // Update BLND token if provided
-        if let Some(blnd) = new_blnd_token {
-            config.blnd_token = blnd;
+        if let Some(oracle_keys) = new_oracle_keys {
+            config.oracle_keys = oracle_keys;
         }
-
-        // Update USDC token if provided
-        if let Some(usdc) = new_usdc_token {
-            config.usdc_token = usdc;
+        if let Some(threshold) = new_oracle_threshold {
+            config.oracle_threshold = threshold;
         }
-
-        // Update epoch duration if provided
-        if let Some(duration) = new_epoch_duration {
-            config.epoch_duration = duration;
+        if let Some(yield_source_kind) = new_yield_source_kind {
+            config.yield_source_kind = yield_source_kind;
         }
-
-        // Update reserve token IDs if provided
-        if let Some(reserve_ids) = new_reserve_token_ids {
-            config.reserve_token_ids = reserve_ids;
+        if let Some(swap_router_kind) = new_swap_router_kind {
+            config.swap_router_kind = swap_router_kind;
         }
 
         storage::set_config(&env, &config);
+        events::emit_config_updated(&env, &storage::get_admin(&env));
 
-        // Emit config updated event
-        events::emit_config_updated(&env, &admin);
+        Ok(())
+    }
+
+    // ========================================================================
+    // Multisig Governance
+    // ========================================================================
+
+    /// Propose `action_hash` for multisig approval, counting as the caller's
+    /// own approval - a no-op (beyond that approval) if already proposed
+    ///
+    /// # Errors
+    /// * `NotAdminSigner` - If caller isn't a configured admin signer
+    pub fn propose_action(env: Env, proposer: Address, action_hash: BytesN<32>) -> Result<(), Error> {
+        governance::propose_action(&env, &proposer, &action_hash)
+    }
+
+    /// Record the caller's approval of an already-proposed `action_hash` -
+    /// a no-op if they already approved it
+    ///
+    /// # Errors
+    /// * `NotAdminSigner` - If caller isn't a configured admin signer
+    /// * `ActionNotProposed` - If nobody has `propose_action`'d this hash yet
+    pub fn approve_action(env: Env, signer: Address, action_hash: BytesN<32>) -> Result<(), Error> {
+        governance::approve_action(&env, &signer, &action_hash)
+    }
+
+    /// Get a proposed action's current approval/timelock/execution state
+    pub fn get_action_status(env: Env, action_hash: BytesN<32>) -> types::ActionStatus {
+        governance::get_action_status(&env, &action_hash)
+    }
+
+    /// Compute the action hash `add_game(game_id)` must be proposed/approved
+    /// under before it can execute
+    pub fn hash_add_game_action(env: Env, game_id: Address) -> BytesN<32> {
+        governance::hash_add_game(&env, &game_id)
+    }
+
+    /// Compute the action hash `update_critical_config` must be
+    /// proposed/approved under, for a given set of arguments, before it can
+    /// execute
+    #[allow(clippy::too_many_arguments)]
+    pub fn hash_update_critical_config_action(
+        env: Env,
+        new_fee_vault: Option<Address>,
+        new_soroswap_router: Option<Address>,
+        new_oracle_keys: Option<Vec<BytesN<32>>>,
+        new_oracle_threshold: Option<u32>,
+        new_yield_source_kind: Option<YieldSourceKind>,
+        new_swap_router_kind: Option<SwapRouterKind>,
+    ) -> BytesN<32> {
+        governance::hash_update_critical_config(
+            &env,
+            &new_fee_vault,
+            &new_soroswap_router,
+            &new_oracle_keys,
+            &new_oracle_threshold,
+            &new_yield_source_kind,
+            &new_swap_router_kind,
+        )
+    }
+
+    /// Set the admin-configured emission bonus schedule
+    ///
+    /// `annual_reward` halves every `halving_interval_epochs` epochs (`0`
+    /// disables halving). Takes effect the next time `cycle_epoch` finalizes
+    /// an epoch - already-finalized epochs are unaffected.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn set_emission_config(
+        env: Env,
+        annual_reward: i128,
+        halving_interval_epochs: u32,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_emission_config(
+            &env,
+            &EmissionConfig {
+                annual_reward,
+                halving_interval_epochs,
+            },
+        );
 
         Ok(())
     }
@@ -248,32 +508,144 @@ impl Blendizzard {
         storage::is_paused(&env)
     }
 
+    // ========================================================================
+    // FP-Weighted Governance
+    // ========================================================================
+    // A second, player-facing path onto `update_config`, `add_game`/
+    // `remove_game`, `upgrade`, and `pause`/`unpause`, alongside the admin
+    // and (for `add_game`/`update_critical_config`) multisig paths above -
+    // see `proposals`.
+
+    /// Propose `action` for an FP-weighted vote, returning its proposal id
+    pub fn propose(env: Env, proposer: Address, action: ProposalAction) -> Result<u32, Error> {
+        proposals::propose(&env, &proposer, action)
+    }
+
+    /// Cast `voter`'s vote on `proposal_id`, weighted by their
+    /// `total_fp_contributed` at the proposal's snapshot epoch
+    ///
+    /// # Errors
+    /// * `ProposalNotFound` - If `proposal_id` doesn't exist
+    /// * `ProposalCanceled` - If the admin has canceled this proposal
+    /// * `VotingClosed` - If the voting deadline has passed
+    /// * `AlreadyVoted` - If `voter` already voted on this proposal
+    /// * `NoVotingPower` - If `voter` contributed no FP at the snapshot epoch
+    pub fn vote(env: Env, voter: Address, proposal_id: u32, support: bool) -> Result<(), Error> {
+        proposals::vote(&env, &voter, proposal_id, support)
+    }
+
+    /// Dispatch a passed proposal's action through the same code path the
+    /// matching admin entrypoint uses
+    ///
+    /// # Errors
+    /// * `ProposalNotFound` - If `proposal_id` doesn't exist
+    /// * `ProposalAlreadyExecuted` - If this proposal already executed
+    /// * `ProposalNotPassed` - If voting hasn't closed, or closed without quorum/majority
+    /// * `TimelockNotElapsed` - If the timelock hasn't elapsed since the voting deadline
+    pub fn execute(env: Env, proposal_id: u32) -> Result<(), Error> {
+        proposals::execute(&env, proposal_id)
+    }
+
+    /// Admin backstop: cancel a not-yet-executed proposal
+    ///
+    /// # Errors
+    /// * `ProposalNotFound` - If `proposal_id` doesn't exist
+    /// * `ProposalAlreadyExecuted` - If this proposal already executed
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn cancel(env: Env, proposal_id: u32) -> Result<(), Error> {
+        proposals::cancel(&env, proposal_id)
+    }
+
+    /// Get a governance proposal's full state
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<Proposal> {
+        storage::get_proposal(&env, proposal_id)
+    }
+
+    /// Get a governance proposal's current status
+    pub fn get_proposal_status(env: Env, proposal_id: u32) -> Option<ProposalStatus> {
+        storage::get_proposal(&env, proposal_id)
+            .map(|proposal| proposals::get_proposal_status(&env, &proposal))
+    }
+
     // ========================================================================
     // Migration Functions
     // ========================================================================
 
-    /// Migration: Update Player struct from old formats to current format
+    /// Migration: Bring a player's entry up to the current schema version
+    ///
+    /// Player data is tagged with a schema version (`DataKey::PlayerSchema`)
+    /// and `migrate::upgrade_player` runs exactly the ordered steps needed to
+    /// take an entry from its tagged version to
+    /// `migrate::PLAYER_CURRENT_VERSION`, rather than this function knowing
+    /// about every historical struct shape itself. `get_player` already calls
+    /// this lazily on every read, so in practice this entrypoint only
+    /// matters for callers who want migration to happen as its own
+    /// transaction rather than piggybacking on the next read.
+    ///
+    /// # Arguments
+    /// * `player` - Player address to migrate
     ///
-    /// This migration fixes deserialization errors caused by Player struct schema changes:
-    /// - V0 (pre-Nov 10): Had `total_deposited` field instead of `last_epoch_balance`
-    /// - V1 (Nov 10-12): Had `deposit_timestamp` field instead of `time_multiplier_start`
-    /// - V2 (current): Uses `time_multiplier_start` and `last_epoch_balance`
+    /// # Returns
+    /// * `true` if one or more migration steps ran
+    /// * `false` if the player was already current, or doesn't exist
     ///
-    /// The migration reads old formats, deletes them, and writes back the current format.
+    /// # Errors
+    /// * `CorruptStorage` - If the entry's schema tag doesn't match any
+    ///   layout `migrate` knows how to read - call `diagnose_player` first
+    ///   to check before retrying.
+    pub fn migrate_player(env: Env, player: Address) -> Result<bool, Error> {
+        migrate::upgrade_player(&env, &player)
+    }
+
+    /// Migration: Fold a player's legacy per-epoch `Claimed` temporary flag
+    /// into their persistent `ClaimBitmap`
     ///
-    /// # Usage
-    /// Call this for each player address that needs migration. This is typically called:
-    /// - By players themselves when they encounter deserialization errors
-    /// - By admin for known active players
+    /// Claim tracking used to be one `Claimed(player, epoch)` temporary
+    /// flag per epoch, which could expire and reopen a double-claim window.
+    /// It's now a single persistent bitmap shared across 128 epochs. This
+    /// folds any surviving legacy flag in before it can lapse.
     ///
     /// # Arguments
     /// * `player` - Player address to migrate
+    /// * `epoch` - Epoch whose legacy claim flag should be folded in
+    ///
+    /// # Returns
+    /// * `true` if migration was performed (a legacy flag was found and folded in)
+    /// * `false` if there was no legacy flag, or it was already folded in
+    pub fn migrate_claimed(env: Env, player: Address, epoch: u32) -> bool {
+        storage::migrate_claimed_storage(&env, &player, epoch)
+    }
+
+    /// Migration: Bring an epoch player's entry up to the current schema
+    /// version, the `EpochPlayer` analogue of `migrate_player`
+    ///
+    /// # Arguments
+    /// * `epoch` - Epoch the entry belongs to
+    /// * `player` - Player address to migrate
+    ///
+    /// # Returns
+    /// * `true` if one or more migration steps ran
+    /// * `false` if the entry was already current, or doesn't exist
+    ///
+    /// # Errors
+    /// * `CorruptStorage` - Same convention as `migrate_player`.
+    pub fn migrate_epoch_player(env: Env, epoch: u32, player: Address) -> Result<bool, Error> {
+        migrate::upgrade_epoch_player(&env, epoch, &player)
+    }
+
+    /// Read-only status check for a player's persistent entry, so front-ends
+    /// and the `migrate_player` flow can detect and route around a bad
+    /// record deterministically instead of discovering it through a trapped
+    /// transaction.
     ///
     /// # Returns
-    /// * `true` if migration was performed (player had V0 or V1 data)
-    /// * `false` if player data doesn't exist or is already in V2 format
-    pub fn migrate_player(env: Env, player: Address) -> bool {
-        storage::migrate_player_storage(&env, &player)
+    /// * `StorageStatus::Current` - entry is current, safe to read normally
+    /// * `StorageStatus::NeedsMigration(version)` - call `migrate_player`
+    /// * `StorageStatus::Corrupt(version)` - schema tag unrecognized; don't
+    ///   call `migrate_player`, it will return `CorruptStorage` too
+    /// * `StorageStatus::Absent` - player has never interacted with the contract
+    pub fn diagnose_player(env: Env, player: Address) -> types::StorageStatus {
+        storage::diagnose_player(&env, &player)
     }
 
     // ========================================================================
@@ -282,10 +654,17 @@ impl Blendizzard {
 
     /// Add a game contract to the approved list
     ///
+    /// Multisig-gated: `action_hash` must match `hash_add_game_action(id)`
+    /// and must already have cleared `admin_threshold` approvals and
+    /// `admin_timelock` via `propose_action`/`approve_action` - so a single
+    /// compromised key can't unilaterally whitelist a malicious game
+    /// contract that calls `end_game`. See `governance`.
+    ///
     /// # Errors
-    /// * `NotAdmin` - If caller is not the admin
-    pub fn add_game(env: Env, id: Address) -> Result<(), Error> {
-        game::add_game(&env, &id)
+    /// * `ActionHashMismatch` - If `action_hash` doesn't match `id`
+    /// * `ActionNotProposed` / `ActionNotApproved` / `TimelockNotElapsed` / `ActionAlreadyExecuted` - See `governance::consume_approved_action`
+    pub fn add_game(env: Env, id: Address, action_hash: BytesN<32>) -> Result<(), Error> {
+        game::add_game(&env, &id, &action_hash)
     }
 
     /// Remove a game contract from the approved list
@@ -301,6 +680,15 @@ impl Blendizzard {
         game::is_game(&env, &id)
     }
 
+    /// Remove `game_id` from the allow-list and invalidate every one of its
+    /// still-`Provisional` sessions, refunding each one's participants
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn slash_game(env: Env, game_id: Address) -> Result<(), Error> {
+        game::slash_game(&env, &game_id)
+    }
+
     // ========================================================================
     // Vault Operations (REMOVED - Players interact directly with fee-vault-v2)
     // ========================================================================
@@ -315,6 +703,183 @@ impl Blendizzard {
     //
     // The 50% withdrawal reset rule is enforced via cross-epoch balance comparison
     // when players play their first game of a new epoch.
+    //
+    // Instant withdrawals straight through fee-vault-v2 still let a player
+    // dodge the FP/time-multiplier consequences of leaving by timing the
+    // call around an epoch boundary. request_withdraw/withdraw_unbonded
+    // below offer an optional two-phase alternative through Blendizzard
+    // itself for players who want a clean exit instead.
+
+    /// Queue `amount` of the caller's fee-vault balance for withdrawal
+    ///
+    /// Debits the player's effective balance immediately, so it stops
+    /// accruing faction points right away, but the funds stay in fee-vault-v2
+    /// until `withdraw_unbonded` is called after `config.unbonding_epochs`
+    /// have passed.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If amount is <= 0
+    /// * `InsufficientBalance` - If amount exceeds the player's effective balance
+    /// * `UnbondingQueueFull` - If the player already has the maximum number
+    ///   of chunks outstanding
+    pub fn request_withdraw(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        vault::request_withdraw(&env, &player, amount)
+    }
+
+    /// Release every one of the caller's queued withdrawals that has reached
+    /// its unlock epoch
+    ///
+    /// # Errors
+    /// * `NoMaturedWithdrawals` - If no queued chunk has reached its unlock epoch
+    /// * `FeeVaultError` - If the fee-vault withdrawal fails
+    ///
+    /// # Returns
+    /// The total amount released
+    pub fn withdraw_unbonded(env: Env, player: Address) -> Result<i128, Error> {
+        vault::withdraw_unbonded(&env, &player)
+    }
+
+    // ========================================================================
+    // Deposit-Weighted Yield Accumulator
+    // ========================================================================
+    //
+    // A MasterChef-style accumulator that lets yield be credited to every
+    // yield-tracked player in O(1) regardless of how many there are, rather
+    // than iterating them. Players deposit/withdraw straight against
+    // fee-vault-v2 (see the ARCHITECTURE CHANGE note above), so Blendizzard
+    // has no way to observe a deposit landing - `track_deposit`/
+    // `track_withdrawal` below are how a player opts a deposit they already
+    // hold into this accumulator, self-reported and authorized by the
+    // player, capped against their real fee-vault balance in
+    // `vault::apply_tracked_deposit` so nobody can track more than they
+    // actually hold. `distribute_yield` and `claim_yield` are fully
+    // functional against whatever has been tracked this way.
+
+    /// Register `amount` of the caller's existing fee-vault deposit into the
+    /// yield accumulator
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If amount is <= 0
+    /// * `DepositBelowMinimum` - If amount is below `config.min_deposit` /
+    ///   `config.min_deposit_add`
+    /// * `VaultCapacityExceeded` - If amount would push total tracked
+    ///   deposits above `config.max_total_deposited`
+    /// * `InsufficientBalance` - If amount would track more than the
+    ///   caller's real effective fee-vault balance
+    pub fn track_deposit(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        vault_backend::backend(&env).credit(&env, &player, amount)
+    }
+
+    /// Remove `amount` from the caller's yield-tracked balance
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If amount is <= 0
+    /// * `InsufficientBalance` - If amount exceeds the caller's tracked balance
+    pub fn track_withdrawal(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        vault_backend::backend(&env).debit(&env, &player, amount)
+    }
+
+    /// Register `amount` of the caller's existing fee-vault deposit into the
+    /// yield accumulator under a lockup commitment, in exchange for the
+    /// boosted reward weight `faction_points::lockup_bonus_fp` grants while
+    /// it's locked
+    ///
+    /// # Errors
+    /// Same as `track_deposit`
+    pub fn lock_deposit(
+        env: Env,
+        player: Address,
+        amount: i128,
+        kind: types::LockupKind,
+    ) -> Result<(), Error> {
+        vault::record_locked_deposit(&env, &player, amount, kind)
+    }
+
+    /// Credit `amount` of yield across every yield-tracked deposit,
+    /// proportional to each player's tracked balance
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `InvalidAmount` - If amount is <= 0
+    pub fn distribute_yield(env: Env, amount: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        vault::distribute_yield(&env, amount)
+    }
+
+    /// Pay out the caller's settled yield and zero their claimable balance
+    ///
+    /// # Errors
+    /// * `NoRewardsAvailable` - If the caller has nothing claimable
+    ///
+    /// # Returns
+    /// The amount paid out, in USDC
+    pub fn claim_yield(env: Env, player: Address) -> Result<i128, Error> {
+        vault::claim_yield(&env, &player)
+    }
+
+    // ========================================================================
+    // Multi-Asset Deposit Routing
+    // ========================================================================
+    //
+    // `Config.fee_vault` is a single default vault; this registry lets
+    // additional deposit assets route to their own fee-vault/pool pair via
+    // `track_deposit_asset` below, which like `track_deposit` above is a
+    // player-authorized self-report capped against the caller's real
+    // balance (in `asset`'s own vault) rather than a transfer.
+
+    /// Register (or replace) the fee-vault/pool pair that deposits of
+    /// `asset` route to
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn register_asset_vault(
+        env: Env,
+        asset: Address,
+        vault: Address,
+        pool: Address,
+    ) -> Result<(), Error> {
+        crate::vault::register_asset_vault(&env, &asset, &vault, &pool)
+    }
+
+    /// Deregister `asset`, rejecting future deposits of it until re-registered
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    pub fn deregister_asset_vault(env: Env, asset: Address) -> Result<(), Error> {
+        crate::vault::deregister_asset_vault(&env, &asset)
+    }
+
+    /// Get the vault address registered for `asset`, if any
+    pub fn get_asset_vault(env: Env, asset: Address) -> Option<Address> {
+        crate::vault::get_asset_vault(&env, &asset)
+    }
+
+    /// Register `amount` of the caller's existing deposit of `asset` into
+    /// the yield accumulator, normalized into the same common unit
+    /// `track_deposit` uses
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - If amount is <= 0
+    /// * `UnsupportedAsset` - If `asset` has no registered vault/pool
+    /// * `InsufficientBalance` - If amount exceeds the caller's real balance
+    ///   in `asset`'s registered vault
+    /// * `DepositBelowMinimum` / `VaultCapacityExceeded` - See `track_deposit`
+    pub fn track_deposit_asset(
+        env: Env,
+        player: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        crate::vault::record_deposit_for_asset(&env, &player, &asset, amount)
+    }
+
+    /// Get a player's current lockup commitment (`Lockup::NONE` if they have
+    /// none). See `vault::record_locked_deposit`.
+    pub fn get_lockup(env: Env, player: Address) -> Lockup {
+        storage::get_lockup(&env, &player)
+    }
 
     // ========================================================================
     // Faction Selection
@@ -347,8 +912,24 @@ impl Blendizzard {
     ///
     /// # Errors
     /// * `PlayerNotFound` - If player has never interacted with the contract
+    /// * `CorruptStorage` - If the entry's schema tag doesn't match any
+    ///   layout `migrate` knows how to read - call `diagnose_player` first.
     pub fn get_player(env: Env, player: Address) -> Result<types::Player, Error> {
-        storage::get_player(&env, &player).ok_or(Error::PlayerNotFound)
+        storage::get_player(&env, &player)?.ok_or(Error::PlayerNotFound)
+    }
+
+    /// Get player information along with its persistent-storage liveness
+    ///
+    /// Safe to call on a player who's been dormant long enough to archive -
+    /// returns `PlayerAccess::Missing` instead of trapping, and
+    /// `PlayerAccess::Restored` when the read renewed the entry's TTL just
+    /// ahead of archival. Lets a simulate-then-submit client notice a
+    /// restore is imminent (and its fee) before the real transaction runs.
+    pub fn get_player_checked(
+        env: Env,
+        player: Address,
+    ) -> (types::PlayerAccess, Option<types::Player>) {
+        storage::get_player_checked(&env, &player)
     }
 
     /// Get player's epoch-specific information for the current epoch
@@ -362,17 +943,19 @@ impl Blendizzard {
     ///
     /// # Errors
     /// * `FactionNotSelected` - If player hasn't selected a faction yet
+    /// * `CorruptStorage` - If either entry's schema tag doesn't match any
+    ///   layout `migrate` knows how to read - call `diagnose_player` first.
     pub fn get_epoch_player(env: Env, player: Address) -> Result<types::EpochPlayer, Error> {
         let current_epoch = storage::get_current_epoch(&env);
 
         // Try to get existing epoch player data
-        if let Some(epoch_player) = storage::get_epoch_player(&env, current_epoch, &player) {
+        if let Some(epoch_player) = storage::get_epoch_player(&env, current_epoch, &player)? {
             return Ok(epoch_player);
         }
 
         // Player hasn't played this epoch yet - calculate FP on-the-fly
         // First, check if player has selected a faction
-        storage::get_player(&env, &player).ok_or(Error::FactionNotSelected)?;
+        storage::get_player(&env, &player)?.ok_or(Error::FactionNotSelected)?;
 
         // Calculate FP using same logic as initialize_player_epoch
         let total_fp = faction_points::calculate_faction_points(&env, &player)?;
@@ -383,7 +966,6 @@ impl Blendizzard {
             epoch_faction: None, // Faction not locked until first game
             epoch_balance_snapshot: current_balance,
             available_fp: total_fp,
-            locked_fp: 0,
             total_fp_contributed: 0,
         })
     }
@@ -402,6 +984,8 @@ impl Blendizzard {
     /// * `SessionAlreadyExists` - If session_id already exists
     /// * `InvalidAmount` - If wagers are <= 0
     /// * `InsufficientFactionPoints` - If players don't have enough fp
+    /// * `FeeVaultError` / `SwapError` - If the epoch's play window already
+    ///   ended and cycling it to start a fresh one fails
     /// * `ContractPaused` - If contract is in emergency pause mode
     pub fn start_game(
         env: Env,
@@ -424,17 +1008,47 @@ impl Blendizzard {
         )
     }
 
+    /// Start a new multi-participant game session (free-for-all or team match)
+    ///
+    /// Pools every listed player's wager into a single pot, to be split on
+    /// `end_game` according to the `GameOutcome::Split` basis-point shares.
+    ///
+    /// If the current epoch's play window has already ended, this cycles the
+    /// epoch first so the session's FP lands in the new epoch's live
+    /// accumulators rather than a stale epoch about to be frozen.
+    ///
+    /// # Errors
+    /// * `GameNotWhitelisted` - If game_id is not approved
+    /// * `SessionAlreadyExists` - If session_id already exists
+    /// * `InvalidAmount` - If players/wagers lengths mismatch, fewer than 2
+    ///   participants, or any wager is <= 0
+    /// * `InsufficientFactionPoints` - If any player doesn't have enough fp
+    /// * `FeeVaultError` / `SwapError` - If the epoch's play window already
+    ///   ended and cycling it to start a fresh one fails
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn start_game_multi(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        wagers: Vec<i128>,
+    ) -> Result<(), Error> {
+        storage::require_not_paused(&env)?;
+        game::start_game_multi(&env, &game_id, session_id, players, wagers)
+    }
+
     /// End a game session with outcome verification
     ///
-    /// Requires game contract authorization. Both players' FP wagers are spent/burned.
-    /// Only the winner's wager contributes to their faction standings.
-    /// ZK proof verification handled client-side for MVP.
+    /// Requires game contract authorization. On a decisive `Winner` result
+    /// the loser's wager is burned and the winner's contributes to their
+    /// faction standings; on a `Draw` each player's own wager is refunded
+    /// instead. A non-empty `proof` is independently recomputed for games
+    /// whose proof layout `end_game` knows - see `game::end_game`.
     ///
     /// # Errors
     /// * `SessionNotFound` - If session doesn't exist
     /// * `InvalidSessionState` - If session is not Pending
-    /// * `InvalidGameOutcome` - If outcome data doesn't match session
-    /// * `ProofVerificationFailed` - If ZK proof is invalid
+    /// * `InvalidGameOutcome` - If outcome data, or a non-empty proof, doesn't match the session
     pub fn end_game(
         env: Env,
         game_id: Address,
@@ -445,6 +1059,67 @@ impl Blendizzard {
         game::end_game(&env, &game_id, session_id, &proof, &outcome)
     }
 
+    /// Settle a `Provisional` session once `Config.dispute_window_secs` has
+    /// elapsed since `end_game`, applying its outcome and emitting
+    /// `GameEnded`. Callable by anyone - a no-op decision point once the
+    /// window has passed.
+    ///
+    /// # Errors
+    /// * `SessionNotFound` - If session doesn't exist
+    /// * `InvalidSessionState` - If session is not `Provisional`
+    /// * `DisputeWindowNotElapsed` - If the dispute window hasn't elapsed yet
+    pub fn finalize_game(env: Env, session_id: u32) -> Result<(), Error> {
+        game::finalize_game(&env, session_id)
+    }
+
+    /// Revert a still-`Provisional` session within its dispute window,
+    /// refunding every participant's wager instead of crediting the reported
+    /// outcome. May trigger `slash_game` automatically if this pushes the
+    /// game's disputed-session count to `Config.game_auto_slash_threshold`.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `SessionNotFound` - If session doesn't exist
+    /// * `GameSessionMismatch` - If `game_id` doesn't own `session_id`
+    /// * `InvalidSessionState` - If session is not `Provisional`
+    /// * `DisputeWindowElapsed` - If the dispute window has already passed
+    pub fn dispute_game(env: Env, game_id: Address, session_id: u32) -> Result<(), Error> {
+        game::dispute_game(&env, &game_id, session_id)
+    }
+
+    // ========================================================================
+    // Oracle Reference Prices
+    // ========================================================================
+
+    /// Submit an oracle-signed BLND/USDC reference price for an epoch
+    ///
+    /// Each signature must be from a key in the registered oracle set, over
+    /// `sha256(epoch_id || blnd_usdc_price)`. Once enough distinct registered
+    /// keys have signed (per `Config.oracle_threshold`), the median becomes
+    /// the epoch's immutable reference price, used to bound slippage on the
+    /// BLND -> USDC swap during `cycle_epoch`.
+    ///
+    /// # Errors
+    /// * `EpochAlreadyFinalized` - If the epoch already has a reference price
+    /// * `OracleKeyNotAuthorized` - If a signature's key isn't registered
+    /// * `DuplicateOracleSigner` - If a key already submitted for this epoch
+    pub fn submit_prices(
+        env: Env,
+        epoch_id: u32,
+        blnd_usdc_price: i128,
+        signatures: Vec<(BytesN<32>, BytesN<64>)>,
+    ) -> Result<(), Error> {
+        oracle::submit_prices(&env, epoch_id, blnd_usdc_price, signatures)
+    }
+
+    /// Get the finalized median BLND/USDC reference price for an epoch
+    ///
+    /// # Errors
+    /// * `ReferencePriceNotSet` - If fewer than the threshold oracles have signed yet
+    pub fn get_reference_price(env: Env, epoch_id: u32) -> Result<i128, Error> {
+        oracle::get_reference_price(&env, epoch_id)
+    }
+
     // ========================================================================
     // Epoch Management
     // ========================================================================
@@ -459,21 +1134,113 @@ impl Blendizzard {
         epoch::get_epoch(&env, epoch)
     }
 
+    /// Get a finalized epoch's permanently-archived summary (winner, pool,
+    /// per-faction totals, participant count) - for leaderboard/analytics
+    /// frontends reading history, not just the live epoch.
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If `epoch_number` hasn't finalized yet (or doesn't exist)
+    pub fn get_epoch_summary(env: Env, epoch_number: u32) -> Result<EpochSummary, Error> {
+        epoch::get_epoch_summary(&env, epoch_number)
+    }
+
     /// Cycle to the next epoch
     ///
-    /// Finalizes current epoch (determines winner, withdraws BLND, swaps to USDC,
-    /// sets reward pool) and opens next epoch.
+    /// Permissionless - anyone can call this once an epoch is due (see
+    /// `get_epoch`/`is_epoch_ready`), and earns `config.cycle_bounty_bps` of
+    /// the swapped reward pool in USDC for doing so, so timely cycling
+    /// doesn't depend on the admin being online. Finalizes current epoch
+    /// (determines winner, harvests BLND and swaps it to USDC against a
+    /// fresh oracle reference price, pays `caller`'s bounty, sets reward
+    /// pool) and opens next epoch. If no fresh reference price is available,
+    /// the swap is deferred and the unclaimed BLND rolls forward to a later
+    /// epoch rather than swapping blind or failing this call.
+    ///
+    /// A convenience wrapper around `cycle_epoch_step` that runs its phases
+    /// (see `FinalizationPhase`) back-to-back within one generous budget. If
+    /// a heavier finalization is still mid-flight once that budget is spent
+    /// (e.g. a prior call already started it but didn't finish), this
+    /// returns `EpochFinalizationInProgress` instead of a new epoch number -
+    /// call `cycle_epoch_step` directly to keep advancing it across
+    /// multiple transactions.
+    ///
+    /// # Arguments
+    /// * `caller` - Address to credit the cycle bounty to; must authorize this call
     ///
     /// # Returns
     /// The new epoch number
     ///
     /// # Errors
-    /// * `EpochNotReady` - If not enough time has passed
+    /// * `EpochNotReady` - If not enough time has passed and no finalization is already in progress
     /// * `EpochAlreadyFinalized` - If current epoch is already finalized
+    /// * `EpochFinalizationInProgress` - If finalization didn't complete within this call's budget
     /// * `FeeVaultError` - If fee-vault operations fail
     /// * `SwapError` - If BLND → USDC swap fails
-    pub fn cycle_epoch(env: Env) -> Result<u32, Error> {
-        epoch::cycle_epoch(&env)
+    pub fn cycle_epoch(env: Env, caller: Address) -> Result<u32, Error> {
+        epoch::cycle_epoch(&env, &caller)
+    }
+
+    /// Advance the current epoch's finalization by at most `max_items`
+    /// `FinalizationPhase` steps, so a keeper can spread a heavier
+    /// finalization across multiple transactions instead of risking
+    /// `cycle_epoch` exhausting its budget in one call. See
+    /// `epoch::cycle_epoch_step`.
+    ///
+    /// # Arguments
+    /// * `caller` - Address to credit the cycle bounty to once finalization completes; must authorize this call
+    /// * `max_items` - Maximum number of finalization phases to advance this call
+    ///
+    /// # Returns
+    /// `true` if finalization is still mid-flight after this call (call
+    /// again to keep advancing it), `false` once it's reached `Done` and the
+    /// next epoch has opened
+    ///
+    /// # Errors
+    /// * `EpochNotReady` - If not enough time has passed and no finalization is already in progress
+    /// * `EpochAlreadyFinalized` - If current epoch is already finalized
+    /// * `FeeVaultError` - If fee-vault operations fail
+    /// * `SwapError` - If BLND → USDC swap fails
+    pub fn cycle_epoch_step(env: Env, caller: Address, max_items: u32) -> Result<bool, Error> {
+        epoch::cycle_epoch_step(&env, &caller, max_items)
+    }
+
+    /// Get the cap on distinct players who may ever select a faction (`0` = unlimited)
+    pub fn get_max_players(env: Env) -> u32 {
+        storage::get_config(&env).max_players
+    }
+
+    /// Get the floor, in seconds, `cycle_epoch` enforces under `epoch_duration` (`0` = disabled)
+    pub fn get_min_cycle_interval(env: Env) -> u64 {
+        storage::get_config(&env).min_cycle_interval
+    }
+
+    /// Check whether `cycle_epoch` may be called right now
+    ///
+    /// Evaluates the same `ledger.timestamp >= epoch_start + epoch_duration`
+    /// predicate `cycle_epoch` uses internally, so keepers can poll this
+    /// instead of simulating `cycle_epoch` itself.
+    pub fn is_epoch_ready(env: Env) -> Result<bool, Error> {
+        let current_epoch = storage::get_current_epoch(&env);
+        let epoch_info = epoch::get_epoch(&env, Some(current_epoch))?;
+        let config = storage::get_config(&env);
+        Ok(epoch::should_end_epoch(&env, &epoch_info, &config))
+    }
+
+    /// Get the live (pre-finalization) per-faction FP standings for an epoch
+    ///
+    /// Once the epoch is finalized this matches `EpochInfo.faction_standings`
+    /// exactly - it's the same snapshot `cycle_epoch` freezes.
+    pub fn get_faction_standings(env: Env, epoch: u32) -> [u128; 3] {
+        storage::get_faction_standings(&env, epoch)
+    }
+
+    /// Get the scheduled emission bonus for an epoch, on top of swapped yield
+    ///
+    /// # Errors
+    /// * `EmissionNotConfigured` - If no `EmissionConfig` has been set
+    /// * `EpochNotFinalized` - If the epoch doesn't exist yet
+    pub fn get_epoch_emission(env: Env, epoch: u32) -> Result<i128, Error> {
+        emission::get_epoch_emission(&env, epoch)
     }
 
     // ========================================================================
@@ -485,22 +1252,256 @@ impl Blendizzard {
     /// Players who contributed FP to the winning faction can claim their share
     /// of the epoch's reward pool (USDC converted from BLND yield).
     ///
+    /// If `Config.vesting_epochs` is nonzero, this doesn't pay the full share
+    /// in one call - the first call starts a linear unlock schedule (see
+    /// `get_vesting`) and pays whatever's already unlocked, and calling again
+    /// in later epochs tops the player up to however much more has unlocked
+    /// since.
+    ///
     /// **Note:** To check claimable amounts or claim status before calling,
     /// use transaction simulation. This is the idiomatic Soroban pattern.
     ///
     /// # Returns
-    /// Amount of USDC claimed
+    /// Amount of USDC claimed (or newly unlocked, under vesting)
     ///
     /// # Errors
     /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
-    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch (non-vesting only)
     /// * `NotWinningFaction` - If player wasn't in the winning faction
-    /// * `NoRewardsAvailable` - If player has no rewards to claim
+    /// * `NoRewardsAvailable` - If player has no rewards to claim, or (vesting only) nothing new has unlocked since their last claim
     /// * `ContractPaused` - If contract is in emergency pause mode
     pub fn claim_epoch_reward(env: Env, player: Address, epoch: u32) -> Result<i128, Error> {
         storage::require_not_paused(&env)?;
         rewards::claim_epoch_reward(&env, &player, epoch)
     }
+
+    /// Claim epoch rewards for a player across several epochs in one call
+    ///
+    /// Equivalent to calling `claim_epoch_reward` once per epoch in `epochs`,
+    /// except ineligible epochs (not finalized, no winner, not the winning
+    /// faction, nothing claimable, already claimed) are skipped instead of
+    /// failing the whole call, and every epoch's amount is summed into one
+    /// USDC transfer.
+    ///
+    /// # Returns
+    /// Total USDC claimed (or newly unlocked, under vesting) across `epochs`
+    ///
+    /// # Errors
+    /// * `InvalidEpochBatch` - If `epochs` is empty or exceeds the per-call batch limit
+    /// * `NoRewardsAvailable` - If no epoch in `epochs` had anything claimable
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_epochs(env: Env, player: Address, epochs: Vec<u32>) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        rewards::claim_epochs(&env, &player, epochs)
+    }
+
+    /// Preview a player's claimable share of a finalized epoch's reward pool
+    ///
+    /// Computed lazily from the epoch's frozen reward pool, so it reflects
+    /// the correct amount whether or not `distribute_rewards_partition` has
+    /// reached this player yet. Returns 0 once the player has already
+    /// claimed, rather than an error.
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `NotWinningFaction` - If player wasn't in the winning faction
+    pub fn get_claimable_amount(env: Env, player: Address, epoch: u32) -> Result<i128, Error> {
+        rewards::get_claimable_amount(&env, &player, epoch)
+    }
+
+    /// Preview a player's pending reward from the most recently finalized
+    /// epoch - a caller-convenience wrapper over `get_claimable_amount` that
+    /// doesn't require knowing the current epoch number. Returns 0 before
+    /// the first `cycle_epoch` call.
+    ///
+    /// # Errors
+    /// * `NotWinningFaction` - If player wasn't in the winning faction
+    pub fn get_pending_rewards(env: Env, player: Address) -> Result<i128, Error> {
+        rewards::get_pending_rewards(&env, &player)
+    }
+
+    /// Get a real-time settlement-progress view over a finalized epoch's
+    /// reward round
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    pub fn get_epoch_rewards(env: Env, epoch: u32) -> Result<types::EpochRewards, Error> {
+        rewards::get_epoch_rewards(&env, epoch)
+    }
+
+    /// Preview a player's vesting status for an epoch's reward claim - total,
+    /// vested, withdrawn, and unvested amounts - so UIs can render an unlock
+    /// countdown. Returns `None` until the player's first `claim_epoch_reward`
+    /// call against this epoch creates the vesting schedule (including when
+    /// `Config.vesting_epochs` is `0`).
+    pub fn get_vesting(env: Env, player: Address, epoch: u32) -> Option<types::VestingStatus> {
+        rewards::get_vesting(&env, &player, epoch)
+    }
+
+    // ========================================================================
+    // Leaderboard
+    // ========================================================================
+
+    /// Get an epoch's top-N leaderboard - `(player, total_fp_contributed)`
+    /// pairs, sorted descending. Empty for an epoch that predates
+    /// `Config.leaderboard_size` being configured, or that never had an
+    /// eligible contribution.
+    pub fn get_epoch_leaderboard(env: Env, epoch: u32) -> Vec<(Address, i128)> {
+        storage::get_epoch_leaderboard(&env, epoch)
+    }
+
+    /// Get the all-time cumulative leaderboard - `(player, lifetime_total)`
+    /// pairs, sorted descending across every epoch.
+    pub fn get_alltime_leaderboard(env: Env) -> Vec<(Address, i128)> {
+        storage::get_alltime_leaderboard(&env)
+    }
+
+    // ========================================================================
+    // Merkle Reward Claims
+    // ========================================================================
+
+    /// Submit the Merkle root over a finalized epoch's reward leaves -
+    /// `merkle::leaf_hash(player, amount)` for every eligible player,
+    /// computed off-chain the same way `oracle::submit_prices` hands off an
+    /// off-chain reference price. One-shot per epoch.
+    ///
+    /// # Errors
+    /// * `NotAdmin` - If caller is not the admin
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `RewardRootAlreadySet` - If this epoch already has a root
+    pub fn set_epoch_reward_root(env: Env, epoch: u32, root: BytesN<32>) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        rewards::set_epoch_reward_root(&env, epoch, &root)
+    }
+
+    /// Get the reward Merkle root submitted for a finalized epoch, if any
+    pub fn get_epoch_reward_root(env: Env, epoch: u32) -> Option<BytesN<32>> {
+        rewards::get_epoch_reward_root(&env, epoch)
+    }
+
+    /// Claim `amount` of USDC for `player`'s reward leaf in `epoch`,
+    /// authorized by a Merkle `proof` against that epoch's submitted root
+    /// rather than the live FP bookkeeping `claim_epoch_reward` reads.
+    /// Shares the same double-claim guard and `DistributedRewards` invariant
+    /// as `claim_epoch_reward`/`distribute_rewards_partition`, so all three
+    /// claim paths can be mixed freely for the same epoch.
+    ///
+    /// # Returns
+    /// Amount of USDC claimed
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `RewardRootNotSet` - If no root has been submitted for this epoch yet
+    /// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+    /// * `InvalidMerkleProof` - If `proof` doesn't authorize `(player, amount)` against the stored root
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn claim_reward(
+        env: Env,
+        player: Address,
+        epoch: u32,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        rewards::claim_reward(&env, &player, epoch, amount, proof)
+    }
+
+    /// Push reward shares to a slice of a finalized epoch's winning-faction
+    /// roster, so large epochs can be settled across several calls
+    ///
+    /// `start_index`/`count` slice the epoch's join-ordered participant
+    /// roster. Permissionless and idempotent per player - ineligible or
+    /// already-credited participants in the range are silently skipped, so
+    /// sweeping `[0, participant_count)` in back-to-back partitions is always
+    /// safe and never double-credits.
+    ///
+    /// # Returns
+    /// The number of players credited by this call
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `InvalidPartitionRange` - If `count` is zero or `start_index` is beyond the roster
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn distribute_rewards_partition(
+        env: Env,
+        epoch: u32,
+        start_index: u32,
+        count: u32,
+    ) -> Result<u32, Error> {
+        storage::require_not_paused(&env)?;
+        rewards::distribute_rewards_partition(&env, epoch, start_index, count)
+    }
+
+    /// Number of distinct players who have joined an epoch's roster so far
+    ///
+    /// Callers sweeping `distribute_rewards_partition` in pages can use this
+    /// to know when `start_index` has reached the end of the roster.
+    pub fn roster_len(env: Env, epoch: u32) -> u32 {
+        storage::roster_len(&env, epoch)
+    }
+
+    /// One fixed-size page of an epoch's participant roster, in join order
+    ///
+    /// Pages hold at most `ROSTER_PAGE_SIZE` entries; an out-of-range page
+    /// returns an empty list rather than erroring, so callers can walk pages
+    /// until they come back empty.
+    pub fn get_roster_page(env: Env, epoch: u32, page: u32) -> Vec<Address> {
+        storage::get_roster_page(&env, epoch, page)
+    }
+
+    /// Sweep a finalized epoch's undistributed truncation dust forward into
+    /// the next epoch's reward pool
+    ///
+    /// Permissionless, but only meant to be called once every eligible player
+    /// has claimed (or been swept by `distribute_rewards_partition`) - calling
+    /// it earlier carries forward whatever is still unclaimed at that moment,
+    /// which would take real claimable balance away from players who haven't
+    /// claimed yet. Safe to call more than once for the same epoch; a second
+    /// sweep sees no remaining dust and carries nothing further.
+    ///
+    /// # Returns
+    /// The amount of dust carried forward (0 if nothing was left to sweep)
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn sweep_dust(env: Env, epoch: u32) -> Result<i128, Error> {
+        storage::require_not_paused(&env)?;
+        rewards::sweep_dust(&env, epoch)
+    }
+
+    /// Delete `EpochPlayer` bookkeeping for already-claimed players in a
+    /// slice of a finalized epoch's roster, so large epochs can be pruned
+    /// across several calls
+    ///
+    /// `start_index`/`count` slice the epoch's join-ordered participant
+    /// roster, the same way `distribute_rewards_partition` does. Idempotent -
+    /// players who haven't claimed yet (or never had an `EpochPlayer` entry)
+    /// are silently skipped, so sweeping `[0, participant_count)` in
+    /// back-to-back calls is always safe and never prunes anyone
+    /// prematurely. Admin-only: see `rewards::prune_claimed_players` for why
+    /// this can't safely be permissionless - it's on the admin to confirm no
+    /// in-flight game settlement or open governance proposal still needs
+    /// this epoch's `EpochPlayer` data before calling it.
+    ///
+    /// # Returns
+    /// The number of players pruned by this call
+    ///
+    /// # Errors
+    /// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+    /// * `InvalidPartitionRange` - If `count` is zero or `start_index` is beyond the roster
+    /// * `ContractPaused` - If contract is in emergency pause mode
+    pub fn prune_claimed_players(
+        env: Env,
+        epoch: u32,
+        start_index: u32,
+        count: u32,
+    ) -> Result<u32, Error> {
+        storage::require_not_paused(&env)?;
+        rewards::prune_claimed_players(&env, epoch, start_index, count)
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,134 @@
+use soroban_sdk::{Address, Env};
+
+use crate::errors::Error;
+use crate::storage;
+use crate::types::{EpochPlayer, Player};
+
+// ============================================================================
+// Player Migration
+// ============================================================================
+
+/// Current schema version for `Player` persistent entries
+pub(crate) const PLAYER_CURRENT_VERSION: u32 = 2;
+
+/// Ordered upgrade steps, indexed by `from_version`: `PLAYER_STEPS[v]` takes
+/// an entry from version `v` to version `v + 1`. Adding a new struct shape is
+/// additive - register one more step and bump `PLAYER_CURRENT_VERSION`,
+/// rather than teaching every read site another decode fallback.
+const PLAYER_STEPS: [fn(&Env, &Address); 2] = [player_v0_to_v1, player_v1_to_v2];
+
+/// Run whichever `PLAYER_STEPS` a player's entry still needs to reach
+/// `PLAYER_CURRENT_VERSION`, tagging the new version as each step completes.
+/// Returns true if any step ran.
+///
+/// # Errors
+/// * `CorruptStorage` - If the entry's schema tag is higher than
+///   `PLAYER_CURRENT_VERSION`, i.e. outside every layout a `PLAYER_STEPS`
+///   entry knows how to read. See `storage::diagnose_player` to detect this
+///   ahead of time instead of hitting it here.
+pub(crate) fn upgrade_player(env: &Env, player: &Address) -> Result<bool, Error> {
+    let mut version = storage::get_player_schema_version(env, player);
+    if version > PLAYER_CURRENT_VERSION {
+        return Err(Error::CorruptStorage);
+    }
+    let starting_version = version;
+
+    while (version as usize) < PLAYER_STEPS.len() {
+        PLAYER_STEPS[version as usize](env, player);
+        version += 1;
+        storage::set_player_schema_version(env, player, version);
+    }
+
+    Ok(version != starting_version)
+}
+
+/// V0 (pre-Nov 10): `total_deposited`/`deposit_timestamp` -> V1:
+/// `deposit_timestamp`/`last_epoch_balance`. V0 never tracked a prior
+/// epoch's balance, so it starts at 0.
+fn player_v0_to_v1(env: &Env, player: &Address) {
+    let Some(old) = storage::get_legacy_player_v0(env, player) else {
+        return;
+    };
+    storage::set_legacy_player_v1(
+        env,
+        player,
+        &crate::types::PlayerV1 {
+            selected_faction: old.selected_faction,
+            deposit_timestamp: old.deposit_timestamp,
+            last_epoch_balance: 0,
+        },
+    );
+}
+
+/// V1 (Nov 10-12): `deposit_timestamp`/`last_epoch_balance` -> V2 (current):
+/// `time_multiplier_start`/`last_epoch_balance` (field rename). Writes the
+/// canonical `Player` key and retires the legacy key.
+fn player_v1_to_v2(env: &Env, player: &Address) {
+    let Some(old) = storage::get_legacy_player_v1(env, player) else {
+        return;
+    };
+    storage::set_player(
+        env,
+        player,
+        &Player {
+            selected_faction: old.selected_faction,
+            time_multiplier_start: old.deposit_timestamp,
+            last_epoch_balance: old.last_epoch_balance,
+        },
+    );
+    storage::remove_legacy_player(env, player);
+}
+
+// ============================================================================
+// Epoch Player Migration
+// ============================================================================
+
+/// Current schema version for `EpochPlayer` temporary entries
+pub(crate) const EPOCH_PLAYER_CURRENT_VERSION: u32 = 1;
+
+/// Ordered upgrade steps for `EpochPlayer`, same convention as `PLAYER_STEPS`
+const EPOCH_PLAYER_STEPS: [fn(&Env, u32, &Address); 1] = [epoch_player_v0_to_v1];
+
+/// Run whichever `EPOCH_PLAYER_STEPS` an epoch player's entry still needs to
+/// reach `EPOCH_PLAYER_CURRENT_VERSION`. Returns true if any step ran.
+///
+/// # Errors
+/// * `CorruptStorage` - If the entry's schema tag is higher than
+///   `EPOCH_PLAYER_CURRENT_VERSION`, same convention as `upgrade_player`.
+pub(crate) fn upgrade_epoch_player(env: &Env, epoch: u32, player: &Address) -> Result<bool, Error> {
+    let mut version = storage::get_epoch_player_schema_version(env, epoch, player);
+    if version > EPOCH_PLAYER_CURRENT_VERSION {
+        return Err(Error::CorruptStorage);
+    }
+    let starting_version = version;
+
+    while (version as usize) < EPOCH_PLAYER_STEPS.len() {
+        EPOCH_PLAYER_STEPS[version as usize](env, epoch, player);
+        version += 1;
+        storage::set_epoch_player_schema_version(env, epoch, player, version);
+    }
+
+    Ok(version != starting_version)
+}
+
+/// V0 (pre-Nov 13): `EpochPlayer` with a `locked_fp` field -> V1 (current):
+/// field dropped. Writes the canonical key via the seal/roster-bypassing
+/// setter, since reshaping historical data isn't a new mutation of a
+/// possibly-sealed epoch's standings.
+fn epoch_player_v0_to_v1(env: &Env, epoch: u32, player: &Address) {
+    let Some(old) = storage::get_legacy_epoch_player_v0(env, epoch, player) else {
+        return;
+    };
+    storage::set_epoch_player_migrated(
+        env,
+        epoch,
+        player,
+        &EpochPlayer {
+            epoch_faction: old.epoch_faction,
+            epoch_balance_snapshot: old.epoch_balance_snapshot,
+            available_fp: old.available_fp,
+            total_fp_contributed: old.total_fp_contributed,
+        },
+    );
+    storage::remove_legacy_epoch_player(env, epoch, player);
+}
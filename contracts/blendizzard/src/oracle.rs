@@ -0,0 +1,247 @@
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::errors::Error;
+use crate::storage;
+use crate::types::{PriceAccumulator, BPS_DENOM, SCALAR_7};
+
+// ============================================================================
+// Oracle-Signed Reference Prices
+// ============================================================================
+// The Soroswap pool rate at swap time can be sandwiched, so epoch cycling
+// instead swaps against a min_amount_out derived from a reference price that
+// M-of-N registered oracles have signed off-chain. Submissions are collected
+// per epoch; once M distinct registered keys have signed, the median becomes
+// that epoch's reference price and is immutable from then on.
+//
+// Prices are expressed in `SCALAR_7` fixed point: USDC per one BLND.
+//
+// A stored price stays usable only for `Config.price_staleness_window`
+// seconds - see `get_fresh_reference_price` - so `cycle_epoch` never swaps
+// against a rate that's drifted too far from whatever the oracles last
+// actually observed.
+
+/// Submit an oracle-signed BLND/USDC price for `epoch_id`.
+///
+/// Each entry in `signatures` is `(oracle_pubkey, signature)` over the
+/// message `sha256(epoch_id || blnd_usdc_price)`. Signers not in
+/// `config.oracle_keys`, duplicate signers (either across this call or
+/// against a prior submission), and submissions for an epoch that already
+/// has a reference price are rejected. Once `config.oracle_threshold`
+/// distinct registered keys have submitted, the median of all submitted
+/// prices is stored as the epoch's reference price.
+///
+/// # Errors
+/// * `EpochAlreadyFinalized` - If the epoch already has a reference price
+/// * `OracleKeyNotAuthorized` - If a signature's key isn't in the registered oracle set
+/// * `DuplicateOracleSigner` - If a key has already submitted for this epoch
+pub(crate) fn submit_prices(
+    env: &Env,
+    epoch_id: u32,
+    blnd_usdc_price: i128,
+    signatures: Vec<(BytesN<32>, BytesN<64>)>,
+) -> Result<(), Error> {
+    if storage::get_reference_price(env, epoch_id).is_some() {
+        return Err(Error::EpochAlreadyFinalized);
+    }
+
+    let config = storage::get_config(env);
+    let message = price_message(env, epoch_id, blnd_usdc_price);
+
+    let mut submitters = storage::get_price_submitters(env, epoch_id);
+
+    for (oracle_key, signature) in signatures.iter() {
+        if !config.oracle_keys.contains(&oracle_key) {
+            return Err(Error::OracleKeyNotAuthorized);
+        }
+        if submitters.contains(&oracle_key) {
+            return Err(Error::DuplicateOracleSigner);
+        }
+
+        env.crypto()
+            .ed25519_verify(&oracle_key, &message, &signature);
+
+        storage::set_price_submission(env, epoch_id, &oracle_key, blnd_usdc_price);
+        submitters.push_back(oracle_key);
+    }
+
+    storage::set_price_submitters(env, epoch_id, &submitters);
+
+    if submitters.len() >= config.oracle_threshold && config.oracle_threshold > 0 {
+        let median = median_submitted_price(env, epoch_id, &submitters);
+        storage::set_reference_price(env, epoch_id, median);
+        accumulate_price(env, median);
+    }
+
+    Ok(())
+}
+
+/// Get the finalized median reference price for an epoch.
+///
+/// # Errors
+/// * `ReferencePriceNotSet` - If fewer than the threshold oracles have signed yet
+pub(crate) fn get_reference_price(env: &Env, epoch_id: u32) -> Result<i128, Error> {
+    storage::get_reference_price(env, epoch_id)
+        .map(|(price, _set_at)| price)
+        .ok_or(Error::ReferencePriceNotSet)
+}
+
+/// The finalized reference price for an epoch, but only if it's still fresh:
+/// set within the last `staleness_window` seconds. `staleness_window == 0`
+/// disables the check entirely (a submitted price never goes stale).
+///
+/// Used by `rewards::withdraw_yield` to decide whether it's safe to swap
+/// against this price at all - unlike `get_reference_price`, a `None` here
+/// isn't an error, just a signal to defer the swap to a later epoch.
+pub(crate) fn get_fresh_reference_price(env: &Env, epoch_id: u32, staleness_window: u64) -> Option<i128> {
+    let (price, set_at) = storage::get_reference_price(env, epoch_id)?;
+    if staleness_window > 0 && env.ledger().timestamp().saturating_sub(set_at) > staleness_window {
+        return None;
+    }
+    Some(price)
+}
+
+/// `sha256(epoch_id.to_be_bytes() || price.to_be_bytes())`, the message every
+/// oracle signature is over.
+fn price_message(env: &Env, epoch_id: u32, price: i128) -> BytesN<32> {
+    let mut bytes = Bytes::new(env);
+    bytes.extend_from_array(&epoch_id.to_be_bytes());
+    bytes.extend_from_array(&price.to_be_bytes());
+    env.crypto().sha256(&bytes).into()
+}
+
+/// Median of all prices submitted by `submitters` for `epoch_id`.
+///
+/// `submitters` is small (bounded by the registered oracle set), so a plain
+/// selection sort is simpler and cheaper here than pulling in a general
+/// sorting routine.
+fn median_submitted_price(env: &Env, epoch_id: u32, submitters: &Vec<BytesN<32>>) -> i128 {
+    let mut prices: Vec<i128> = Vec::new(env);
+    for key in submitters.iter() {
+        let price = storage::get_price_submission(env, epoch_id, &key)
+            .expect("submitter without a stored price");
+        prices.push_back(price);
+    }
+
+    let len = prices.len();
+    for i in 0..len {
+        let mut min_idx = i;
+        for j in (i + 1)..len {
+            if prices.get_unchecked(j) < prices.get_unchecked(min_idx) {
+                min_idx = j;
+            }
+        }
+        if min_idx != i {
+            let a = prices.get_unchecked(i);
+            let b = prices.get_unchecked(min_idx);
+            prices.set(i, b);
+            prices.set(min_idx, a);
+        }
+    }
+
+    prices.get_unchecked(len / 2)
+}
+
+// ============================================================================
+// TWAP Defense
+// ============================================================================
+// A single oracle reference price, however well-signed, is still one
+// snapshot - if the signers themselves are compromised or the off-chain feed
+// glitches, `min_amount_out` would bound the swap against a bad number with
+// total confidence. `accumulate_price`/`check_twap_deviation` maintain a
+// running time-weighted cumulative-price total (the same accumulator shape
+// a constant-product AMM uses) and reject a fresh price that's drifted too
+// far from the TWAP derived from it, so a single bad observation can't
+// immediately move the swap's floor.
+
+/// Roll `new_price` into the running BLND/USDC cumulative-price accumulator.
+/// Called every time `submit_prices` finalizes a new epoch's reference
+/// price: adds `last_price * seconds_since_last_update` to `cumulative`
+/// before overwriting `last_price`, so `cumulative` always reflects a
+/// time-weighted sum over every reference price this contract has ever
+/// finalized.
+fn accumulate_price(env: &Env, new_price: i128) {
+    let now = env.ledger().timestamp();
+    let mut acc = storage::get_price_accumulator(env).unwrap_or(PriceAccumulator {
+        cumulative: 0,
+        last_update: now,
+        last_price: new_price,
+    });
+
+    let elapsed = now.saturating_sub(acc.last_update);
+    acc.cumulative = acc
+        .cumulative
+        .saturating_add(acc.last_price.saturating_mul(elapsed as i128));
+    acc.last_update = now;
+    acc.last_price = new_price;
+
+    storage::set_price_accumulator(env, &acc);
+}
+
+/// Check that `spot_price` doesn't deviate from the BLND/USDC TWAP by more
+/// than `max_deviation_bps`. The TWAP is `(cumulative_now - cumulative_at_last_check)
+/// / (now - last_check)`, where `cumulative_now` brings the live accumulator
+/// current as of this call without persisting it (that happens via
+/// `accumulate_price` whenever a new price is actually finalized). The first
+/// ever check, or one with no elapsed time since the last, has no history to
+/// compare against and always passes. Either way, this call stamps a fresh
+/// snapshot so the next check's window starts from here.
+///
+/// # Errors
+/// * `PriceDeviatesFromTwap` - If `spot_price` is outside the allowed band
+pub(crate) fn check_twap_deviation(
+    env: &Env,
+    spot_price: i128,
+    max_deviation_bps: u32,
+) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    let acc = storage::get_price_accumulator(env).unwrap_or(PriceAccumulator {
+        cumulative: 0,
+        last_update: now,
+        last_price: spot_price,
+    });
+    let elapsed_since_update = now.saturating_sub(acc.last_update);
+    let cumulative_now = acc
+        .cumulative
+        .saturating_add(acc.last_price.saturating_mul(elapsed_since_update as i128));
+
+    if let Some(snapshot) = storage::get_price_accumulator_snapshot(env) {
+        let elapsed = now.saturating_sub(snapshot.last_update);
+        if elapsed > 0 && max_deviation_bps > 0 {
+            let twap = (cumulative_now - snapshot.cumulative) / elapsed as i128;
+            let deviation = (spot_price - twap).abs();
+            let allowed = twap
+                .checked_mul(max_deviation_bps as i128)
+                .and_then(|v| v.checked_div(BPS_DENOM as i128))
+                .unwrap_or(i128::MAX)
+                .abs();
+            if deviation > allowed {
+                return Err(Error::PriceDeviatesFromTwap);
+            }
+        }
+    }
+
+    storage::set_price_accumulator_snapshot(
+        env,
+        &PriceAccumulator {
+            cumulative: cumulative_now,
+            last_update: now,
+            last_price: spot_price,
+        },
+    );
+
+    Ok(())
+}
+
+/// Minimum acceptable USDC out for swapping `blnd_amount` at the epoch's
+/// reference price, after allowing `slippage_bps` basis points of slippage.
+pub(crate) fn min_amount_out(reference_price: i128, blnd_amount: i128, slippage_bps: u32) -> i128 {
+    let expected = blnd_amount
+        .checked_mul(reference_price)
+        .and_then(|v| v.checked_div(SCALAR_7))
+        .expect("min_amount_out: overflow computing expected USDC out");
+
+    expected
+        .checked_mul((crate::types::BPS_DENOM - slippage_bps) as i128)
+        .and_then(|v| v.checked_div(crate::types::BPS_DENOM as i128))
+        .expect("min_amount_out: overflow applying slippage bound")
+}
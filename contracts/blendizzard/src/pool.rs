@@ -0,0 +1,29 @@
+//! External contract type definition for a Blend pool.
+//!
+//! Only declares the entrypoint `vault::normalize_to_common_unit` actually
+//! calls; the rest of the pool's interface is irrelevant to this crate.
+//! `Reserve`'s fields mirror `tests::fee_vault_utils::Reserve` - see that
+//! file if this ever needs to track a field the mock doesn't exercise yet.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reserve {
+    pub b_rate: i128,
+    pub b_supply: i128,
+    pub c_factor: u32,
+    pub d_rate: i128,
+    pub index: u32,
+    pub ir_mod: i128,
+    pub l_factor: u32,
+    pub last_time: u64,
+    pub scalar: i128,
+}
+
+#[contractclient(name = "Client")]
+pub trait PoolTrait {
+    /// Reserve data for `reserve` (an underlying asset address), used to
+    /// normalize deposits of that asset to a common unit.
+    fn get_reserve(env: Env, reserve: Address) -> Reserve;
+}
@@ -0,0 +1,206 @@
+use soroban_sdk::{Address, Env};
+
+use crate::config;
+use crate::errors::Error;
+use crate::events;
+use crate::storage;
+use crate::types::{Proposal, ProposalAction, ProposalStatus, BPS_DENOM};
+
+// ============================================================================
+// FP-Weighted Governance
+// ============================================================================
+// A second, player-facing path onto the same admin surface `update_config`,
+// `add_game`/`remove_game`, `upgrade`, and `pause`/`unpause` expose - see
+// `ProposalAction`. Any address may `propose` one of these actions; voting
+// power is each voter's `EpochPlayer.total_fp_contributed` at the proposal's
+// `snapshot_epoch` (so it can't be farmed by playing games after the
+// proposal goes up), and a proposal passes once yes-votes clear both a
+// quorum (bps of that epoch's total FP across `EpochInfo.faction_standings`)
+// and a majority (bps of votes cast) - both fixed at construction like
+// `admin_threshold`, so the admin can't move the goalposts later. A passed
+// proposal still sits out `governance_timelock` seconds past its voting
+// deadline before `execute` will dispatch it, during which the admin can
+// still `cancel` it as a backstop against a passed-but-malicious proposal.
+
+/// Propose `action` for an FP-weighted vote
+pub(crate) fn propose(env: &Env, proposer: &Address, action: ProposalAction) -> Result<u32, Error> {
+    proposer.require_auth();
+
+    let snapshot_epoch = storage::get_current_epoch(env);
+    let now = env.ledger().timestamp();
+    let voting_deadline = now + storage::get_governance_voting_period(env);
+    let earliest_execution_ts = voting_deadline + storage::get_governance_timelock(env);
+
+    let proposal_id = storage::next_proposal_id(env);
+    storage::set_proposal(
+        env,
+        proposal_id,
+        &Proposal {
+            proposer: proposer.clone(),
+            action,
+            snapshot_epoch,
+            voting_deadline,
+            earliest_execution_ts,
+            yes_votes: 0,
+            no_votes: 0,
+            executed: false,
+            canceled: false,
+        },
+    );
+
+    events::emit_proposal_created(env, proposal_id, proposer);
+    Ok(proposal_id)
+}
+
+/// Cast `voter`'s vote on `proposal_id` - weighted by their
+/// `total_fp_contributed` at the proposal's `snapshot_epoch`. Each address
+/// may vote once.
+///
+/// # Errors
+/// * `ProposalNotFound` - If `proposal_id` doesn't exist
+/// * `ProposalCanceled` - If the admin has canceled this proposal
+/// * `VotingClosed` - If `voting_deadline` has passed
+/// * `AlreadyVoted` - If `voter` already voted on this proposal
+/// * `NoVotingPower` - If `voter` contributed no FP at the snapshot epoch
+pub(crate) fn vote(env: &Env, voter: &Address, proposal_id: u32, support: bool) -> Result<(), Error> {
+    voter.require_auth();
+
+    let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
+    if proposal.canceled {
+        return Err(Error::ProposalCanceled);
+    }
+    if env.ledger().timestamp() >= proposal.voting_deadline {
+        return Err(Error::VotingClosed);
+    }
+    if storage::has_voted(env, proposal_id, voter) {
+        return Err(Error::AlreadyVoted);
+    }
+
+    let weight = storage::get_epoch_player(env, proposal.snapshot_epoch, voter)?
+        .map(|p| p.total_fp_contributed)
+        .unwrap_or(0);
+    if weight == 0 {
+        return Err(Error::NoVotingPower);
+    }
+
+    if support {
+        proposal.yes_votes = proposal.yes_votes.saturating_add(weight);
+    } else {
+        proposal.no_votes = proposal.no_votes.saturating_add(weight);
+    }
+
+    storage::set_voted(env, proposal_id, voter);
+    storage::set_proposal(env, proposal_id, &proposal);
+    events::emit_proposal_voted(env, proposal_id, voter, support, weight);
+
+    Ok(())
+}
+
+/// Where `proposal_id` stands right now
+///
+/// Quorum is computed against `snapshot_epoch`'s total FP, read from
+/// `storage::get_epoch_summary` rather than the live `EpochInfo` -
+/// `EpochInfo` is temporary storage capped at a 30-day TTL, so an old
+/// snapshot epoch's entry can expire (or simply never get re-read/extended)
+/// long before a slow-moving proposal's voting closes, while
+/// `EpochSummary` is the permanent archive `epoch::settle_reward_pool`
+/// writes the same `faction_standings` into at the same moment, precisely
+/// so downstream readers like this one don't race `EpochInfo`'s TTL (see
+/// `EpochSummary`'s doc comment). If `snapshot_epoch` hasn't finalized yet
+/// (or, implausibly, its summary is itself missing) quorum isn't
+/// determinable at all yet - rather than treat that as "0 FP outstanding,
+/// anything passes", this reports `Failed`, the same as closing without
+/// quorum, so an indeterminate proposal can never sneak through passed.
+pub(crate) fn get_proposal_status(env: &Env, proposal: &Proposal) -> ProposalStatus {
+    if proposal.canceled {
+        return ProposalStatus::Canceled;
+    }
+    if proposal.executed {
+        return ProposalStatus::Executed;
+    }
+    if env.ledger().timestamp() < proposal.voting_deadline {
+        return ProposalStatus::Voting;
+    }
+
+    let total_epoch_fp: u128 = match storage::get_epoch_summary(env, proposal.snapshot_epoch) {
+        Some(summary) => summary.faction_standings.iter().sum(),
+        None => return ProposalStatus::Failed,
+    };
+    let quorum_bps = storage::get_governance_quorum_bps(env);
+    let quorum_met = proposal.yes_votes.saturating_mul(BPS_DENOM as u128)
+        >= total_epoch_fp.saturating_mul(quorum_bps as u128);
+
+    let votes_cast = proposal.yes_votes.saturating_add(proposal.no_votes);
+    let majority_bps = storage::get_governance_majority_bps(env);
+    let majority_met = votes_cast > 0
+        && proposal.yes_votes.saturating_mul(BPS_DENOM as u128)
+            >= votes_cast.saturating_mul(majority_bps as u128);
+
+    if quorum_met && majority_met {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Failed
+    }
+}
+
+/// Dispatch a passed proposal's `action` through the same code path the
+/// matching admin entrypoint uses, then mark it executed.
+///
+/// # Errors
+/// * `ProposalNotFound` - If `proposal_id` doesn't exist
+/// * `ProposalAlreadyExecuted` - If this proposal already executed
+/// * `ProposalNotPassed` - If voting hasn't closed, or closed without quorum/majority
+/// * `TimelockNotElapsed` - If `governance_timelock` hasn't elapsed since `voting_deadline`
+pub(crate) fn execute(env: &Env, proposal_id: u32) -> Result<(), Error> {
+    let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(Error::ProposalAlreadyExecuted);
+    }
+    if get_proposal_status(env, &proposal) != ProposalStatus::Passed {
+        return Err(Error::ProposalNotPassed);
+    }
+    if env.ledger().timestamp() < proposal.earliest_execution_ts {
+        return Err(Error::TimelockNotElapsed);
+    }
+
+    match &proposal.action {
+        ProposalAction::UpdateConfig(update) => config::apply_update(env, update),
+        ProposalAction::AddGame(game_id) => storage::add_game_to_whitelist(env, game_id),
+        ProposalAction::RemoveGame(game_id) => storage::remove_game_from_whitelist(env, game_id),
+        ProposalAction::Upgrade(wasm_hash) => {
+            env.deployer().update_current_contract_wasm(wasm_hash.clone())
+        }
+        ProposalAction::Pause => storage::set_pause_state(env, true),
+        ProposalAction::Unpause => storage::set_pause_state(env, false),
+    }
+
+    proposal.executed = true;
+    storage::set_proposal(env, proposal_id, &proposal);
+    events::emit_proposal_executed(env, proposal_id);
+
+    Ok(())
+}
+
+/// Admin backstop: cancel a not-yet-executed proposal, e.g. one that passed
+/// but turns out to be malicious, before its timelock lets `execute`
+/// dispatch it.
+///
+/// # Errors
+/// * `ProposalNotFound` - If `proposal_id` doesn't exist
+/// * `ProposalAlreadyExecuted` - If this proposal already executed
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn cancel(env: &Env, proposal_id: u32) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(Error::ProposalAlreadyExecuted);
+    }
+
+    proposal.canceled = true;
+    storage::set_proposal(env, proposal_id, &proposal);
+    events::emit_proposal_canceled(env, proposal_id);
+
+    Ok(())
+}
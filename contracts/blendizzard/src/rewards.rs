@@ -0,0 +1,884 @@
+use soroban_sdk::{token, Address, BytesN, Env, Vec};
+
+use crate::errors::Error;
+use crate::merkle;
+use crate::oracle;
+use crate::storage;
+use crate::swap_router::{self, SwapRouter};
+use crate::types::{Config, EpochRewards, VestingEntry, VestingStatus};
+use crate::yield_source::{self, YieldSource};
+
+// ============================================================================
+// Yield Harvesting
+// ============================================================================
+// Split into `withdraw_yield`/`swap_withdrawn_yield` rather than one
+// harvest-and-swap call, so `epoch::cycle_epoch_step` can run them as two
+// separate `FinalizationPhase` steps (possibly in separate transactions)
+// instead of the external token transfer and swap calls both having to fit
+// in whichever call starts finalizing.
+
+/// Claim yield from the configured `YieldSource`, the first of the two
+/// phases `epoch::cycle_epoch_step`'s `FinalizationPhase::WithdrawingYield`
+/// runs - see `swap_withdrawn_yield` for the second.
+///
+/// Returns the oracle-signed reference price for `epoch_id` alongside the
+/// amount claimed, or `None` if claiming was deferred entirely. That price
+/// is what bounds the later swap's `min_amount_out` against sandwiching
+/// rather than trusting the pool's spot rate - if it isn't fresh (see
+/// `oracle::get_fresh_reference_price`), there's no safe rate to bound the
+/// swap with, so this skips harvesting entirely and leaves the unclaimed
+/// yield sitting in the vault for a later epoch to pick up, rather than
+/// swapping blind or failing `cycle_epoch_step` outright. Before trusting
+/// the reference price at all, it's also checked against the BLND/USDC TWAP
+/// (see `oracle::check_twap_deviation`) so a single stale or compromised
+/// snapshot can't move the swap's floor on its own.
+///
+/// The returned price is carried forward into
+/// `FinalizationPhase::Swapping` rather than re-derived by
+/// `swap_withdrawn_yield`, so a price that's fresh here can't have gone
+/// stale or drifted by the time the swap actually runs, even if the two
+/// phases land in separate transactions.
+///
+/// # Errors
+/// * `PriceDeviatesFromTwap` - If the reference price has drifted too far from the TWAP
+/// * `FeeVaultError` - If fee-vault emission claiming fails
+pub(crate) fn withdraw_yield(
+    env: &Env,
+    config: &Config,
+    epoch_id: u32,
+) -> Result<Option<(i128, i128)>, Error> {
+    let Some(reference_price) =
+        oracle::get_fresh_reference_price(env, epoch_id, config.price_staleness_window)
+    else {
+        return Ok(None);
+    };
+
+    oracle::check_twap_deviation(env, reference_price, config.max_price_deviation_bps)?;
+
+    let source = yield_source::make_yield_source(
+        &config.yield_source_kind,
+        config.fee_vault.clone(),
+        config.reserve_token_ids.clone(),
+        config.blnd_token.clone(),
+    );
+    let yield_claimed = source.claim_yield(env);
+
+    Ok(Some((reference_price, yield_claimed)))
+}
+
+/// Swap yield `withdraw_yield` already claimed to USDC through the
+/// configured `SwapRouter` - the second of the two phases
+/// `epoch::cycle_epoch_step`'s yield handling runs.
+///
+/// Returns the amount of USDC received, which becomes the epoch's reward
+/// pool. `reference_price` must be the exact value `withdraw_yield` returned
+/// for this same claim - it's not re-checked for freshness here, since
+/// re-deriving it could hand back a different (or no longer fresh) price
+/// than the one `withdraw_yield` already validated.
+///
+/// Neither the vault nor the router is hardcoded here - `Config.yield_source_kind`/
+/// `swap_router_kind` select which adapter to construct (see `yield_source`/
+/// `swap_router`), so deploying against a different Blend-compatible vault
+/// or a different Stellar DEX needs a new adapter there, not a change here.
+///
+/// # Errors
+/// * `SwapError` - If the BLND -> USDC swap fails to meet `min_amount_out`
+pub(crate) fn swap_withdrawn_yield(
+    env: &Env,
+    config: &Config,
+    reference_price: i128,
+    yield_claimed: i128,
+) -> Result<i128, Error> {
+    if yield_claimed <= 0 {
+        return Ok(0);
+    }
+
+    let source = yield_source::make_yield_source(
+        &config.yield_source_kind,
+        config.fee_vault.clone(),
+        config.reserve_token_ids.clone(),
+        config.blnd_token.clone(),
+    );
+    let min_out = oracle::min_amount_out(reference_price, yield_claimed, config.slippage_bps);
+
+    let usdc_before = token::Client::new(env, &config.usdc_token)
+        .balance(&env.current_contract_address());
+
+    let router = swap_router::make_swap_router(&config.swap_router_kind, config.soroswap_router.clone());
+    router.swap(
+        env,
+        &source.underlying_token(),
+        &config.usdc_token,
+        yield_claimed,
+        min_out,
+    );
+
+    let usdc_after = token::Client::new(env, &config.usdc_token)
+        .balance(&env.current_contract_address());
+
+    Ok(usdc_after - usdc_before)
+}
+
+// ============================================================================
+// Reward Claims
+// ============================================================================
+
+/// Claim a player's share of a finalized epoch's reward pool.
+///
+/// The share is `(player_fp * rewards) / points`, computed with a u128
+/// intermediate so it can't overflow, truncating down. The truncated dust
+/// (at most `points - 1` units) simply stays in the pool. A running
+/// invariant over `DistributedRewards(epoch)` guarantees the sum of every
+/// claim in an epoch never exceeds `rewards`, protecting `claim_epoch_reward`
+/// against rounding drift across many claimants. That invariant is charged
+/// the player's full share up front regardless of vesting - `VestingEntry`
+/// only staggers when it's paid out, not how much is committed.
+///
+/// If `Config.vesting_epochs` is nonzero, the share doesn't transfer in
+/// full right away: the first call creates a `VestingEntry` and pays out
+/// whatever has unlocked since (see `vested_amount`), and every later call
+/// against this same epoch tops the player up to however much has unlocked
+/// since their last withdrawal - see `withdraw_vested`.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `EpochFinalizationInProgress` - If `epoch::cycle_epoch_step` is still mid-flight on this epoch
+/// * `RewardAlreadyClaimed` - If player already claimed for this epoch (non-vesting only)
+/// * `NotWinningFaction` - If player wasn't in the winning faction
+/// * `NoRewardsAvailable` - If player has no rewards to claim, or (vesting only) nothing new has unlocked since their last claim
+pub(crate) fn claim_epoch_reward(env: &Env, player: &Address, epoch: u32) -> Result<i128, Error> {
+    player.require_auth();
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        let cursor_epoch = storage::get_finalization_cursor(env).map(|cursor| cursor.epoch_id);
+        if cursor_epoch == Some(epoch) {
+            return Err(Error::EpochFinalizationInProgress);
+        }
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let config = storage::get_config(env);
+
+    // An existing `VestingEntry` means `total_amount` was already derived
+    // and charged against `DistributedRewards` on the first claim - only how
+    // much of it has unlocked since can change.
+    if let Some(entry) = storage::get_vesting_entry(env, epoch, player) {
+        let delta = withdraw_vested(env, player, epoch, entry)?;
+        let usdc_client = token::Client::new(env, &config.usdc_token);
+        usdc_client.transfer(&env.current_contract_address(), player, &delta);
+        return Ok(delta);
+    }
+
+    if storage::has_claimed(env, player, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    let winning_faction = epoch_info.winning_faction.ok_or(Error::NoRewardsAvailable)?;
+
+    let epoch_player = storage::get_epoch_player(env, epoch, player)?.ok_or(Error::NotWinningFaction)?;
+    if epoch_player.epoch_faction != Some(winning_faction) {
+        return Err(Error::NotWinningFaction);
+    }
+
+    let player_fp = epoch_player.total_fp_contributed;
+    let reward_round = epoch_info.reward_pool;
+    if player_fp == 0 || reward_round.points == 0 || reward_round.rewards == 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    let share = claimable_share(player_fp, reward_round.rewards, reward_round.points);
+    if share == 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    // Invariant: the sum of every share ever distributed for this epoch must
+    // never exceed the epoch's allocated reward pool.
+    let distributed_so_far = storage::get_distributed_rewards(env, epoch);
+    let new_total = distributed_so_far
+        .checked_add(share)
+        .expect("reward distribution overflow");
+    if new_total > reward_round.rewards {
+        panic!("claim_epoch_reward: distributed total would exceed the epoch reward pool");
+    }
+    storage::set_distributed_rewards(env, epoch, new_total);
+    storage::set_claimed(env, player, epoch);
+    storage::increment_claimant_count(env, epoch);
+
+    if config.vesting_epochs == 0 {
+        let usdc_client = token::Client::new(env, &config.usdc_token);
+        usdc_client.transfer(&env.current_contract_address(), player, &share);
+        return Ok(share);
+    }
+
+    let entry = VestingEntry {
+        total_amount: share,
+        start_epoch: epoch,
+        vesting_epochs: config.vesting_epochs,
+        withdrawn: 0,
+    };
+    // Persisted up front, before `withdraw_vested` computes this call's
+    // delta - it must survive even when nothing's vested yet (e.g. claiming
+    // in the same epoch it finalized), since `DistributedRewards` and
+    // `ClaimBitmap` are already committed by this point and a later claim
+    // needs to find this entry rather than re-derive `total_amount`.
+    storage::set_vesting_entry(env, epoch, player, &entry);
+    let delta = withdraw_vested(env, player, epoch, entry)?;
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    usdc_client.transfer(&env.current_contract_address(), player, &delta);
+    Ok(delta)
+}
+
+/// Maximum epochs `claim_epochs` will process in a single call.
+const MAX_CLAIM_EPOCHS_PER_CALL: u32 = 64;
+
+/// Claim a player's reward share across several finalized epochs in one
+/// call, instead of one `claim_epoch_reward` transaction per epoch.
+///
+/// Every epoch in `epochs` is validated exactly as `claim_epoch_reward`
+/// would, but ineligible ones (not finalized, no winner, player not in the
+/// winning faction, nothing to claim, or already claimed) are skipped
+/// rather than erroring, same as `distribute_rewards_partition` - callers
+/// don't need to know in advance which of several candidate epochs are
+/// actually claimable. Every eligible epoch's amount is summed into one
+/// USDC transfer, and every non-vesting epoch newly claimed this call has
+/// its `ClaimBitmap` bit batched through `storage::set_claimed_many`, so a
+/// call touching several epochs in the same 128-epoch window writes that
+/// window once instead of once per epoch. Epochs with an in-progress
+/// `VestingEntry` withdraw only however much has newly unlocked, same as
+/// calling `claim_epoch_reward` on them individually - their bit was
+/// already set the first time they were claimed, so they don't add to the
+/// batched write.
+///
+/// # Errors
+/// * `InvalidEpochBatch` - If `epochs` is empty or longer than `MAX_CLAIM_EPOCHS_PER_CALL`
+/// * `NoRewardsAvailable` - If no epoch in `epochs` had anything claimable
+pub(crate) fn claim_epochs(env: &Env, player: &Address, epochs: Vec<u32>) -> Result<i128, Error> {
+    player.require_auth();
+
+    if epochs.is_empty() || epochs.len() > MAX_CLAIM_EPOCHS_PER_CALL {
+        return Err(Error::InvalidEpochBatch);
+    }
+
+    let config = storage::get_config(env);
+    let mut newly_claimed: Vec<u32> = Vec::new(env);
+    let mut total: i128 = 0;
+
+    for epoch in epochs.iter() {
+        if let Some(amount) = claim_one_epoch(env, &config, player, epoch, &mut newly_claimed) {
+            total += amount;
+        }
+    }
+
+    if !newly_claimed.is_empty() {
+        storage::set_claimed_many(env, player, &newly_claimed);
+    }
+
+    if total == 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    usdc_client.transfer(&env.current_contract_address(), player, &total);
+
+    Ok(total)
+}
+
+/// Settle one epoch's claim for `player` as part of `claim_epochs` -
+/// ineligible epochs return `None` instead of an error. A non-vesting epoch
+/// newly claimed this call is pushed onto `newly_claimed` for
+/// `claim_epochs` to batch into one `ClaimBitmap` write; a vesting epoch
+/// already claimed before isn't, since its bit is already set.
+fn claim_one_epoch(
+    env: &Env,
+    config: &Config,
+    player: &Address,
+    epoch: u32,
+    newly_claimed: &mut Vec<u32>,
+) -> Option<i128> {
+    let epoch_info = storage::get_epoch(env, epoch)?;
+    if !epoch_info.finalized {
+        return None;
+    }
+
+    if let Some(entry) = storage::get_vesting_entry(env, epoch, player) {
+        return withdraw_vested(env, player, epoch, entry).ok();
+    }
+
+    if storage::has_claimed(env, player, epoch) {
+        return None;
+    }
+
+    let winning_faction = epoch_info.winning_faction?;
+
+    let epoch_player = match storage::get_epoch_player(env, epoch, &player.clone()) {
+        Ok(Some(data)) => data,
+        Ok(None) | Err(_) => return None,
+    };
+    if epoch_player.epoch_faction != Some(winning_faction) {
+        return None;
+    }
+
+    let player_fp = epoch_player.total_fp_contributed;
+    let reward_round = epoch_info.reward_pool;
+    if player_fp == 0 || reward_round.points == 0 || reward_round.rewards == 0 {
+        return None;
+    }
+
+    let share = claimable_share(player_fp, reward_round.rewards, reward_round.points);
+    if share == 0 {
+        return None;
+    }
+
+    let distributed_so_far = storage::get_distributed_rewards(env, epoch);
+    let new_total = distributed_so_far
+        .checked_add(share)
+        .expect("reward distribution overflow");
+    if new_total > reward_round.rewards {
+        panic!("claim_epochs: distributed total would exceed the epoch reward pool");
+    }
+    storage::set_distributed_rewards(env, epoch, new_total);
+    storage::increment_claimant_count(env, epoch);
+
+    if config.vesting_epochs == 0 {
+        newly_claimed.push_back(epoch);
+        return Some(share);
+    }
+
+    let entry = VestingEntry {
+        total_amount: share,
+        start_epoch: epoch,
+        vesting_epochs: config.vesting_epochs,
+        withdrawn: 0,
+    };
+    storage::set_vesting_entry(env, epoch, player, &entry);
+    newly_claimed.push_back(epoch);
+    withdraw_vested(env, player, epoch, entry).ok()
+}
+
+/// `(player_fp * rewards) / points`, computed with a u128 intermediate to
+/// avoid overflow, truncating down.
+fn claimable_share(player_fp: u128, rewards: i128, points: u128) -> i128 {
+    let share_u128 = player_fp
+        .checked_mul(rewards as u128)
+        .and_then(|v| v.checked_div(points))
+        .expect("reward share calculation overflow");
+    share_u128 as i128
+}
+
+/// Amount of `total_amount` unlocked by `current_epoch`, under a linear
+/// vesting schedule starting at `start_epoch` over `vesting_epochs` epochs:
+/// `total_amount * min(current_epoch - start_epoch, vesting_epochs) /
+/// vesting_epochs`, computed with a u128 intermediate like
+/// `claimable_share`, truncating down. Saturates at `total_amount` once
+/// `vesting_epochs` have elapsed.
+fn vested_amount(total_amount: i128, current_epoch: u32, start_epoch: u32, vesting_epochs: u32) -> i128 {
+    let elapsed = current_epoch.saturating_sub(start_epoch).min(vesting_epochs);
+    if elapsed == 0 {
+        return 0;
+    }
+    if elapsed >= vesting_epochs {
+        return total_amount;
+    }
+
+    let vested_u128 = (total_amount as u128)
+        .checked_mul(elapsed as u128)
+        .and_then(|v| v.checked_div(vesting_epochs as u128))
+        .expect("vesting calculation overflow");
+    vested_u128 as i128
+}
+
+/// Compute however much of `entry` has newly unlocked since its last
+/// withdrawal and persist the updated `withdrawn` tally - doesn't transfer
+/// anything itself, so callers settling several epochs at once (see
+/// `claim_epochs`) can sum every epoch's delta into one transfer instead of
+/// one per epoch.
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If nothing new has unlocked since the last withdrawal
+fn withdraw_vested(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    mut entry: VestingEntry,
+) -> Result<i128, Error> {
+    let current_epoch = storage::get_current_epoch(env);
+    let vested = vested_amount(entry.total_amount, current_epoch, entry.start_epoch, entry.vesting_epochs);
+    let delta = vested - entry.withdrawn;
+    if delta <= 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    entry.withdrawn += delta;
+    storage::set_vesting_entry(env, epoch, player, &entry);
+
+    Ok(delta)
+}
+
+/// Preview a player's vesting status for an epoch's reward claim - total,
+/// vested, withdrawn, and unvested amounts, for UIs to render an unlock
+/// countdown. Returns `None` until the player's first `claim_epoch_reward`
+/// call against this epoch creates the `VestingEntry` (including when
+/// vesting isn't enabled at all).
+pub(crate) fn get_vesting(env: &Env, player: &Address, epoch: u32) -> Option<VestingStatus> {
+    let entry = storage::get_vesting_entry(env, epoch, player)?;
+    let current_epoch = storage::get_current_epoch(env);
+    let vested = vested_amount(entry.total_amount, current_epoch, entry.start_epoch, entry.vesting_epochs);
+
+    Some(VestingStatus {
+        total_amount: entry.total_amount,
+        vested_amount: vested,
+        withdrawn_amount: entry.withdrawn,
+        unvested_amount: entry.total_amount - vested,
+    })
+}
+
+/// Preview a player's claimable share of a finalized epoch's reward pool.
+///
+/// Computes `(player_fp * rewards) / points` lazily from the frozen
+/// `reward_pool`, independent of how much of the epoch `distribute_rewards_partition`
+/// has processed so far. Returns `0` rather than an error once the player has
+/// already claimed (whether via `claim_epoch_reward` or a partition sweep),
+/// so front-ends can display "nothing left to claim" without a failed call.
+///
+/// If a `VestingEntry` already exists for this player/epoch, returns however
+/// much of it has unlocked since their last withdrawal rather than the full
+/// share. If vesting is enabled but a player hasn't claimed yet, previews
+/// what `claim_epoch_reward` would pay out if called right now, as though
+/// vesting had started at `epoch`.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `NotWinningFaction` - If player wasn't in the winning faction
+pub(crate) fn get_claimable_amount(env: &Env, player: &Address, epoch: u32) -> Result<i128, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let config = storage::get_config(env);
+
+    if let Some(entry) = storage::get_vesting_entry(env, epoch, player) {
+        let current_epoch = storage::get_current_epoch(env);
+        let vested = vested_amount(entry.total_amount, current_epoch, entry.start_epoch, entry.vesting_epochs);
+        return Ok((vested - entry.withdrawn).max(0));
+    }
+
+    if storage::has_claimed(env, player, epoch) {
+        return Ok(0);
+    }
+
+    let winning_faction = match epoch_info.winning_faction {
+        Some(faction) => faction,
+        None => return Ok(0),
+    };
+
+    let epoch_player = storage::get_epoch_player(env, epoch, player)?.ok_or(Error::NotWinningFaction)?;
+    if epoch_player.epoch_faction != Some(winning_faction) {
+        return Err(Error::NotWinningFaction);
+    }
+
+    let player_fp = epoch_player.total_fp_contributed;
+    let reward_round = epoch_info.reward_pool;
+    if player_fp == 0 || reward_round.points == 0 || reward_round.rewards == 0 {
+        return Ok(0);
+    }
+
+    let share = claimable_share(player_fp, reward_round.rewards, reward_round.points);
+    if config.vesting_epochs == 0 {
+        return Ok(share);
+    }
+
+    let current_epoch = storage::get_current_epoch(env);
+    Ok(vested_amount(share, current_epoch, epoch, config.vesting_epochs).max(0))
+}
+
+/// Real-time settlement-progress view over a finalized epoch's reward round.
+///
+/// `distributed_amount` and `remaining_amount` always sum to
+/// `total_reward_pool` - both `claim_epoch_reward` and
+/// `distribute_rewards_partition` update the same `DistributedRewards`
+/// counter this reads, so the split is exact regardless of which path (or
+/// mix of both) credited any given claimant.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+pub(crate) fn get_epoch_rewards(env: &Env, epoch: u32) -> Result<EpochRewards, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let total_reward_pool = epoch_info.reward_pool.rewards;
+    let distributed_amount = storage::get_distributed_rewards(env, epoch);
+    let remaining_amount = total_reward_pool - distributed_amount;
+    assert_eq!(
+        distributed_amount + remaining_amount,
+        total_reward_pool,
+        "get_epoch_rewards: distributed + remaining must equal the reward pool"
+    );
+
+    Ok(EpochRewards {
+        total_reward_pool,
+        distributed_amount,
+        remaining_amount,
+        claimant_count: storage::get_claimant_count(env, epoch),
+    })
+}
+
+/// Preview a player's pending reward, scoped to the most recently finalized
+/// epoch rather than a caller-chosen one.
+///
+/// Unlike `get_claimable_amount`, the contract doesn't track an epoch-ID
+/// argument from the caller here - "pending" means "whatever the last
+/// `cycle_epoch` froze", which is the only epoch whose `reward_pool` isn't
+/// still accruing. Epoch 0 never finalizes into a predecessor, so this
+/// simply reports `0` until the first `cycle_epoch` call.
+///
+/// # Errors
+/// * `NotWinningFaction` - If player wasn't in the winning faction
+pub(crate) fn get_pending_rewards(env: &Env, player: &Address) -> Result<i128, Error> {
+    let current_epoch = storage::get_current_epoch(env);
+    let Some(last_finalized) = current_epoch.checked_sub(1) else {
+        return Ok(0);
+    };
+
+    get_claimable_amount(env, player, last_finalized)
+}
+
+// ============================================================================
+// Partitioned Reward Distribution
+// ============================================================================
+
+/// Push a slice of a finalized epoch's winning-faction roster their reward
+/// shares, so settling a large epoch doesn't have to happen in one call.
+///
+/// `start_index`/`count` index into the epoch's roster (see
+/// `storage::get_roster_page`), which is ordered by when each player first
+/// joined the epoch - a stable, deterministic slice that callers can sweep
+/// across in back-to-back calls without overlap or gaps. Participants outside
+/// the winning faction, with no
+/// contributed FP, or already credited (by this or a prior partition, or by a
+/// direct `claim_epoch_reward` call) are skipped rather than erroring, so
+/// partitions can be swept without needing to know who's eligible in advance.
+/// The same running `DistributedRewards(epoch)` invariant `claim_epoch_reward`
+/// uses guards every credit here too, so the two paths can never jointly
+/// overspend the reward pool.
+///
+/// Gated through `config.vesting_epochs` exactly like `claim_epoch_reward`:
+/// when vesting is enabled, each credited player gets a `VestingEntry`
+/// instead of their full `share` transferred up front, and only the
+/// newly-unlocked `withdraw_vested` delta (`share * 0 / vesting_epochs` right
+/// after this partition runs) is paid out now. This call is intentionally
+/// permissionless, so it must never be a way to skip vesting a player never
+/// asked to skip.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `InvalidPartitionRange` - If `count` is zero or `start_index` is beyond the roster
+pub(crate) fn distribute_rewards_partition(
+    env: &Env,
+    epoch: u32,
+    start_index: u32,
+    count: u32,
+) -> Result<u32, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let roster_len = storage::roster_len(env, epoch);
+    if count == 0 || start_index >= roster_len {
+        return Err(Error::InvalidPartitionRange);
+    }
+
+    let winning_faction = match epoch_info.winning_faction {
+        Some(faction) => faction,
+        None => return Ok(0),
+    };
+
+    let reward_round = epoch_info.reward_pool;
+    let config = storage::get_config(env);
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+
+    let end_index = (start_index + count).min(roster_len);
+    let mut distributed_so_far = storage::get_distributed_rewards(env, epoch);
+    let mut credited = 0u32;
+
+    // Walk the roster a page at a time rather than re-fetching per index -
+    // each page covers `ROSTER_PAGE_SIZE` consecutive indices.
+    let mut page_number = start_index / storage::ROSTER_PAGE_SIZE;
+    let mut page = storage::get_roster_page(env, epoch, page_number);
+
+    for i in start_index..end_index {
+        let current_page = i / storage::ROSTER_PAGE_SIZE;
+        if current_page != page_number {
+            page_number = current_page;
+            page = storage::get_roster_page(env, epoch, page_number);
+        }
+        let player = page.get_unchecked(i % storage::ROSTER_PAGE_SIZE);
+
+        if storage::has_claimed(env, &player, epoch) {
+            continue;
+        }
+
+        let epoch_player = match storage::get_epoch_player(env, epoch, &player)? {
+            Some(data) => data,
+            None => continue,
+        };
+        if epoch_player.epoch_faction != Some(winning_faction) {
+            continue;
+        }
+
+        let player_fp = epoch_player.total_fp_contributed;
+        if player_fp == 0 || reward_round.points == 0 || reward_round.rewards == 0 {
+            continue;
+        }
+
+        let share = claimable_share(player_fp, reward_round.rewards, reward_round.points);
+        if share == 0 {
+            continue;
+        }
+
+        let new_total = distributed_so_far
+            .checked_add(share)
+            .expect("reward distribution overflow");
+        if new_total > reward_round.rewards {
+            panic!("distribute_rewards_partition: distributed total would exceed the epoch reward pool");
+        }
+        distributed_so_far = new_total;
+        storage::set_distributed_rewards(env, epoch, distributed_so_far);
+        storage::set_claimed(env, &player, epoch);
+        storage::increment_claimant_count(env, epoch);
+
+        if config.vesting_epochs == 0 {
+            usdc_client.transfer(&env.current_contract_address(), &player, &share);
+        } else {
+            let entry = VestingEntry {
+                total_amount: share,
+                start_epoch: epoch,
+                vesting_epochs: config.vesting_epochs,
+                withdrawn: 0,
+            };
+            storage::set_vesting_entry(env, epoch, &player, &entry);
+            if let Ok(delta) = withdraw_vested(env, &player, epoch, entry) {
+                usdc_client.transfer(&env.current_contract_address(), &player, &delta);
+            }
+        }
+        credited += 1;
+    }
+
+    Ok(credited)
+}
+
+/// Delete `EpochPlayer` bookkeeping for every already-claimed player in
+/// `epoch`'s roster range `[start_index, start_index + count)`, walked a
+/// page at a time the same way `distribute_rewards_partition` does.
+///
+/// Once `storage::has_claimed` is true for a player, none of the three claim
+/// paths (`claim_epoch_reward`, `claim_epochs`, `claim_reward`) ever reads
+/// their `EpochPlayer` again - each checks the claim bitmap first and
+/// returns `RewardAlreadyClaimed` before touching it. But claiming isn't the
+/// only live reader of a past epoch's `EpochPlayer`: `game::finalize_game`
+/// settles a `Provisional` session against its *original* epoch even long
+/// after that epoch has sealed and finalized (a long `dispute_window_secs`
+/// is explicitly allowed to outlive the epoch it started in - see
+/// `game.rs`'s settlement comment), and `proposals::vote` reads
+/// `EpochPlayer(proposal.snapshot_epoch, voter)` for voting weight for as
+/// long as that proposal stays open. Pruning a player's entry out from
+/// under either would strand their wager or zero their voting power with
+/// no recovery. There's no cheap on-chain way to rule out "some session or
+/// proposal still needs this epoch" from here, so rather than pretend to
+/// check it, this is admin-gated: the admin is trusted to confirm every
+/// session touching `epoch` has settled and no open proposal's
+/// `snapshot_epoch` is `epoch` before calling this, the same trust already
+/// placed in `set_epoch_reward_root`'s one-shot, unverified root submission.
+///
+/// Unclaimed players (including anyone who never had an `EpochPlayer` entry
+/// to begin with, or wasn't in the winning faction) are left untouched -
+/// they may still claim later.
+///
+/// # Returns
+/// The number of players pruned.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `InvalidPartitionRange` - If `count` is 0 or `start_index` is past the
+///   end of the roster
+pub(crate) fn prune_claimed_players(
+    env: &Env,
+    epoch: u32,
+    start_index: u32,
+    count: u32,
+) -> Result<u32, Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let roster_len = storage::roster_len(env, epoch);
+    if count == 0 || start_index >= roster_len {
+        return Err(Error::InvalidPartitionRange);
+    }
+
+    let end_index = (start_index + count).min(roster_len);
+    let mut pruned = 0u32;
+
+    let mut page_number = start_index / storage::ROSTER_PAGE_SIZE;
+    let mut page = storage::get_roster_page(env, epoch, page_number);
+
+    for i in start_index..end_index {
+        let current_page = i / storage::ROSTER_PAGE_SIZE;
+        if current_page != page_number {
+            page_number = current_page;
+            page = storage::get_roster_page(env, epoch, page_number);
+        }
+        let player = page.get_unchecked(i % storage::ROSTER_PAGE_SIZE);
+
+        if !storage::has_claimed(env, &player, epoch) {
+            continue;
+        }
+        if !storage::has_epoch_player(env, epoch, &player) {
+            continue;
+        }
+        storage::remove_epoch_player(env, epoch, &player);
+        pruned += 1;
+    }
+
+    Ok(pruned)
+}
+
+/// Sweep a finalized epoch's undistributed truncation dust into the next
+/// epoch's reward pool.
+///
+/// `claim_epoch_reward` and `distribute_rewards_partition` both round each
+/// player's share down, so `rewards - distributed_so_far` is left over once
+/// every eligible player has been paid - at most `points - 1` units per
+/// claimant, but never reclaimed anywhere. Rather than pick a "top
+/// contributor" to dump it on, which would need a full standings scan this
+/// contract doesn't otherwise do, the remainder is carried forward and folded
+/// into whichever epoch finalizes next, so no unit of harvested USDC is ever
+/// permanently stranded. Calling this more than once for the same epoch is
+/// safe - it clamps `distributed_so_far` up to `rewards` so a second sweep
+/// sees no dust left and carries nothing further.
+///
+/// # Returns
+/// The amount of dust carried forward (0 if the epoch was already fully
+/// distributed)
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+pub(crate) fn sweep_dust(env: &Env, epoch: u32) -> Result<i128, Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let reward_round = epoch_info.reward_pool;
+    let distributed_so_far = storage::get_distributed_rewards(env, epoch);
+    let dust = reward_round.rewards - distributed_so_far;
+    if dust <= 0 {
+        return Ok(0);
+    }
+
+    storage::set_distributed_rewards(env, epoch, reward_round.rewards);
+    storage::add_pending_dust_carry(env, dust);
+
+    Ok(dust)
+}
+
+// ============================================================================
+// Merkle Reward Claims
+// ============================================================================
+// An alternative to `claim_epoch_reward`/`distribute_rewards_partition` for
+// epochs whose reward root has been submitted via `set_epoch_reward_root` -
+// see `merkle`'s module doc for why the root is submitted rather than built
+// incrementally on-chain. All three claim paths share the same
+// `ClaimBitmap`/`DistributedRewards` bookkeeping, so whichever one a player
+// (or a settling script) uses first is authoritative and none of them can
+// jointly overspend the reward pool. `prune_claimed_players`, above, reclaims
+// `EpochPlayer` storage for players any of the three have already paid out.
+
+/// Submit the Merkle root over a finalized epoch's reward leaves
+/// (`merkle::leaf_hash(player, amount)` for every eligible player).
+///
+/// One-shot: an epoch's root can never be replaced once set, so a
+/// compromised or mistaken submission can't retroactively redirect
+/// already-trusted claims.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardRootAlreadySet` - If this epoch already has a root
+pub(crate) fn set_epoch_reward_root(env: &Env, epoch: u32, root: &BytesN<32>) -> Result<(), Error> {
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+    if storage::get_epoch_reward_root(env, epoch).is_some() {
+        return Err(Error::RewardRootAlreadySet);
+    }
+
+    storage::set_epoch_reward_root(env, epoch, root);
+    Ok(())
+}
+
+/// Get the reward Merkle root submitted for a finalized epoch, if any.
+pub(crate) fn get_epoch_reward_root(env: &Env, epoch: u32) -> Option<BytesN<32>> {
+    storage::get_epoch_reward_root(env, epoch)
+}
+
+/// Claim `amount` of USDC for `player`'s reward leaf in `epoch`, authorized
+/// by a Merkle `proof` against that epoch's submitted root rather than the
+/// live `EpochPlayer`/FP bookkeeping `claim_epoch_reward` reads.
+///
+/// # Errors
+/// * `EpochNotFinalized` - If epoch doesn't exist or isn't finalized
+/// * `RewardRootNotSet` - If no root has been submitted for this epoch yet
+/// * `RewardAlreadyClaimed` - If player already claimed for this epoch
+/// * `InvalidMerkleProof` - If `proof` doesn't authorize `(player, amount)` against the stored root
+pub(crate) fn claim_reward(
+    env: &Env,
+    player: &Address,
+    epoch: u32,
+    amount: i128,
+    proof: Vec<BytesN<32>>,
+) -> Result<i128, Error> {
+    player.require_auth();
+
+    let epoch_info = storage::get_epoch(env, epoch).ok_or(Error::EpochNotFinalized)?;
+    if !epoch_info.finalized {
+        return Err(Error::EpochNotFinalized);
+    }
+
+    let root = storage::get_epoch_reward_root(env, epoch).ok_or(Error::RewardRootNotSet)?;
+
+    if storage::has_claimed(env, player, epoch) {
+        return Err(Error::RewardAlreadyClaimed);
+    }
+
+    let leaf = merkle::leaf_hash(env, player, amount);
+    if merkle::compute_root(env, leaf, &proof) != root {
+        return Err(Error::InvalidMerkleProof);
+    }
+
+    let reward_round = epoch_info.reward_pool;
+    let distributed_so_far = storage::get_distributed_rewards(env, epoch);
+    let new_total = distributed_so_far
+        .checked_add(amount)
+        .expect("reward distribution overflow");
+    if new_total > reward_round.rewards {
+        panic!("claim_reward: distributed total would exceed the epoch reward pool");
+    }
+    storage::set_distributed_rewards(env, epoch, new_total);
+    storage::set_claimed(env, player, epoch);
+    storage::increment_claimant_count(env, epoch);
+
+    let config = storage::get_config(env);
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    usdc_client.transfer(&env.current_contract_address(), player, &amount);
+
+    Ok(amount)
+}
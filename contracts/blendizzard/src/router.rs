@@ -0,0 +1,21 @@
+//! External contract type definition for the Soroswap router.
+//!
+//! We only declare the entrypoint Blendizzard actually calls (swapping BLND
+//! yield into USDC during epoch cycling).
+
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+#[contractclient(name = "Client")]
+pub trait SoroswapRouterTrait {
+    /// Swap an exact `amount_in` of `path[0]` for at least `amount_out_min`
+    /// of `path[path.len() - 1]`, crediting `to`. Returns the actual amounts
+    /// at each hop of `path`. Reverts if the minimum can't be met.
+    fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+}
@@ -1,18 +1,25 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
+use crate::errors::Error;
 use crate::types::{
-    Config, EpochInfo, EpochPlayer, EpochPlayerV0, GameSession, Player, PlayerV0, PlayerV1,
+    AssetVaultEntry, Config, EmissionConfig, EpochInfo, EpochPlayer, EpochPlayerV0, EpochSummary,
+    FinalizationCursor, GameSession, Lockup, Player, PlayerAccess, PlayerV0, PlayerV1,
+    PriceAccumulator, Proposal, StorageStatus, VestingEntry, YieldPosition,
 };
 
+/// One queued-but-unclaimed withdrawal: `(unlock_epoch, amount)`. Matures
+/// once the current epoch reaches `unlock_epoch`.
+pub(crate) type UnbondingChunk = (u32, i128);
+
 // ============================================================================
 // Storage Keys
 // ============================================================================
 // Uses type-safe enum keys to prevent storage collisions and improve type safety
 //
 // Storage Types:
-// - Instance: Admin, Config, CurrentEpoch, Paused
-// - Persistent: Player, Game
-// - Temporary: EpochPlayer, Epoch, Session, Claimed
+// - Instance: Admin, Config, CurrentEpoch, Paused, AccYieldPerToken, TotalTrackedDeposits, PendingYieldCarry
+// - Persistent: Player, Game, ClaimBitmap, UnbondingQueue, YieldPosition, AssetVault, AssetTotalDeposited, Lockup, VestingEntry
+// - Temporary: EpochPlayer, Epoch, Session
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -54,8 +61,275 @@ pub enum DataKey {
     /// Whitelisted game contracts - Game(game_address) -> bool (Persistent storage)
     Game(Address),
 
-    /// Reward claim tracking - Claimed(player_address, epoch_number) -> bool (Temporary storage)
+    /// OLD - Reward claim tracking (for migration only - DO NOT USE). Lived
+    /// in temporary storage, so a claim record could expire and reopen a
+    /// double-claim window; superseded by `ClaimBitmap`.
+    #[deprecated]
     Claimed(Address, u32),
+
+    /// Reward claim bitmap - ClaimBitmap(player_address, window_number) ->
+    /// u128 bitfield (Persistent storage). Epoch `e` maps to window `e /
+    /// 128`, bit `e % 128`. Persistent (not temporary) so a claim record can
+    /// never expire and reopen a double-claim window; one entry covers 128
+    /// epochs instead of one entry per epoch.
+    ClaimBitmap(Address, u32),
+
+    /// Live per-faction FP standings for an epoch, indexed by faction ID -
+    /// FactionStandings(epoch_number) -> [u128; 3] (Temporary storage)
+    FactionStandings(u32),
+
+    /// Running total of rewards distributed so far for an epoch - used to
+    /// enforce the "never distribute more than the reward pool" invariant -
+    /// DistributedRewards(epoch_number) -> i128 (Temporary storage)
+    DistributedRewards(u32),
+
+    /// Price submitted by a given oracle key for an epoch -
+    /// PriceSubmission(epoch_number, oracle_pubkey) -> i128 (Temporary storage)
+    PriceSubmission(u32, BytesN<32>),
+
+    /// Distinct oracle keys that have submitted a price for an epoch, in
+    /// submission order - PriceSubmitters(epoch_number) -> Vec<BytesN<32>>
+    /// (Temporary storage)
+    PriceSubmitters(u32),
+
+    /// Median BLND/USDC reference price for an epoch, once threshold
+    /// submissions are in, alongside the ledger timestamp it was set at so
+    /// `oracle::get_fresh_reference_price` can judge staleness -
+    /// ReferencePrice(epoch_number) -> (i128, u64) (Temporary storage)
+    ReferencePrice(u32),
+
+    /// Count of distinct players who have played at least one game in an
+    /// epoch - EpochRosterMeta(epoch_number) -> u32 (Temporary storage)
+    EpochRosterMeta(u32),
+
+    /// One fixed-size page of an epoch's participant roster, in join order -
+    /// EpochRosterPage(epoch_number, page_number) -> Vec<Address>
+    /// (Temporary storage). Bounded to `ROSTER_PAGE_SIZE` entries per page so
+    /// a settlement function can walk the whole roster in fixed-size chunks
+    /// without an unbounded-length read.
+    EpochRosterPage(u32, u32),
+
+    /// Dedupe flag set the first time a player is added to an epoch's
+    /// roster - EpochRosterSeen(epoch_number, player_address) -> bool
+    /// (Temporary storage)
+    EpochRosterSeen(u32, Address),
+
+    /// Truncation dust swept from a finalized epoch's reward round, awaiting
+    /// the next epoch's finalization to fold it into that epoch's reward
+    /// pool - singleton (Instance storage)
+    PendingDustCarry,
+
+    /// Admin-configured emission bonus schedule - singleton (Instance storage)
+    EmissionConfig,
+
+    /// Count of distinct players credited so far for an epoch's reward round,
+    /// via either `claim_epoch_reward` or `distribute_rewards_partition` -
+    /// ClaimantCount(epoch_number) -> u32 (Temporary storage)
+    ClaimantCount(u32),
+
+    /// Seal flag marking an epoch's accumulation phase permanently closed -
+    /// EpochSealed(epoch_number) -> bool (Temporary storage). Once set,
+    /// `set_epoch_player`/`set_epoch`/`add_faction_points` for that epoch
+    /// return `Error::EpochSealed` instead of writing; reads are unaffected.
+    EpochSealed(u32),
+
+    /// Schema version tag for a player's persistent entry -
+    /// PlayerSchema(player_address) -> u32 (Persistent storage). Consulted by
+    /// `migrate::upgrade_player` to run exactly the steps needed to reach
+    /// `migrate::PLAYER_CURRENT_VERSION`.
+    PlayerSchema(Address),
+
+    /// Schema version tag for an epoch player's entry -
+    /// EpochPlayerSchema(epoch_number, player_address) -> u32 (Temporary
+    /// storage). Consulted by `migrate::upgrade_epoch_player`.
+    EpochPlayerSchema(u32, Address),
+
+    /// A player's queued unbonding withdrawals, oldest first -
+    /// UnbondingQueue(player_address) -> Vec<(unlock_epoch, amount)>
+    /// (Persistent storage). Capped to `vault::MAX_UNBONDING_CHUNKS` entries.
+    UnbondingQueue(Address),
+
+    /// Global accumulated yield per tracked deposit token, scaled by
+    /// `SCALAR_7` - singleton (Instance storage). See
+    /// `vault::distribute_yield`.
+    AccYieldPerToken,
+
+    /// Sum of every player's `YieldPosition.tracked_balance` - singleton
+    /// (Instance storage).
+    TotalTrackedDeposits,
+
+    /// A player's position in the yield accumulator -
+    /// YieldPosition(player_address) -> YieldPosition (Persistent storage).
+    YieldPosition(Address),
+
+    /// Yield handed to `vault::distribute_yield` while `TotalTrackedDeposits`
+    /// was zero, parked here until a later call sees a nonzero total -
+    /// singleton (Instance storage).
+    PendingYieldCarry,
+
+    /// Registered routing target for a deposit asset - AssetVault(asset_address)
+    /// -> AssetVaultEntry (Persistent storage). See
+    /// `vault::record_deposit_for_asset`.
+    AssetVault(Address),
+
+    /// Running total raw (non-normalized) deposits recorded for an asset -
+    /// AssetTotalDeposited(asset_address) -> i128 (Persistent storage).
+    /// Informational only; `TotalTrackedDeposits` (in the common unit) is
+    /// what bounds checking is done against.
+    AssetTotalDeposited(Address),
+
+    /// A player's current lockup commitment - Lockup(player_address) ->
+    /// Lockup (Persistent storage). See `vault::record_locked_deposit`.
+    Lockup(Address),
+
+    /// Merkle root over every winning-faction player's reward leaf for a
+    /// finalized epoch - EpochRewardRoot(epoch_number) -> BytesN<32>
+    /// (Persistent storage). See `merkle::leaf_hash`/`rewards::claim_reward`.
+    /// Set once via `set_epoch_reward_root`; never overwritten.
+    EpochRewardRoot(u32),
+
+    /// A player's linear unlock schedule for an epoch's reward share -
+    /// VestingEntry(epoch_number, player_address) -> VestingEntry
+    /// (Persistent storage, like `ClaimBitmap` - must survive however many
+    /// epochs `Config.vesting_epochs` spans). Created the first time
+    /// `rewards::claim_epoch_reward` is called against this epoch while
+    /// vesting is enabled; absent otherwise.
+    VestingEntry(u32, Address),
+
+    /// Count of distinct players who have ever selected a faction - singleton
+    /// (Instance storage). Checked against `config.max_players` in
+    /// `faction::select_faction`.
+    TotalPlayers,
+
+    /// The live BLND/USDC cumulative-price accumulator - singleton (Instance
+    /// storage). Rolled forward by `oracle::accumulate_price` every time a
+    /// fresh reference price is finalized.
+    PriceAccumulator,
+
+    /// The accumulator snapshot taken at the last `oracle::check_twap_deviation`
+    /// call - singleton (Instance storage). Paired with the live
+    /// `PriceAccumulator` to derive a TWAP over the time since that check.
+    PriceAccumulatorSnapshot,
+
+    /// Permanent archive of a finalized epoch's outcome -
+    /// EpochSummary(epoch_number) -> EpochSummary (Persistent storage). Set
+    /// once via `set_epoch_summary`; never overwritten. Unlike `Epoch`, not
+    /// subject to temporary storage's TTL expiry - see `get_epoch_summary`.
+    EpochSummary(u32),
+
+    /// The m-of-n admin signer set, fixed at construction - singleton
+    /// (Instance storage). See `governance::is_admin_signer`.
+    AdminSigners,
+
+    /// Approvals required out of `AdminSigners` before a proposed action may
+    /// execute - singleton (Instance storage).
+    AdminThreshold,
+
+    /// Seconds a proposed action must sit fully-approved before it may
+    /// execute, on top of collecting `AdminThreshold` approvals - singleton
+    /// (Instance storage). `0` disables the delay (approval alone suffices).
+    AdminTimelock,
+
+    /// Ledger timestamp a multisig action was first proposed at -
+    /// ActionProposedAt(action_hash) -> u64 (Temporary storage). Absent (or
+    /// `0`) means nobody has proposed this hash yet.
+    ActionProposedAt(BytesN<32>),
+
+    /// Running count of distinct admin signers who have approved a proposed
+    /// action - ActionApprovalCount(action_hash) -> u32 (Temporary storage).
+    ActionApprovalCount(BytesN<32>),
+
+    /// Dedupe flag so a signer's approval is only ever counted once per
+    /// action - ActionApprovedBy(action_hash, signer_address) -> bool
+    /// (Temporary storage).
+    ActionApprovedBy(BytesN<32>, Address),
+
+    /// Permanent replay guard marking an `action_hash` as already executed -
+    /// ActionExecuted(action_hash) -> bool (Persistent storage, like
+    /// `ClaimBitmap` - must never expire and reopen a re-execution window).
+    ActionExecuted(BytesN<32>),
+
+    /// Minimum yes-vote share (bps of the snapshot epoch's total FP) a
+    /// `proposals::Proposal` needs to pass - singleton (Instance storage),
+    /// fixed at construction like `AdminThreshold` so the admin can't
+    /// unilaterally raise it to block proposals later. See `proposals`.
+    GovernanceQuorumBps,
+
+    /// Minimum yes-vote share (bps of `yes_votes + no_votes`) a
+    /// `proposals::Proposal` needs to pass - singleton (Instance storage),
+    /// fixed at construction.
+    GovernanceMajorityBps,
+
+    /// How long (in seconds) a `proposals::Proposal` stays open for voting
+    /// after it's created - singleton (Instance storage), fixed at
+    /// construction.
+    GovernanceVotingPeriod,
+
+    /// Seconds a passed `proposals::Proposal` must sit past its voting
+    /// deadline before `proposals::execute` may dispatch it - singleton
+    /// (Instance storage), fixed at construction. `0` disables the delay.
+    GovernanceTimelock,
+
+    /// Next unused governance proposal id - singleton (Instance storage).
+    NextProposalId,
+
+    /// A governance proposal's full state - Proposal(proposal_id) ->
+    /// Proposal (Persistent storage, since a voting period plus timelock can
+    /// outlast temporary storage's TTL window).
+    Proposal(u32),
+
+    /// Dedupe flag so an address's vote on a proposal is only ever counted
+    /// once - ProposalVoted(proposal_id, voter_address) -> bool (Persistent
+    /// storage).
+    ProposalVoted(u32, Address),
+
+    /// The in-flight `epoch::cycle_epoch_step` finalization, if any -
+    /// singleton (Instance storage). Absent whenever no epoch is currently
+    /// being torn down.
+    FinalizationCursor,
+
+    /// One epoch's top-N leaderboard by `total_fp_contributed`, sorted
+    /// descending - EpochLeaderboard(epoch_number) -> Vec<(Address, i128)>
+    /// (Temporary storage, like `FactionStandings`). Capped to
+    /// `Config.leaderboard_size` entries by
+    /// `leaderboard::record_contribution`; absent (empty) for any epoch that
+    /// predates this feature or never had an eligible contribution.
+    EpochLeaderboard(u32),
+
+    /// Cumulative top-N leaderboard across every epoch, sorted descending by
+    /// lifetime contribution - singleton (Instance storage). Capped to
+    /// `Config.leaderboard_size` entries, like `EpochLeaderboard`.
+    AllTimeLeaderboard,
+
+    /// A player's lifetime cumulative contribution across every epoch, kept
+    /// so `leaderboard::record_contribution` can reinsert them into
+    /// `AllTimeLeaderboard` at the right position without rescanning it -
+    /// AllTimeScore(player_address) -> i128 (Persistent storage, like
+    /// `YieldPosition` - must survive indefinitely).
+    AllTimeScore(Address),
+
+    /// Session ids a game contract currently has open (`Pending` or
+    /// `Provisional`) - GameOpenSessions(game_address) -> Vec<u32>
+    /// (Persistent storage, like `UnbondingQueue` - must outlast a
+    /// `Config.dispute_window_secs` that can run longer than temporary
+    /// storage's TTL). Lets `game::slash_game` find every still-provisional
+    /// session for a game without scanning all sessions; entries are removed
+    /// as sessions settle/dispute, so with the default disabled dispute
+    /// window (`0`) this stays empty in steady state.
+    GameOpenSessions(Address),
+
+    /// Count of a game contract's sessions that settled via
+    /// `game::dispute_game`/`game::slash_game` instead of
+    /// `game::finalize_game` - GameDisputeCount(game_address) -> u32
+    /// (Persistent storage). Checked against
+    /// `Config.game_auto_slash_threshold` by `game::dispute_game`.
+    GameDisputeCount(Address),
+
+    /// Count of a game contract's sessions that reached
+    /// `SessionStatus::Settled` - GameSettledCount(game_address) -> u32
+    /// (Persistent storage). Tracked alongside `GameDisputeCount` so an
+    /// indexer can compute a game's dispute rate.
+    GameSettledCount(Address),
 }
 
 // ============================================================================
@@ -80,6 +354,200 @@ pub(crate) fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
 
+// ============================================================================
+// Multisig Governance
+// ============================================================================
+
+/// Get the admin signer set
+pub(crate) fn get_admin_signers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AdminSigners)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Set the admin signer set - only ever called once, from `__constructor`
+pub(crate) fn set_admin_signers(env: &Env, signers: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::AdminSigners, signers);
+}
+
+/// Get the number of signer approvals a proposed action needs to execute
+pub(crate) fn get_admin_threshold(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::AdminThreshold).unwrap_or(0)
+}
+
+/// Set the admin approval threshold - only ever called once, from `__constructor`
+pub(crate) fn set_admin_threshold(env: &Env, threshold: u32) {
+    env.storage().instance().set(&DataKey::AdminThreshold, &threshold);
+}
+
+/// Get the delay (in seconds) a fully-approved action must still wait out
+pub(crate) fn get_admin_timelock(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::AdminTimelock).unwrap_or(0)
+}
+
+/// Set the admin timelock - only ever called once, from `__constructor`
+pub(crate) fn set_admin_timelock(env: &Env, timelock: u64) {
+    env.storage().instance().set(&DataKey::AdminTimelock, &timelock);
+}
+
+/// Ledger timestamp `action_hash` was first proposed at, or `0` if nobody has
+pub(crate) fn get_action_proposed_at(env: &Env, action_hash: &BytesN<32>) -> u64 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ActionProposedAt(action_hash.clone()))
+        .unwrap_or(0)
+}
+
+/// Record `action_hash` as proposed at the current ledger timestamp
+pub(crate) fn set_action_proposed_at(env: &Env, action_hash: &BytesN<32>, proposed_at: u64) {
+    let key = DataKey::ActionProposedAt(action_hash.clone());
+    env.storage().temporary().set(&key, &proposed_at);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Number of distinct admin signers who have approved `action_hash` so far
+pub(crate) fn get_action_approval_count(env: &Env, action_hash: &BytesN<32>) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ActionApprovalCount(action_hash.clone()))
+        .unwrap_or(0)
+}
+
+/// Whether `signer` has already approved `action_hash`
+pub(crate) fn has_action_approval(env: &Env, action_hash: &BytesN<32>, signer: &Address) -> bool {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ActionApprovedBy(action_hash.clone(), signer.clone()))
+        .unwrap_or(false)
+}
+
+/// Record `signer`'s approval of `action_hash` and bump its approval count -
+/// caller must already have checked `has_action_approval` is false
+pub(crate) fn record_action_approval(env: &Env, action_hash: &BytesN<32>, signer: &Address) {
+    let approved_key = DataKey::ActionApprovedBy(action_hash.clone(), signer.clone());
+    env.storage().temporary().set(&approved_key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&approved_key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+
+    let count_key = DataKey::ActionApprovalCount(action_hash.clone());
+    let count = get_action_approval_count(env, action_hash) + 1;
+    env.storage().temporary().set(&count_key, &count);
+    env.storage()
+        .temporary()
+        .extend_ttl(&count_key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Whether `action_hash` has already been consumed by
+/// `governance::consume_approved_action`
+pub(crate) fn is_action_executed(env: &Env, action_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActionExecuted(action_hash.clone()))
+        .unwrap_or(false)
+}
+
+/// Permanently mark `action_hash` as executed
+pub(crate) fn set_action_executed(env: &Env, action_hash: &BytesN<32>) {
+    let key = DataKey::ActionExecuted(action_hash.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+// ============================================================================
+// FP-Weighted Governance
+// ============================================================================
+
+/// Get the configured proposal quorum, in bps of the snapshot epoch's total FP
+pub(crate) fn get_governance_quorum_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::GovernanceQuorumBps).unwrap_or(0)
+}
+
+/// Set the governance quorum - only ever called once, from `__constructor`
+pub(crate) fn set_governance_quorum_bps(env: &Env, quorum_bps: u32) {
+    env.storage().instance().set(&DataKey::GovernanceQuorumBps, &quorum_bps);
+}
+
+/// Get the configured proposal majority threshold, in bps of votes cast
+pub(crate) fn get_governance_majority_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::GovernanceMajorityBps).unwrap_or(0)
+}
+
+/// Set the governance majority threshold - only ever called once, from `__constructor`
+pub(crate) fn set_governance_majority_bps(env: &Env, majority_bps: u32) {
+    env.storage().instance().set(&DataKey::GovernanceMajorityBps, &majority_bps);
+}
+
+/// Get how long (in seconds) a proposal stays open for voting
+pub(crate) fn get_governance_voting_period(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::GovernanceVotingPeriod).unwrap_or(0)
+}
+
+/// Set the governance voting period - only ever called once, from `__constructor`
+pub(crate) fn set_governance_voting_period(env: &Env, voting_period: u64) {
+    env.storage().instance().set(&DataKey::GovernanceVotingPeriod, &voting_period);
+}
+
+/// Get the timelock (in seconds) a passed proposal must wait past its voting deadline
+pub(crate) fn get_governance_timelock(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::GovernanceTimelock).unwrap_or(0)
+}
+
+/// Set the governance timelock - only ever called once, from `__constructor`
+pub(crate) fn set_governance_timelock(env: &Env, timelock: u64) {
+    env.storage().instance().set(&DataKey::GovernanceTimelock, &timelock);
+}
+
+/// Allocate and return the next unused proposal id
+pub(crate) fn next_proposal_id(env: &Env) -> u32 {
+    let id = env.storage().instance().get(&DataKey::NextProposalId).unwrap_or(0);
+    env.storage().instance().set(&DataKey::NextProposalId, &(id + 1));
+    id
+}
+
+/// Get a governance proposal by id
+pub(crate) fn get_proposal(env: &Env, proposal_id: u32) -> Option<Proposal> {
+    let key = DataKey::Proposal(proposal_id);
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+    }
+    result
+}
+
+/// Set a governance proposal's state
+pub(crate) fn set_proposal(env: &Env, proposal_id: u32, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Whether `voter` has already voted on `proposal_id`
+pub(crate) fn has_voted(env: &Env, proposal_id: u32, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalVoted(proposal_id, voter.clone()))
+        .unwrap_or(false)
+}
+
+/// Record that `voter` has voted on `proposal_id`
+pub(crate) fn set_voted(env: &Env, proposal_id: u32, voter: &Address) {
+    let key = DataKey::ProposalVoted(proposal_id, voter.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
 /// Get the global configuration
 pub(crate) fn get_config(env: &Env) -> Config {
     env.storage()
@@ -106,14 +574,91 @@ pub(crate) fn set_current_epoch(env: &Env, epoch: u32) {
     env.storage().instance().set(&DataKey::CurrentEpoch, &epoch);
 }
 
+/// Get the in-flight `epoch::cycle_epoch_step` finalization, if any.
+pub(crate) fn get_finalization_cursor(env: &Env) -> Option<FinalizationCursor> {
+    env.storage().instance().get(&DataKey::FinalizationCursor)
+}
+
+/// Set the in-flight finalization cursor.
+pub(crate) fn set_finalization_cursor(env: &Env, cursor: &FinalizationCursor) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FinalizationCursor, cursor);
+}
+
+/// Clear the finalization cursor - called once `cycle_epoch_step` reaches
+/// `FinalizationPhase::Done`.
+pub(crate) fn clear_finalization_cursor(env: &Env) {
+    env.storage().instance().remove(&DataKey::FinalizationCursor);
+}
+
 /// Get player persistent data
-pub(crate) fn get_player(env: &Env, player: &Address) -> Option<Player> {
+///
+/// Lazily upgrades a legacy entry to the current schema first (see
+/// `migrate::upgrade_player`), then is restore-checked: a player dormant
+/// long enough for their entry to archive reads back as `None` here rather
+/// than trapping the transaction.
+///
+/// # Errors
+/// * `CorruptStorage` - If the entry's schema tag doesn't match any layout
+///   `migrate::upgrade_player` knows how to read. See `diagnose_player` to
+///   detect this ahead of time.
+pub(crate) fn get_player(env: &Env, player: &Address) -> Result<Option<Player>, Error> {
+    crate::migrate::upgrade_player(env, player)?;
+    if let PlayerAccess::Missing = restore_player(env, player) {
+        return Ok(None);
+    }
+    Ok(env.storage().persistent().get(&DataKey::Player(player.clone())))
+}
+
+/// Check a player's persistent entry for liveness, renewing its TTL if
+/// it's within `TTL_THRESHOLD_LEDGERS` of archiving.
+///
+/// Reading an entry the host has archived past its TTL traps rather than
+/// returning `None`, so this (or `get_player_checked`) must run before any
+/// `get()` on a player who might have gone dormant a long time - the whole
+/// point of keeping `Player` in persistent storage rather than temporary.
+pub(crate) fn restore_player(env: &Env, player: &Address) -> PlayerAccess {
     let key = DataKey::Player(player.clone());
-    let result = env.storage().persistent().get(&key);
-    if result.is_some() {
-        extend_player_ttl(env, player);
+    if !env.storage().persistent().has(&key) {
+        return PlayerAccess::Missing;
+    }
+    let was_near_expiry = env.storage().persistent().ttl(&key) <= TTL_THRESHOLD_LEDGERS;
+    extend_player_ttl(env, player);
+    if was_near_expiry {
+        PlayerAccess::Restored
+    } else {
+        PlayerAccess::Live
+    }
+}
+
+/// `restore_player` plus the data itself, for callers that want to surface
+/// the liveness outcome (e.g. a restore-fee warning) alongside the read.
+pub(crate) fn get_player_checked(env: &Env, player: &Address) -> (PlayerAccess, Option<Player>) {
+    let access = restore_player(env, player);
+    let data = match access {
+        PlayerAccess::Missing => None,
+        _ => env.storage().persistent().get(&DataKey::Player(player.clone())),
+    };
+    (access, data)
+}
+
+/// Same liveness check as `restore_player`, for a whitelisted game
+/// contract's persistent entry.
+pub(crate) fn restore_game(env: &Env, game_id: &Address) -> PlayerAccess {
+    let key = DataKey::Game(game_id.clone());
+    if !env.storage().persistent().has(&key) {
+        return PlayerAccess::Missing;
+    }
+    let was_near_expiry = env.storage().persistent().ttl(&key) <= TTL_THRESHOLD_LEDGERS;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+    if was_near_expiry {
+        PlayerAccess::Restored
+    } else {
+        PlayerAccess::Live
     }
-    result
 }
 
 /// Set player persistent data
@@ -124,139 +669,178 @@ pub(crate) fn set_player(env: &Env, player: &Address, data: &Player) {
     extend_player_ttl(env, player);
 }
 
-/// Migrate player data from old formats to current format
-///
-/// Handles migration from:
-/// - Old storage key (DataKey::Player) to new key (DataKey::Player)
-/// - V0 (pre-Nov 10): selected_faction, total_deposited, deposit_timestamp
-/// - V1 (Nov 10-12): selected_faction, deposit_timestamp, last_epoch_balance
-/// - V2 (current): selected_faction, time_multiplier_start, last_epoch_balance
-///
-/// This reads old storage keys and struct formats, deletes them, and writes back the current format.
-/// Returns true if migration was performed, false if player doesn't exist or is already migrated.
-pub(crate) fn migrate_player_storage(env: &Env, player: &Address) -> bool {
-    let new_key = DataKey::Player(player.clone());
-    let old_key = DataKey::User(player.clone());
-
-    // Try to read as current format (V2) with new key first
-    if let Some(_) = get_player(env, player) {
-        // Already in new format with new key, no migration needed
-        return false;
+/// A player's schema version tag, defaulting for untagged entries that
+/// predate the versioning system: already under the canonical `Player` key
+/// means current, already under the legacy `User` key means version 0 (the
+/// oldest `migrate` step knows how to read it regardless of its actual
+/// shape), and otherwise the player just doesn't exist yet.
+pub(crate) fn get_player_schema_version(env: &Env, player: &Address) -> u32 {
+    let key = DataKey::PlayerSchema(player.clone());
+    if let Some(version) = env.storage().persistent().get(&key) {
+        return version;
     }
-
-    // Try to read from old key as V1 format (deposit_timestamp + last_epoch_balance)
-    let v1_data: Option<PlayerV1> = env.storage().persistent().get(&old_key);
-    if let Some(old) = v1_data {
-        // Convert V1 to V2
-        let new_data = Player {
-            selected_faction: old.selected_faction,
-            time_multiplier_start: old.deposit_timestamp, // Field rename
-            last_epoch_balance: old.last_epoch_balance,
-        };
-
-        // Delete old key
-        env.storage().persistent().remove(&old_key);
-
-        // Write back with new key and format
-        set_player(env, player, &new_data);
-        return true;
+    if env.storage().persistent().has(&DataKey::Player(player.clone())) {
+        crate::migrate::PLAYER_CURRENT_VERSION
+    } else if env.storage().persistent().has(&DataKey::User(player.clone())) {
+        0
+    } else {
+        crate::migrate::PLAYER_CURRENT_VERSION
     }
+}
 
-    // Try to read from old key as V0 format (total_deposited + deposit_timestamp)
-    let v0_data: Option<PlayerV0> = env.storage().persistent().get(&old_key);
-    if let Some(old) = v0_data {
-        // Convert V0 to V2
-        let new_data = Player {
-            selected_faction: old.selected_faction,
-            time_multiplier_start: old.deposit_timestamp, // Field rename
-            last_epoch_balance: 0, // V0 didn't track this, set to 0 (no previous epoch)
-        };
+/// Tag a player's entry with its current schema version
+pub(crate) fn set_player_schema_version(env: &Env, player: &Address, version: u32) {
+    let key = DataKey::PlayerSchema(player.clone());
+    env.storage().persistent().set(&key, &version);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
 
-        // Delete old key
-        env.storage().persistent().remove(&old_key);
+/// Raw schema version tag for a player's persistent entry, with none of
+/// `get_player_schema_version`'s defaulting for untagged entries - `None`
+/// means no `PlayerSchema` tag has ever been written, distinct from "tagged
+/// 0". Used by `diagnose_player` to tell a genuinely untagged legacy/current
+/// entry apart from one carrying a tag outside every version `migrate` knows.
+fn get_player_schema_version_raw(env: &Env, player: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PlayerSchema(player.clone()))
+}
 
-        // Write back with new key and format
-        set_player(env, player, &new_data);
-        return true;
+/// Fail-closed, read-only diagnosis of a player's persistent entry, so
+/// front-ends and the `migrate_player` flow can detect a bad record
+/// deterministically instead of discovering it through a trapped
+/// transaction - see `errors::Error::CorruptStorage`.
+#[allow(deprecated)]
+pub(crate) fn diagnose_player(env: &Env, player: &Address) -> StorageStatus {
+    let has_current = env.storage().persistent().has(&DataKey::Player(player.clone()));
+    let has_legacy = env.storage().persistent().has(&DataKey::User(player.clone()));
+    if !has_current && !has_legacy {
+        return StorageStatus::Absent;
     }
 
-    // Try to read from new key as V2 format but check if it needs schema fix
-    // This handles the edge case where key was already migrated but schema wasn't
-    let new_key_v1: Option<PlayerV1> = env.storage().persistent().get(&new_key);
-    if let Some(old) = new_key_v1 {
-        let new_data = Player {
-            selected_faction: old.selected_faction,
-            time_multiplier_start: old.deposit_timestamp,
-            last_epoch_balance: old.last_epoch_balance,
-        };
-        env.storage().persistent().remove(&new_key);
-        set_player(env, player, &new_data);
-        return true;
+    match get_player_schema_version_raw(env, player) {
+        Some(version) if version > crate::migrate::PLAYER_CURRENT_VERSION => {
+            StorageStatus::Corrupt(version)
+        }
+        Some(version) if version == crate::migrate::PLAYER_CURRENT_VERSION => {
+            StorageStatus::Current
+        }
+        Some(version) => StorageStatus::NeedsMigration(version),
+        None if has_current => StorageStatus::Current,
+        None => StorageStatus::NeedsMigration(0),
     }
-
-    // Player doesn't exist in any format
-    false
 }
 
-/// Migrate epoch-specific player data from old storage key to new one
-///
-/// Handles migration from:
-/// - Old storage key (DataKey::EpochUser) to new key (DataKey::EpochPlayer)
-/// - V0 (pre-Nov 13): EpochPlayer with locked_fp field
-/// - V1 (current): EpochPlayer without locked_fp field
-///
-/// Returns true if migration was performed, false if already migrated or doesn't exist.
-pub(crate) fn migrate_epoch_player_storage(env: &Env, epoch: u32, player: &Address) -> bool {
-    let new_key = DataKey::EpochPlayer(epoch, player.clone());
-    let old_key = DataKey::EpochUser(epoch, player.clone());
-
-    // Check if already migrated to new format (exists in new key with new format)
-    let new_format_check: Option<EpochPlayer> = env.storage().temporary().get(&new_key);
-    if new_format_check.is_some() {
-        return false;
-    }
+/// Read a player's legacy entry (`User` key) in `PlayerV0` format
+#[allow(deprecated)]
+pub(crate) fn get_legacy_player_v0(env: &Env, player: &Address) -> Option<PlayerV0> {
+    env.storage().persistent().get(&DataKey::User(player.clone()))
+}
 
-    // Try to read from old key as V0 format (with locked_fp)
-    let v0_data: Option<EpochPlayerV0> = env.storage().temporary().get(&old_key);
-    if let Some(old) = v0_data {
-        // Convert V0 to V1 (drop locked_fp field)
-        let new_data = EpochPlayer {
-            epoch_faction: old.epoch_faction,
-            epoch_balance_snapshot: old.epoch_balance_snapshot,
-            available_fp: old.available_fp,
-            total_fp_contributed: old.total_fp_contributed,
-        };
+/// Read a player's legacy entry (`User` key) in `PlayerV1` format
+#[allow(deprecated)]
+pub(crate) fn get_legacy_player_v1(env: &Env, player: &Address) -> Option<PlayerV1> {
+    env.storage().persistent().get(&DataKey::User(player.clone()))
+}
 
-        // Write to new key
-        env.storage().temporary().set(&new_key, &new_data);
-        extend_epoch_player_ttl(env, epoch, player);
+/// Overwrite a player's legacy entry with the `PlayerV1` format - an
+/// intermediate shape on the way to `Player` that the next migration step
+/// reads and then deletes, never read back by anything else.
+#[allow(deprecated)]
+pub(crate) fn set_legacy_player_v1(env: &Env, player: &Address, data: &PlayerV1) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::User(player.clone()), data);
+}
 
-        // Delete old key
-        env.storage().temporary().remove(&old_key);
+/// Delete a player's legacy (`User` key) entry once fully migrated
+#[allow(deprecated)]
+pub(crate) fn remove_legacy_player(env: &Env, player: &Address) {
+    env.storage().persistent().remove(&DataKey::User(player.clone()));
+}
 
-        return true;
+/// An epoch player's schema version tag, defaulting for untagged entries
+/// the same way `get_player_schema_version` does for `Player`.
+pub(crate) fn get_epoch_player_schema_version(env: &Env, epoch: u32, player: &Address) -> u32 {
+    let key = DataKey::EpochPlayerSchema(epoch, player.clone());
+    if let Some(version) = env.storage().temporary().get(&key) {
+        return version;
+    }
+    if env
+        .storage()
+        .temporary()
+        .has(&DataKey::EpochPlayer(epoch, player.clone()))
+    {
+        crate::migrate::EPOCH_PLAYER_CURRENT_VERSION
+    } else if env
+        .storage()
+        .temporary()
+        .has(&DataKey::EpochUser(epoch, player.clone()))
+    {
+        0
+    } else {
+        crate::migrate::EPOCH_PLAYER_CURRENT_VERSION
     }
+}
 
-    // Try to read from new key as V0 format (with locked_fp) - in case it was partially migrated
-    let v0_new_key_data: Option<EpochPlayerV0> = env.storage().temporary().get(&new_key);
-    if let Some(old) = v0_new_key_data {
-        // Convert V0 to V1 (drop locked_fp field)
-        let new_data = EpochPlayer {
-            epoch_faction: old.epoch_faction,
-            epoch_balance_snapshot: old.epoch_balance_snapshot,
-            available_fp: old.available_fp,
-            total_fp_contributed: old.total_fp_contributed,
-        };
+/// Tag an epoch player's entry with its current schema version
+pub(crate) fn set_epoch_player_schema_version(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+    version: u32,
+) {
+    let key = DataKey::EpochPlayerSchema(epoch, player.clone());
+    env.storage().temporary().set(&key, &version);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
 
-        // Overwrite with new format
-        env.storage().temporary().set(&new_key, &new_data);
-        extend_epoch_player_ttl(env, epoch, player);
+/// Read an epoch player's legacy entry (`EpochUser` key) in `EpochPlayerV0` format
+#[allow(deprecated)]
+pub(crate) fn get_legacy_epoch_player_v0(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+) -> Option<EpochPlayerV0> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EpochUser(epoch, player.clone()))
+}
 
-        return true;
-    }
+/// Delete an epoch player's legacy (`EpochUser` key) entry once fully migrated
+#[allow(deprecated)]
+pub(crate) fn remove_legacy_epoch_player(env: &Env, epoch: u32, player: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::EpochUser(epoch, player.clone()));
+}
+
+/// Write an epoch player entry without the seal check or roster
+/// registration `set_epoch_player` applies - used only by `migrate` to
+/// reshape already-existing historical data, which isn't a new mutation of
+/// epoch standings and must be allowed through even for a long-sealed epoch.
+pub(crate) fn set_epoch_player_migrated(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
+    let key = DataKey::EpochPlayer(epoch, player.clone());
+    env.storage().temporary().set(&key, data);
+    extend_epoch_player_ttl(env, epoch, player);
+}
 
-    // Data doesn't exist in either key or format
-    false
+/// Delete an epoch player's entry (and schema tag) once nothing will ever
+/// read it again - see `rewards::prune_claimed_players`. Does not touch
+/// `EpochRosterSeen`/the roster pages themselves; those must stay intact so
+/// a re-prune (or a partition walk covering the same range twice) still
+/// sees a consistent roster.
+pub(crate) fn remove_epoch_player(env: &Env, epoch: u32, player: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::EpochPlayer(epoch, player.clone()));
+    env.storage()
+        .temporary()
+        .remove(&DataKey::EpochPlayerSchema(epoch, player.clone()));
 }
 
 /// Check if player exists
@@ -268,38 +852,63 @@ pub(crate) fn has_player(env: &Env, player: &Address) -> bool {
 }
 
 /// Get epoch-specific player data
-pub(crate) fn get_epoch_player(env: &Env, epoch: u32, player: &Address) -> Option<EpochPlayer> {
-    let new_key = DataKey::EpochPlayer(epoch, player.clone());
-
-    // Try new key with new format (V1 - without locked_fp)
-    let result: Option<EpochPlayer> = env.storage().temporary().get(&new_key);
+///
+/// Lazily upgrades a legacy entry to the current schema first (see
+/// `migrate::upgrade_epoch_player`) before reading the canonical key.
+///
+/// # Errors
+/// * `CorruptStorage` - If the entry's schema tag doesn't match any layout
+///   `migrate::upgrade_epoch_player` knows how to read.
+pub(crate) fn get_epoch_player(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+) -> Result<Option<EpochPlayer>, Error> {
+    crate::migrate::upgrade_epoch_player(env, epoch, player)?;
+    let key = DataKey::EpochPlayer(epoch, player.clone());
+    let result = env.storage().temporary().get(&key);
     if result.is_some() {
         extend_epoch_player_ttl(env, epoch, player);
-        return result;
     }
+    Ok(result)
+}
 
-    // Try old key (DataKey::EpochUser) with V0 format (with locked_fp)
-    let old_key = DataKey::EpochUser(epoch, player.clone());
-    let v0_result: Option<EpochPlayerV0> = env.storage().temporary().get(&old_key);
-    if let Some(v0_data) = v0_result {
-        extend_epoch_player_ttl(env, epoch, player);
-        // Convert on the fly (drop locked_fp)
-        return Some(EpochPlayer {
-            epoch_faction: v0_data.epoch_faction,
-            epoch_balance_snapshot: v0_data.epoch_balance_snapshot,
-            available_fp: v0_data.available_fp,
-            total_fp_contributed: v0_data.total_fp_contributed,
-        });
+/// Set epoch-specific player data
+///
+/// # Errors
+/// * `EpochSealed` - If `epoch` has been sealed
+pub(crate) fn set_epoch_player(
+    env: &Env,
+    epoch: u32,
+    player: &Address,
+    data: &EpochPlayer,
+) -> Result<(), Error> {
+    if is_epoch_sealed(env, epoch) {
+        return Err(Error::EpochSealed);
     }
+    write_epoch_player(env, epoch, player, data);
+    Ok(())
+}
 
-    None
+/// Write a game session settlement's `EpochPlayer` update against its
+/// original epoch, bypassing the seal check `set_epoch_player` applies.
+///
+/// A session's wager is reserved out of `available_fp` *before* its epoch
+/// can seal (see `game::reserve_wager`), so crediting or refunding it once
+/// the dispute window elapses is finishing a transaction the epoch already
+/// committed to, not a new mutation of live standings - used by
+/// `game::credit_player`/`refund_wager` so a `Provisional` session whose
+/// dispute window outlives its own epoch doesn't get stuck forever with both
+/// players' wagers locked.
+pub(crate) fn set_epoch_player_settlement(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
+    write_epoch_player(env, epoch, player, data);
 }
 
-/// Set epoch-specific player data
-pub(crate) fn set_epoch_player(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
+fn write_epoch_player(env: &Env, epoch: u32, player: &Address, data: &EpochPlayer) {
     let key = DataKey::EpochPlayer(epoch, player.clone());
     env.storage().temporary().set(&key, data);
     extend_epoch_player_ttl(env, epoch, player);
+    add_to_roster(env, epoch, player);
 }
 
 /// Check if epoch player exists
@@ -320,10 +929,36 @@ pub(crate) fn get_epoch(env: &Env, epoch: u32) -> Option<EpochInfo> {
 }
 
 /// Set epoch metadata
-pub(crate) fn set_epoch(env: &Env, epoch: u32, data: &EpochInfo) {
+///
+/// # Errors
+/// * `EpochSealed` - If `epoch` has been sealed
+pub(crate) fn set_epoch(env: &Env, epoch: u32, data: &EpochInfo) -> Result<(), Error> {
+    if is_epoch_sealed(env, epoch) {
+        return Err(Error::EpochSealed);
+    }
     let key = DataKey::Epoch(epoch);
     env.storage().temporary().set(&key, data);
     extend_epoch_ttl(env, epoch);
+    Ok(())
+}
+
+/// Whether `epoch`'s accumulation phase has been permanently closed
+pub(crate) fn is_epoch_sealed(env: &Env, epoch: u32) -> bool {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EpochSealed(epoch))
+        .unwrap_or(false)
+}
+
+/// Seal `epoch`, permanently blocking further `set_epoch_player`/`set_epoch`/
+/// `add_faction_points` writes against it. Idempotent - sealing an
+/// already-sealed epoch is a no-op.
+pub(crate) fn seal_epoch(env: &Env, epoch: u32) {
+    let key = DataKey::EpochSealed(epoch);
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
 }
 
 /// Get game session
@@ -349,7 +984,13 @@ pub(crate) fn has_session(env: &Env, session_id: u32) -> bool {
 }
 
 /// Check if a game contract is whitelisted
+///
+/// Restore-checked: a long-dormant whitelist entry reads back as `false`
+/// here rather than trapping the transaction.
 pub(crate) fn is_game_whitelisted(env: &Env, game_id: &Address) -> bool {
+    if let PlayerAccess::Missing = restore_game(env, game_id) {
+        return false;
+    }
     env.storage()
         .persistent()
         .get(&DataKey::Game(game_id.clone()))
@@ -370,12 +1011,25 @@ pub(crate) fn remove_game_from_whitelist(env: &Env, game_id: &Address) {
         .remove(&DataKey::Game(game_id.clone()));
 }
 
+/// Window and bit position `epoch` maps to within a player's `ClaimBitmap`
+fn claim_bitmap_coords(epoch: u32) -> (u32, u32) {
+    (epoch / 128, epoch % 128)
+}
+
+/// Get a player's raw claim bitmap for the window covering `epoch`
+fn get_claim_bitmap(env: &Env, player: &Address, window: u32) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimBitmap(player.clone(), window))
+        .unwrap_or(0)
+}
+
 /// Check if player has claimed rewards for an epoch
 pub(crate) fn has_claimed(env: &Env, player: &Address, epoch: u32) -> bool {
-    let key = DataKey::Claimed(player.clone(), epoch);
-    let result: Option<bool> = env.storage().temporary().get(&key);
-    if let Some(true) = result {
-        extend_claimed_ttl(env, player, epoch);
+    let (window, bit) = claim_bitmap_coords(epoch);
+    let bitmap = get_claim_bitmap(env, player, window);
+    if bitmap & (1u128 << bit) != 0 {
+        extend_claim_bitmap_ttl(env, player, window);
         true
     } else {
         false
@@ -384,49 +1038,704 @@ pub(crate) fn has_claimed(env: &Env, player: &Address, epoch: u32) -> bool {
 
 /// Mark rewards as claimed for player and epoch
 pub(crate) fn set_claimed(env: &Env, player: &Address, epoch: u32) {
-    let key = DataKey::Claimed(player.clone(), epoch);
-    env.storage().temporary().set(&key, &true);
-    extend_claimed_ttl(env, player, epoch);
+    let (window, bit) = claim_bitmap_coords(epoch);
+    let bitmap = get_claim_bitmap(env, player, window) | (1u128 << bit);
+    let key = DataKey::ClaimBitmap(player.clone(), window);
+    env.storage().persistent().set(&key, &bitmap);
+    extend_claim_bitmap_ttl(env, player, window);
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) management ensures data doesn't expire unexpectedly
-// Based on Soroban best practices:
-// - Instance storage: Tied to contract lifetime (Admin, Config, CurrentEpoch, Paused)
-// - Persistent storage: Cross-epoch data (Player, Game whitelist) - extends to 30 days when accessed
-// - Temporary storage: Epoch-specific data (EpochPlayer, Epoch, Claimed, Session) - 30 days from last interaction
-//
-// Storage Type Summary:
-// - Instance: Config-type variables that persist for contract lifetime
-// - Persistent: Player data and game whitelist that must survive across epochs
-// - Temporary: Epoch-specific data that expires 30 days after last access
+/// Mark rewards as claimed for player across several epochs in one call -
+/// see `rewards::claim_epochs`. Writes each distinct `ClaimBitmap` window
+/// touched by `epochs` exactly once, however many of its 128 epochs are
+/// actually claimed this call, rather than once per epoch.
+pub(crate) fn set_claimed_many(env: &Env, player: &Address, epochs: &Vec<u32>) {
+    // Small batches are expected (bounded by
+    // `rewards::MAX_CLAIM_EPOCHS_PER_CALL`), so a linear scan for the
+    // window an epoch belongs to is cheaper than a map for the handful of
+    // distinct windows a batch will ever touch.
+    let mut windows: Vec<(u32, u128)> = Vec::new(env);
+
+    for epoch in epochs.iter() {
+        let (window, bit) = claim_bitmap_coords(epoch);
+        let mut updated = false;
+        for i in 0..windows.len() {
+            let (existing_window, bitmap) = windows.get_unchecked(i);
+            if existing_window == window {
+                windows.set(i, (existing_window, bitmap | (1u128 << bit)));
+                updated = true;
+                break;
+            }
+        }
+        if !updated {
+            let bitmap = get_claim_bitmap(env, player, window) | (1u128 << bit);
+            windows.push_back((window, bitmap));
+        }
+    }
 
-/// TTL thresholds and extensions (in ledgers, ~5 seconds per ledger)
-/// ~30 days = 518,400 ledgers
-/// ~7 days = 120,960 ledgers
-const TTL_THRESHOLD_LEDGERS: u32 = 120_960; // Extend if < 7 days remaining
-const TTL_EXTEND_TO_LEDGERS: u32 = 518_400; // Extend to 30 days
+    for (window, bitmap) in windows.iter() {
+        let key = DataKey::ClaimBitmap(player.clone(), window);
+        env.storage().persistent().set(&key, &bitmap);
+        extend_claim_bitmap_ttl(env, player, window);
+    }
+}
 
-/// Extend TTL for player data
-/// Should be called whenever player data is read/written
-pub(crate) fn extend_player_ttl(env: &Env, player: &Address) {
-    env.storage().persistent().extend_ttl(
-        &DataKey::Player(player.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
-    );
+/// One-time migration of a player's legacy per-epoch `Claimed` temporary
+/// flags into their persistent `ClaimBitmap`, for epochs that might still be
+/// live when this ships. Returns true if any legacy flag was folded in.
+///
+/// Only the temporary storage's own TTL protects legacy flags from vanishing
+/// outright (they're never deleted here, since a temporary `remove` on an
+/// already-expired key is a no-op anyway) - this just makes sure a claim
+/// that's still visible gets a permanent home before its TTL can lapse.
+#[allow(deprecated)]
+pub(crate) fn migrate_claimed_storage(env: &Env, player: &Address, epoch: u32) -> bool {
+    let legacy_key = DataKey::Claimed(player.clone(), epoch);
+    let legacy_claimed: Option<bool> = env.storage().temporary().get(&legacy_key);
+    if legacy_claimed != Some(true) {
+        return false;
+    }
+
+    if has_claimed(env, player, epoch) {
+        return false;
+    }
+
+    set_claimed(env, player, epoch);
+    env.storage().temporary().remove(&legacy_key);
+    true
 }
 
-/// Extend TTL for epoch player data (temporary storage)
-/// Should be called whenever epoch player data is read/written
-pub(crate) fn extend_epoch_player_ttl(env: &Env, epoch: u32, player: &Address) {
-    env.storage().temporary().extend_ttl(
-        &DataKey::EpochPlayer(epoch, player.clone()),
-        TTL_THRESHOLD_LEDGERS,
-        TTL_EXTEND_TO_LEDGERS,
-    );
+/// Get live per-faction FP standings for an epoch (defaults to all zero)
+pub(crate) fn get_faction_standings(env: &Env, epoch: u32) -> [u128; 3] {
+    let key = DataKey::FactionStandings(epoch);
+    let result = env.storage().temporary().get(&key);
+    if result.is_some() {
+        extend_faction_standings_ttl(env, epoch);
+    }
+    result.unwrap_or([0u128; 3])
+}
+
+/// Set per-faction FP standings for an epoch
+pub(crate) fn set_faction_standings(env: &Env, epoch: u32, standings: &[u128; 3]) {
+    let key = DataKey::FactionStandings(epoch);
+    env.storage().temporary().set(&key, standings);
+    extend_faction_standings_ttl(env, epoch);
+}
+
+/// Credit `amount` FP to `faction`'s live standings for `epoch`
+///
+/// # Errors
+/// * `EpochSealed` - If `epoch` has been sealed
+pub(crate) fn add_faction_points(
+    env: &Env,
+    epoch: u32,
+    faction: u32,
+    amount: u128,
+) -> Result<(), Error> {
+    if is_epoch_sealed(env, epoch) {
+        return Err(Error::EpochSealed);
+    }
+    let mut standings = get_faction_standings(env, epoch);
+    standings[faction as usize] = standings[faction as usize].saturating_add(amount);
+    set_faction_standings(env, epoch, &standings);
+    Ok(())
+}
+
+/// Bump a sealed epoch's frozen `reward_pool.points` denominator by
+/// `amount` - the counterpart `game::credit_player` reaches for when a
+/// late-dispute-window settlement lands on an already-sealed epoch's
+/// winning faction. Bypasses `set_epoch`'s seal check the same way
+/// `set_epoch_player_settlement` bypasses `set_epoch_player`'s, so the
+/// denominator every `rewards::claimable_share` divides by stays in
+/// lockstep with the matching bump to the credited player's own
+/// `total_fp_contributed` numerator, instead of diverging and letting the
+/// sum of claimed shares exceed `reward_pool.rewards`. A no-op if the
+/// epoch doesn't exist (it always should, by the time this is called).
+pub(crate) fn credit_sealed_epoch_reward_points(env: &Env, epoch: u32, amount: u128) {
+    let key = DataKey::Epoch(epoch);
+    let existing: Option<EpochInfo> = env.storage().temporary().get(&key);
+    if let Some(mut info) = existing {
+        info.reward_pool.points = info.reward_pool.points.saturating_add(amount);
+        env.storage().temporary().set(&key, &info);
+        extend_epoch_ttl(env, epoch);
+    }
+}
+
+/// Get the running total of rewards distributed so far for an epoch
+pub(crate) fn get_distributed_rewards(env: &Env, epoch: u32) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::DistributedRewards(epoch))
+        .unwrap_or(0)
+}
+
+/// Set the running total of rewards distributed so far for an epoch
+pub(crate) fn set_distributed_rewards(env: &Env, epoch: u32, total: i128) {
+    let key = DataKey::DistributedRewards(epoch);
+    env.storage().temporary().set(&key, &total);
+    env.storage().temporary().extend_ttl(
+        &key,
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Get the price a given oracle key submitted for an epoch, if any
+pub(crate) fn get_price_submission(env: &Env, epoch: u32, oracle_key: &BytesN<32>) -> Option<i128> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::PriceSubmission(epoch, oracle_key.clone()))
+}
+
+/// Record the price a given oracle key submitted for an epoch
+pub(crate) fn set_price_submission(env: &Env, epoch: u32, oracle_key: &BytesN<32>, price: i128) {
+    let key = DataKey::PriceSubmission(epoch, oracle_key.clone());
+    env.storage().temporary().set(&key, &price);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get the distinct oracle keys that have submitted a price for an epoch
+pub(crate) fn get_price_submitters(env: &Env, epoch: u32) -> Vec<BytesN<32>> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::PriceSubmitters(epoch))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the distinct oracle keys that have submitted a price for an epoch
+pub(crate) fn set_price_submitters(env: &Env, epoch: u32, submitters: &Vec<BytesN<32>>) {
+    let key = DataKey::PriceSubmitters(epoch);
+    env.storage().temporary().set(&key, submitters);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get an epoch's finalized median BLND/USDC reference price and the ledger
+/// timestamp it was set at, if set
+pub(crate) fn get_reference_price(env: &Env, epoch: u32) -> Option<(i128, u64)> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ReferencePrice(epoch))
+}
+
+/// Set an epoch's finalized median BLND/USDC reference price, stamped with
+/// the current ledger timestamp
+pub(crate) fn set_reference_price(env: &Env, epoch: u32, price: i128) {
+    let key = DataKey::ReferencePrice(epoch);
+    env.storage()
+        .temporary()
+        .set(&key, &(price, env.ledger().timestamp()));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get the live BLND/USDC cumulative-price accumulator, if any observation
+/// has ever been recorded
+pub(crate) fn get_price_accumulator(env: &Env) -> Option<PriceAccumulator> {
+    env.storage().instance().get(&DataKey::PriceAccumulator)
+}
+
+/// Set the live BLND/USDC cumulative-price accumulator
+pub(crate) fn set_price_accumulator(env: &Env, accumulator: &PriceAccumulator) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PriceAccumulator, accumulator);
+}
+
+/// Get the accumulator snapshot taken at the last TWAP deviation check, if any
+pub(crate) fn get_price_accumulator_snapshot(env: &Env) -> Option<PriceAccumulator> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PriceAccumulatorSnapshot)
+}
+
+/// Set the accumulator snapshot for the current TWAP deviation check
+pub(crate) fn set_price_accumulator_snapshot(env: &Env, snapshot: &PriceAccumulator) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PriceAccumulatorSnapshot, snapshot);
+}
+
+/// Number of distinct players who have joined an epoch's roster so far
+pub(crate) fn roster_len(env: &Env, epoch: u32) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EpochRosterMeta(epoch))
+        .unwrap_or(0)
+}
+
+/// One fixed-size page of an epoch's participant roster, in join order.
+/// `page * ROSTER_PAGE_SIZE .. roster_len` gives the valid page range; an
+/// out-of-range page returns an empty `Vec` rather than erroring, so callers
+/// can walk pages until they come back empty.
+pub(crate) fn get_roster_page(env: &Env, epoch: u32, page: u32) -> Vec<Address> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EpochRosterPage(epoch, page))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add `player` to an epoch's roster if they haven't already joined it.
+/// Dedupes via `EpochRosterSeen` so this is safe to call on every game the
+/// player plays, not just their first.
+pub(crate) fn add_to_roster(env: &Env, epoch: u32, player: &Address) {
+    let seen_key = DataKey::EpochRosterSeen(epoch, player.clone());
+    if env.storage().temporary().has(&seen_key) {
+        env.storage()
+            .temporary()
+            .extend_ttl(&seen_key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+        return;
+    }
+    env.storage().temporary().set(&seen_key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&seen_key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+
+    let len = roster_len(env, epoch);
+    let page_number = len / ROSTER_PAGE_SIZE;
+    let page_key = DataKey::EpochRosterPage(epoch, page_number);
+    let mut page = get_roster_page(env, epoch, page_number);
+    page.push_back(player.clone());
+    env.storage().temporary().set(&page_key, &page);
+    env.storage().temporary().extend_ttl(
+        &page_key,
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+
+    let meta_key = DataKey::EpochRosterMeta(epoch);
+    env.storage().temporary().set(&meta_key, &(len + 1));
+    env.storage().temporary().extend_ttl(
+        &meta_key,
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Add `amount` to the dust carry awaiting the next epoch's finalization
+pub(crate) fn add_pending_dust_carry(env: &Env, amount: i128) {
+    let current: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingDustCarry)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingDustCarry, &(current + amount));
+}
+
+/// Take and clear the dust carry, for folding into a newly finalizing epoch
+pub(crate) fn take_pending_dust_carry(env: &Env) -> i128 {
+    let amount: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingDustCarry)
+        .unwrap_or(0);
+    env.storage().instance().set(&DataKey::PendingDustCarry, &0i128);
+    amount
+}
+
+/// Get the admin-configured emission bonus schedule, if set
+pub(crate) fn get_emission_config(env: &Env) -> Option<EmissionConfig> {
+    env.storage().instance().get(&DataKey::EmissionConfig)
+}
+
+/// Set the admin-configured emission bonus schedule
+pub(crate) fn set_emission_config(env: &Env, emission_config: &EmissionConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EmissionConfig, emission_config);
+}
+
+/// Get the count of distinct players credited so far for an epoch
+pub(crate) fn get_claimant_count(env: &Env, epoch: u32) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ClaimantCount(epoch))
+        .unwrap_or(0)
+}
+
+/// Increment the count of distinct players credited for an epoch
+pub(crate) fn increment_claimant_count(env: &Env, epoch: u32) {
+    let key = DataKey::ClaimantCount(epoch);
+    let count = get_claimant_count(env, epoch) + 1;
+    env.storage().temporary().set(&key, &count);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get a player's queued unbonding withdrawals, oldest first (empty if none)
+pub(crate) fn get_unbonding_queue(env: &Env, player: &Address) -> Vec<UnbondingChunk> {
+    let key = DataKey::UnbondingQueue(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_unbonding_queue_ttl(env, player);
+    }
+    result.unwrap_or(Vec::new(env))
+}
+
+/// Set a player's queued unbonding withdrawals
+pub(crate) fn set_unbonding_queue(env: &Env, player: &Address, queue: &Vec<UnbondingChunk>) {
+    let key = DataKey::UnbondingQueue(player.clone());
+    env.storage().persistent().set(&key, queue);
+    extend_unbonding_queue_ttl(env, player);
+}
+
+/// Get the global accumulated yield per tracked deposit token (defaults to 0)
+pub(crate) fn get_acc_yield_per_token(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccYieldPerToken)
+        .unwrap_or(0)
+}
+
+/// Set the global accumulated yield per tracked deposit token
+pub(crate) fn set_acc_yield_per_token(env: &Env, acc: i128) {
+    env.storage().instance().set(&DataKey::AccYieldPerToken, &acc);
+}
+
+/// Get the sum of every player's tracked deposit balance (defaults to 0)
+pub(crate) fn get_total_tracked_deposits(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalTrackedDeposits)
+        .unwrap_or(0)
+}
+
+/// Set the sum of every player's tracked deposit balance
+pub(crate) fn set_total_tracked_deposits(env: &Env, total: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalTrackedDeposits, &total);
+}
+
+/// Get the number of distinct players who have ever selected a faction
+pub(crate) fn get_total_players(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::TotalPlayers).unwrap_or(0)
+}
+
+/// Set the number of distinct players who have ever selected a faction
+pub(crate) fn set_total_players(env: &Env, total: u32) {
+    env.storage().instance().set(&DataKey::TotalPlayers, &total);
+}
+
+/// Get a player's yield accumulator position (defaults to `YieldPosition::ZERO`)
+pub(crate) fn get_yield_position(env: &Env, player: &Address) -> YieldPosition {
+    let key = DataKey::YieldPosition(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_yield_position_ttl(env, player);
+    }
+    result.unwrap_or(YieldPosition::ZERO)
+}
+
+/// Set a player's yield accumulator position
+pub(crate) fn set_yield_position(env: &Env, player: &Address, position: &YieldPosition) {
+    let key = DataKey::YieldPosition(player.clone());
+    env.storage().persistent().set(&key, position);
+    extend_yield_position_ttl(env, player);
+}
+
+// ============================================================================
+// Leaderboard
+// ============================================================================
+
+/// Get an epoch's top-N leaderboard (defaults to empty, for epochs predating
+/// this feature or with no eligible contribution yet)
+pub(crate) fn get_epoch_leaderboard(env: &Env, epoch: u32) -> Vec<(Address, i128)> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::EpochLeaderboard(epoch))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set an epoch's top-N leaderboard
+pub(crate) fn set_epoch_leaderboard(env: &Env, epoch: u32, board: &Vec<(Address, i128)>) {
+    let key = DataKey::EpochLeaderboard(epoch);
+    env.storage().temporary().set(&key, board);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get the all-time cumulative leaderboard (defaults to empty)
+pub(crate) fn get_alltime_leaderboard(env: &Env) -> Vec<(Address, i128)> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllTimeLeaderboard)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the all-time cumulative leaderboard
+pub(crate) fn set_alltime_leaderboard(env: &Env, board: &Vec<(Address, i128)>) {
+    env.storage().instance().set(&DataKey::AllTimeLeaderboard, board);
+}
+
+/// Get a player's lifetime cumulative contribution (defaults to 0)
+pub(crate) fn get_alltime_score(env: &Env, player: &Address) -> i128 {
+    let key = DataKey::AllTimeScore(player.clone());
+    let result = env.storage().persistent().get(&key);
+    if result.is_some() {
+        extend_alltime_score_ttl(env, player);
+    }
+    result.unwrap_or(0)
+}
+
+/// Set a player's lifetime cumulative contribution
+pub(crate) fn set_alltime_score(env: &Env, player: &Address, score: i128) {
+    let key = DataKey::AllTimeScore(player.clone());
+    env.storage().persistent().set(&key, &score);
+    extend_alltime_score_ttl(env, player);
+}
+
+// ============================================================================
+// Game Dispute/Slash Bookkeeping
+// ============================================================================
+
+/// Get a game's currently-open (`Pending`/`Provisional`) session ids
+/// (defaults to empty)
+pub(crate) fn get_game_open_sessions(env: &Env, game_id: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GameOpenSessions(game_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Record `session_id` as open for `game_id` - see `game::start_game_multi`.
+pub(crate) fn add_game_open_session(env: &Env, game_id: &Address, session_id: u32) {
+    let key = DataKey::GameOpenSessions(game_id.clone());
+    let mut open = get_game_open_sessions(env, game_id);
+    open.push_back(session_id);
+    env.storage().persistent().set(&key, &open);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Remove `session_id` from `game_id`'s open set once it settles or is
+/// disputed - a linear scan, like `migrate_claimed_storage`'s window walk,
+/// since a game's open set is expected to stay small in steady state.
+pub(crate) fn remove_game_open_session(env: &Env, game_id: &Address, session_id: u32) {
+    let open = get_game_open_sessions(env, game_id);
+    let mut remaining = Vec::new(env);
+    for id in open.iter() {
+        if id != session_id {
+            remaining.push_back(id);
+        }
+    }
+    let key = DataKey::GameOpenSessions(game_id.clone());
+    env.storage().persistent().set(&key, &remaining);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get a game's disputed-session count (defaults to 0)
+pub(crate) fn get_game_dispute_count(env: &Env, game_id: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GameDisputeCount(game_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment and return a game's disputed-session count
+pub(crate) fn increment_game_dispute_count(env: &Env, game_id: &Address) -> u32 {
+    let key = DataKey::GameDisputeCount(game_id.clone());
+    let count = get_game_dispute_count(env, game_id) + 1;
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+    count
+}
+
+/// Get a game's settled-session count (defaults to 0)
+pub(crate) fn get_game_settled_count(env: &Env, game_id: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GameSettledCount(game_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment a game's settled-session count
+pub(crate) fn increment_game_settled_count(env: &Env, game_id: &Address) {
+    let key = DataKey::GameSettledCount(game_id.clone());
+    let count = get_game_settled_count(env, game_id) + 1;
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_TO_LEDGERS);
+}
+
+/// Get the yield parked while `TotalTrackedDeposits` was zero (defaults to 0)
+pub(crate) fn get_pending_yield_carry(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PendingYieldCarry)
+        .unwrap_or(0)
+}
+
+/// Set the yield parked while `TotalTrackedDeposits` was zero
+pub(crate) fn set_pending_yield_carry(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingYieldCarry, &amount);
+}
+
+/// Take (read and zero) the yield parked while `TotalTrackedDeposits` was zero
+pub(crate) fn take_pending_yield_carry(env: &Env) -> i128 {
+    let amount = get_pending_yield_carry(env);
+    if amount != 0 {
+        env.storage().instance().set(&DataKey::PendingYieldCarry, &0i128);
+    }
+    amount
+}
+
+/// Get the routing target registered for a deposit asset, if any
+pub(crate) fn get_asset_vault(env: &Env, asset: &Address) -> Option<AssetVaultEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssetVault(asset.clone()))
+}
+
+/// Register (or replace) the routing target for a deposit asset
+pub(crate) fn set_asset_vault(env: &Env, asset: &Address, entry: &AssetVaultEntry) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AssetVault(asset.clone()), entry);
+}
+
+/// Deregister a deposit asset's routing target
+pub(crate) fn remove_asset_vault(env: &Env, asset: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AssetVault(asset.clone()));
+}
+
+/// Get the running raw total deposited for an asset (defaults to 0)
+pub(crate) fn get_asset_total_deposited(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssetTotalDeposited(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the running raw total deposited for an asset
+pub(crate) fn set_asset_total_deposited(env: &Env, asset: &Address, total: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AssetTotalDeposited(asset.clone()), &total);
+}
+
+/// Get a player's current lockup commitment (defaults to `Lockup::NONE`)
+pub(crate) fn get_lockup(env: &Env, player: &Address) -> Lockup {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Lockup(player.clone()))
+        .unwrap_or(Lockup::NONE)
+}
+
+/// Set a player's current lockup commitment
+pub(crate) fn set_lockup(env: &Env, player: &Address, lockup: &Lockup) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lockup(player.clone()), lockup);
+}
+
+/// Get the reward Merkle root submitted for a finalized epoch, if any
+pub(crate) fn get_epoch_reward_root(env: &Env, epoch: u32) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EpochRewardRoot(epoch))
+}
+
+/// Set a finalized epoch's reward Merkle root. Callers must check
+/// `get_epoch_reward_root` returns `None` first - this overwrites
+/// unconditionally.
+pub(crate) fn set_epoch_reward_root(env: &Env, epoch: u32, root: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EpochRewardRoot(epoch), root);
+}
+
+/// Get a player's vesting schedule for an epoch's reward share, if any
+pub(crate) fn get_vesting_entry(env: &Env, epoch: u32, player: &Address) -> Option<VestingEntry> {
+    let key = DataKey::VestingEntry(epoch, player.clone());
+    let entry = env.storage().persistent().get(&key);
+    if entry.is_some() {
+        extend_vesting_entry_ttl(env, epoch, player);
+    }
+    entry
+}
+
+/// Set a player's vesting schedule for an epoch's reward share
+pub(crate) fn set_vesting_entry(env: &Env, epoch: u32, player: &Address, entry: &VestingEntry) {
+    let key = DataKey::VestingEntry(epoch, player.clone());
+    env.storage().persistent().set(&key, entry);
+    extend_vesting_entry_ttl(env, epoch, player);
+}
+
+/// Get a finalized epoch's archived summary, or `None` if `epoch` hasn't
+/// finalized yet.
+pub(crate) fn get_epoch_summary(env: &Env, epoch: u32) -> Option<EpochSummary> {
+    env.storage().persistent().get(&DataKey::EpochSummary(epoch))
+}
+
+/// Archive a finalized epoch's summary. Called exactly once, by
+/// `epoch::cycle_epoch_step`.
+pub(crate) fn set_epoch_summary(env: &Env, epoch: u32, summary: &EpochSummary) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EpochSummary(epoch), summary);
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// TTL (Time To Live) management ensures data doesn't expire unexpectedly
+// Based on Soroban best practices:
+// - Instance storage: Tied to contract lifetime (Admin, Config, CurrentEpoch, Paused)
+// - Persistent storage: Cross-epoch data (Player, Game whitelist, ClaimBitmap) - extends to 30 days when accessed
+// - Temporary storage: Epoch-specific data (EpochPlayer, Epoch, Session) - 30 days from last interaction
+//
+// Storage Type Summary:
+// - Instance: Config-type variables that persist for contract lifetime
+// - Persistent: Player data and game whitelist that must survive across epochs
+// - Temporary: Epoch-specific data that expires 30 days after last access
+
+/// TTL thresholds and extensions (in ledgers, ~5 seconds per ledger)
+/// ~30 days = 518,400 ledgers
+/// ~7 days = 120,960 ledgers
+const TTL_THRESHOLD_LEDGERS: u32 = 120_960; // Extend if < 7 days remaining
+const TTL_EXTEND_TO_LEDGERS: u32 = 518_400; // Extend to 30 days
+
+/// Max entries per `EpochRosterPage`, so `distribute_rewards_partition` can
+/// walk a whole epoch's roster in fixed-size chunks without an unbounded read.
+pub(crate) const ROSTER_PAGE_SIZE: u32 = 64;
+
+/// Extend TTL for player data
+/// Should be called whenever player data is read/written
+pub(crate) fn extend_player_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Player(player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for epoch player data (temporary storage)
+/// Should be called whenever epoch player data is read/written
+pub(crate) fn extend_epoch_player_ttl(env: &Env, epoch: u32, player: &Address) {
+    env.storage().temporary().extend_ttl(
+        &DataKey::EpochPlayer(epoch, player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
 }
 
 /// Extend TTL for epoch data (temporary storage)
@@ -439,11 +1748,61 @@ pub(crate) fn extend_epoch_ttl(env: &Env, epoch: u32) {
     );
 }
 
-/// Extend TTL for claimed rewards data (temporary storage)
-/// Should be called whenever claim data is written
-pub(crate) fn extend_claimed_ttl(env: &Env, player: &Address, epoch: u32) {
+/// Extend TTL for a player's claim bitmap (persistent storage)
+/// Should be called whenever a claim bitmap window is read/written
+pub(crate) fn extend_claim_bitmap_ttl(env: &Env, player: &Address, window: u32) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::ClaimBitmap(player.clone(), window),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's vesting entry (persistent storage)
+/// Should be called whenever a vesting entry is read/written
+pub(crate) fn extend_vesting_entry_ttl(env: &Env, epoch: u32, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::VestingEntry(epoch, player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's lifetime leaderboard score (persistent storage)
+/// Should be called whenever a player's all-time score is read/written
+pub(crate) fn extend_alltime_score_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::AllTimeScore(player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for live per-epoch faction standings (temporary storage)
+/// Should be called whenever faction standings are read/written
+pub(crate) fn extend_faction_standings_ttl(env: &Env, epoch: u32) {
     env.storage().temporary().extend_ttl(
-        &DataKey::Claimed(player.clone(), epoch),
+        &DataKey::FactionStandings(epoch),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's unbonding queue (persistent storage)
+/// Should be called whenever the queue is read/written
+pub(crate) fn extend_unbonding_queue_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::UnbondingQueue(player.clone()),
+        TTL_THRESHOLD_LEDGERS,
+        TTL_EXTEND_TO_LEDGERS,
+    );
+}
+
+/// Extend TTL for a player's yield accumulator position (persistent storage)
+/// Should be called whenever the position is read/written
+pub(crate) fn extend_yield_position_ttl(env: &Env, player: &Address) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::YieldPosition(player.clone()),
         TTL_THRESHOLD_LEDGERS,
         TTL_EXTEND_TO_LEDGERS,
     );
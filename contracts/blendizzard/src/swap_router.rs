@@ -0,0 +1,89 @@
+//! Pluggable DEX-router abstraction for epoch harvesting.
+//!
+//! `rewards::swap_withdrawn_yield` used to call `router::Client` (the
+//! Soroswap router) directly, so swapping in a different Stellar AMM meant
+//! editing `rewards.rs` itself. `SwapRouter` decouples that the same way
+//! `yield_source` decouples the vault side: harvesting only ever calls
+//! through the trait, and a new DEX (Aquarius, Phoenix, ...) just needs its
+//! own adapter here plus a new `SwapRouterKind` variant (see
+//! `types::SwapRouterKind`). Dispatched through `SwapRouterAdapter` (a plain
+//! enum match, not a heap-allocated trait object - see `yield_source`'s
+//! module doc for why).
+
+use soroban_sdk::{vec, Address, Env};
+
+use crate::router;
+use crate::types::SwapRouterKind;
+
+/// A DEX router capable of swapping one token for another with a minimum
+/// acceptable output.
+pub(crate) trait SwapRouter {
+    /// Swap exactly `amount_in` of `token_in` for at least `min_out` of
+    /// `token_out`, crediting this contract. Returns the amount of
+    /// `token_out` actually received. Reverts if `min_out` can't be met.
+    fn swap(
+        &self,
+        env: &Env,
+        token_in: &Address,
+        token_out: &Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128;
+}
+
+/// `SwapRouter` adapter over the Soroswap router.
+pub(crate) struct SoroswapRouter {
+    router: Address,
+}
+
+impl SwapRouter for SoroswapRouter {
+    fn swap(
+        &self,
+        env: &Env,
+        token_in: &Address,
+        token_out: &Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128 {
+        let client = router::Client::new(env, &self.router);
+        let path = vec![env, token_in.clone(), token_out.clone()];
+        let amounts = client.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &min_out,
+            &path,
+            &env.current_contract_address(),
+            &(env.ledger().timestamp() + 300),
+        );
+        amounts.get_unchecked(amounts.len() - 1)
+    }
+}
+
+/// Dispatches to whichever concrete `SwapRouter` adapter `Config.swap_router_kind`
+/// selects - see `make_swap_router`.
+pub(crate) enum SwapRouterAdapter {
+    Soroswap(SoroswapRouter),
+}
+
+impl SwapRouter for SwapRouterAdapter {
+    fn swap(
+        &self,
+        env: &Env,
+        token_in: &Address,
+        token_out: &Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128 {
+        match self {
+            SwapRouterAdapter::Soroswap(inner) => {
+                inner.swap(env, token_in, token_out, amount_in, min_out)
+            }
+        }
+    }
+}
+
+/// Construct the `SwapRouter` adapter `kind` selects, wired to `router`'s address.
+pub(crate) fn make_swap_router(kind: &SwapRouterKind, router: Address) -> SwapRouterAdapter {
+    match kind {
+        SwapRouterKind::Soroswap => SwapRouterAdapter::Soroswap(SoroswapRouter { router }),
+    }
+}
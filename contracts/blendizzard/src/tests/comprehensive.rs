@@ -20,7 +20,7 @@ fn test_complete_game_flow_player1_wins() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Step 1: Setup - whitelist game and select factions
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0); // WholeNoodle
     client.select_faction(&player2, &1); // PointyStick
 
@@ -61,7 +61,7 @@ fn test_complete_game_flow_player1_wins() {
 
     // Step 4: End game - player1 wins
     let proof = Bytes::new(&env);
-    let outcome = GameOutcome {
+    let outcome = GameOutcome::Winner {
         game_id: game_contract.clone(),
         session_id: session_id.clone(),
         player1: player1.clone(),
@@ -100,7 +100,7 @@ fn test_complete_game_flow_player2_wins() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0);
     client.select_faction(&player2, &1);
     client.deposit(&player1, &1000_0000000);
@@ -124,7 +124,7 @@ fn test_complete_game_flow_player2_wins() {
 
     // End game - player2 wins
     let proof = Bytes::new(&env);
-    let outcome = GameOutcome {
+    let outcome = GameOutcome::Winner {
         game_id: game_contract.clone(),
         session_id: session_id.clone(),
         player1: player1.clone(),
@@ -220,7 +220,7 @@ fn test_start_game_when_paused() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.deposit(&player1, &1000_0000000);
     client.deposit(&player2, &1000_0000000);
     client.select_faction(&player1, &0);
@@ -270,7 +270,7 @@ fn test_pause_does_not_affect_admin_functions() {
     client.pause();
 
     // Admin functions should still work
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     assert!(client.is_game(&game));
 
     client.remove_game(&game);
@@ -295,7 +295,7 @@ fn test_large_withdrawal_resets_fp() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&user, &0);
     client.select_faction(&opponent, &1);
 
@@ -332,7 +332,7 @@ fn test_large_withdrawal_resets_fp() {
 
     // End first game first
     let proof = Bytes::new(&env);
-    let outcome = GameOutcome {
+    let outcome = GameOutcome::Winner {
         game_id: game_contract.clone(),
         session_id: session_id.clone(),
         player1: user.clone(),
@@ -409,7 +409,7 @@ fn test_duplicate_session_id() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0);
     client.select_faction(&player2, &1);
     client.deposit(&player1, &1000_0000000);
@@ -535,7 +535,7 @@ fn test_can_change_faction_but_epoch_stays_locked() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0); // WholeNoodle
     client.select_faction(&player2, &1); // PointyStick
     client.deposit(&player1, &1000_0000000);
@@ -587,7 +587,7 @@ fn test_multiple_games_in_same_epoch() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0);
     client.select_faction(&player2, &1);
     client.deposit(&player1, &1000_0000000);
@@ -605,7 +605,7 @@ fn test_multiple_games_in_same_epoch() {
     );
 
     let proof = Bytes::new(&env);
-    let outcome1 = GameOutcome {
+    let outcome1 = GameOutcome::Winner {
         game_id: game_contract.clone(),
         session_id: session1.clone(),
         player1: player1.clone(),
@@ -625,7 +625,7 @@ fn test_multiple_games_in_same_epoch() {
         &10_0000000,
     );
 
-    let outcome2 = GameOutcome {
+    let outcome2 = GameOutcome::Winner {
         game_id: game_contract.clone(),
         session_id: session2.clone(),
         player1: player1.clone(),
@@ -652,7 +652,7 @@ fn test_insufficient_fp_for_wager() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup with small deposits
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     client.select_faction(&player1, &0);
     client.select_faction(&player2, &1);
     client.deposit(&player1, &10_0000000); // Only 10 USDC
@@ -707,7 +707,7 @@ fn test_multi_player_game_flow() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.select_faction(&winner1, &0); // WholeNoodle
     client.select_faction(&winner2, &0); // WholeNoodle
     client.select_faction(&loser, &1); // PointyStick
@@ -728,7 +728,7 @@ fn test_multi_player_game_flow() {
         &100_0000000,
     );
 
-    let outcome1 = GameOutcome {
+    let outcome1 = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session1.clone(),
         player1: winner1.clone(),
@@ -744,7 +744,7 @@ fn test_multi_player_game_flow() {
     let session2 = BytesN::from_array(&env, &[2u8; 32]);
     client.start_game(&game, &session2, &winner2, &loser, &50_0000000, &50_0000000);
 
-    let outcome2 = GameOutcome {
+    let outcome2 = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session2.clone(),
         player1: winner2.clone(),
@@ -794,7 +794,7 @@ fn test_fp_accumulation_from_varying_deposits() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // All players join same faction
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.select_faction(&player1, &0);
     client.select_faction(&player2, &0);
     client.select_faction(&player3, &0);
@@ -814,7 +814,7 @@ fn test_fp_accumulation_from_varying_deposits() {
         let session = BytesN::from_array(&env, &[(idx as u8 + 1); 32]);
         client.start_game(&game, &session, player, &opponent, &50_0000000, &50_0000000);
 
-        let outcome = GameOutcome {
+        let outcome = GameOutcome::Winner {
             game_id: game.clone(),
             session_id: session.clone(),
             player1: (*player).clone(),
@@ -864,7 +864,7 @@ fn test_losing_faction_has_no_claimable_rewards() {
 
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.select_faction(&winner, &0);
     client.select_faction(&loser, &1);
 
@@ -875,7 +875,7 @@ fn test_losing_faction_has_no_claimable_rewards() {
     let session = BytesN::from_array(&env, &[1u8; 32]);
     client.start_game(&game, &session, &winner, &loser, &100_0000000, &100_0000000);
 
-    let outcome = GameOutcome {
+    let outcome = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session.clone(),
         player1: winner.clone(),
@@ -918,7 +918,7 @@ fn test_game_outcome_and_fp_transfer() {
 
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.select_faction(&winner, &0);
     client.select_faction(&loser, &1);
 
@@ -932,7 +932,7 @@ fn test_game_outcome_and_fp_transfer() {
     let session = BytesN::from_array(&env, &[1u8; 32]);
     client.start_game(&game, &session, &winner, &loser, &100_0000000, &100_0000000);
 
-    let outcome = GameOutcome {
+    let outcome = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session.clone(),
         player1: winner.clone(),
@@ -987,7 +987,7 @@ fn test_epoch_structure_and_faction_switching() {
 
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.select_faction(&player, &0);
     client.select_faction(&opponent, &1);
 
@@ -1005,7 +1005,7 @@ fn test_epoch_structure_and_faction_switching() {
         &50_0000000,
     );
 
-    let outcome0 = GameOutcome {
+    let outcome0 = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session0.clone(),
         player1: player.clone(),
@@ -110,7 +110,7 @@ fn test_winning_faction_determined_by_standings() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup game and players
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.deposit(&p1, &1000_0000000);
     client.deposit(&p2, &1000_0000000);
     client.deposit(&p3, &500_0000000);
@@ -121,19 +121,19 @@ fn test_winning_faction_determined_by_standings() {
     client.select_faction(&p3, &0); // WholeNoodle
 
     // Play and complete games to generate faction points
-    use crate::types::GameOutcome;
+    use crate::types::{GameOutcome, MatchResult};
     use soroban_sdk::Bytes;
 
     let session1 = BytesN::from_array(&env, &[1u8; 32]);
     client.start_game(&game, &session1, &p1, &p2, &100_0000000, &100_0000000);
 
     // End game 1: Player 1 (faction 0) wins
-    let outcome1 = GameOutcome {
+    let outcome1 = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session1.clone(),
         player1: p1.clone(),
         player2: p2.clone(),
-        winner: true, // Player1 wins
+        result: MatchResult::Player1,
     };
     client.end_game(&game, &session1, &Bytes::new(&env), &outcome1);
 
@@ -141,12 +141,12 @@ fn test_winning_faction_determined_by_standings() {
     client.start_game(&game, &session2, &p3, &p2, &50_0000000, &50_0000000);
 
     // End game 2: Player 3 (faction 0) wins
-    let outcome2 = GameOutcome {
+    let outcome2 = GameOutcome::Winner {
         game_id: game.clone(),
         session_id: session2.clone(),
         player1: p3.clone(),
         player2: p2.clone(),
-        winner: true, // Player3 wins
+        result: MatchResult::Player1,
     };
     client.end_game(&game, &session2, &Bytes::new(&env), &outcome2);
 
@@ -183,38 +183,38 @@ fn test_winning_faction_determined_by_standings() {
 #[test]
 fn test_epoch_cycle_no_yield_scenario() {
     let env = setup_test_env();
-    let admin = Address::generate(&env);
-
-    // Create Blendizzard with mock vault
-    use super::testutils::create_blendizzard_with_mock_vault;
-    let client = create_blendizzard_with_mock_vault(&env, &admin);
+    use super::testutils::TestBuilder;
+    let state = TestBuilder::new(&env).build_with_mock_vault();
 
     // Add game and create activity
     let game = Address::generate(&env);
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
 
-    client.add_game(&game);
-    client.deposit(&p1, &500_0000000);
-    client.deposit(&p2, &500_0000000);
-    client.select_faction(&p1, &0);
-    client.select_faction(&p2, &1);
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 500_0000000).unwrap();
+    state.deposit_as(&p2, 500_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
 
     // Play a game
     let session = BytesN::from_array(&env, &[1u8; 32]);
-    client.start_game(&game, &session, &p1, &p2, &50_0000000, &50_0000000);
-
-    // Advance time
-    env.ledger().with_mut(|li| {
-        li.timestamp += 345_601;
-    });
-
-    // Try to cycle (will fail due to missing real soroswap, but tests logic)
-    let _result = client.try_cycle_epoch();
-
-    // Expected behavior: If no yield, epoch should still cycle with reward_pool = 0
-    // Current implementation may error on swap, which is acceptable
-    // This documents intended behavior for future implementation
+    state.client.start_game(&game, &session, &p1, &p2, &50_0000000, &50_0000000);
+
+    // A fresh reference price lets `withdraw_yield`/`swap_withdrawn_yield`
+    // run to completion instead of deferring for want of a fresh price -
+    // this plain `build_with_mock_vault` deployment's
+    // `MockVault::claim_emissions` always reports zero, so the swap is
+    // skipped deterministically and the reward pool stays at zero, rather
+    // than the assertion being vacuously true because the swap never ran at
+    // all.
+    state.set_reference_price_for_epoch(0, 1_0000000);
+
+    let next_epoch = state.advance_epoch();
+    assert_eq!(next_epoch, 1);
+
+    let ended_epoch = state.client.get_epoch(&Some(0));
+    assert_eq!(ended_epoch.reward_pool.rewards, 0);
 }
 
 // ============================================================================
@@ -283,7 +283,7 @@ fn test_full_epoch_cycle_with_soroswap() {
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
 
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.deposit(&p1, &1000_0000000);
     client.deposit(&p2, &1000_0000000);
     client.select_faction(&p1, &0);
@@ -322,36 +322,232 @@ fn test_full_epoch_cycle_with_soroswap() {
 #[test]
 fn test_reward_pool_set_after_cycle() {
     let env = setup_test_env();
-    let admin = Address::generate(&env);
-
-    use super::testutils::create_blendizzard_with_mock_vault;
-    let client = create_blendizzard_with_mock_vault(&env, &admin);
+    use super::testutils::TestBuilder;
+    // `with_emitting_vault` swaps in `MockEmittingVault`/`MockSwapRouter` so
+    // this test can inject a deterministic, nonzero yield claim instead of
+    // the always-zero `MockVault`/`MockRouter` pair, and assert a real
+    // reward-pool value rather than just that cycling didn't panic.
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
 
     // Create minimal activity
     let game = Address::generate(&env);
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
 
-    client.add_game(&game);
-    client.deposit(&p1, &100_0000000);
-    client.deposit(&p2, &100_0000000);
-    client.select_faction(&p1, &0);
-    client.select_faction(&p2, &1);
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
 
     // Initial epoch should have 0 reward pool
-    let epoch_0 = client.get_epoch(&None);
-    assert_eq!(epoch_0.reward_pool, 0);
+    let epoch_0 = state.client.get_epoch(&None);
+    assert_eq!(epoch_0.reward_pool.rewards, 0);
 
-    // After cycling (even if failed), reward pool logic should execute
-    env.ledger().with_mut(|li| {
-        li.timestamp += 345_601;
-    });
+    // Deterministic fake yield: the vault reports a fixed BLND claim, the
+    // router swaps it 1:1 for USDC, and the reference price lets the harvest
+    // run instead of deferring.
+    state.set_vault_emission(1000_0000000);
+    state.set_reference_price_for_epoch(0, 1_0000000);
 
-    // Try to cycle (may fail without real contracts)
-    let _result = client.try_cycle_epoch();
+    state.advance_epoch();
+
+    let ended_epoch = state.client.get_epoch(&Some(0));
+    assert_eq!(ended_epoch.reward_pool.rewards, 1000_0000000);
+}
+
+#[test]
+fn test_get_epoch_summary() {
+    let env = setup_test_env();
+    use super::testutils::TestBuilder;
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
+
+    // Not finalized yet.
+    let result = state.client.try_get_epoch_summary(&0);
+    assert!(result.is_err());
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    let session = BytesN::from_array(&env, &[1u8; 32]);
+    state.client.start_game(&game, &session, &p1, &p2, &50_0000000, &50_0000000);
+
+    use crate::types::{GameOutcome, MatchResult};
+    use soroban_sdk::Bytes;
+    let outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: session.clone(),
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state.client.end_game(&game, &session, &Bytes::new(&env), &outcome);
+
+    state.set_vault_emission(1000_0000000);
+    state.set_reference_price_for_epoch(0, 1_0000000);
+    state.advance_epoch();
+
+    let ended_epoch = state.client.get_epoch(&Some(0));
+    let summary = state.client.get_epoch_summary(&0);
 
-    // Once cycle_epoch is fully working with real contracts,
-    // reward_pool should be > 0 if there was yield
+    assert_eq!(summary.epoch_number, 0);
+    assert_eq!(summary.winning_faction, ended_epoch.winning_faction);
+    assert_eq!(summary.reward_pool, ended_epoch.reward_pool.rewards);
+    assert_eq!(summary.faction_standings, ended_epoch.faction_standings);
+    assert_eq!(summary.participant_count, 2);
+
+    // The epoch-1 summary doesn't exist yet, unlike the finalized epoch 0's.
+    let result = state.client.try_get_epoch_summary(&1);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Sybil-Resistant Standings
+// ============================================================================
+
+/// Below `config.epoch_quorum` distinct roster participants, `cycle_epoch`
+/// must not declare a winner, and the reward pool it would have funded rolls
+/// forward (via the same dust-carry mechanism as rounding remainders)
+/// instead of being stranded in an epoch nobody can claim against.
+#[test]
+fn test_epoch_quorum_blocks_winner_and_rolls_reward_forward() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
+
+    // Require 3 distinct participants; only 2 will actually join.
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &None, &None, &None, &Some(3u32), &None, &None, &None, &None,
+    );
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    let session = BytesN::from_array(&env, &[1u8; 32]);
+    state.client.start_game(&game, &session, &p1, &p2, &50_0000000, &50_0000000);
+
+    use crate::types::{GameOutcome, MatchResult};
+    use soroban_sdk::Bytes;
+    let outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: session.clone(),
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state.client.end_game(&game, &session, &Bytes::new(&env), &outcome);
+
+    state.set_vault_emission(1000_0000000);
+    state.set_reference_price_for_epoch(0, 1_0000000);
+    state.advance_epoch();
+
+    let ended_epoch_0 = state.client.get_epoch(&Some(0));
+    assert_eq!(ended_epoch_0.winning_faction, None);
+    assert_eq!(
+        ended_epoch_0.reward_pool.rewards, 0,
+        "quorum miss should roll the whole pool forward rather than fund an unclaimable epoch"
+    );
+
+    // Drop the quorum so the next cycle can finalize freely, and confirm the
+    // rolled-forward amount actually lands in epoch 1.
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &None, &None, &None, &Some(0u32), &None, &None, &None, &None,
+    );
+    state.advance_epoch();
+
+    let ended_epoch_1 = state.client.get_epoch(&Some(1));
+    assert_eq!(ended_epoch_1.reward_pool.rewards, 1000_0000000);
+}
+
+/// Below `config.min_standings_balance`, a player's faction selection earns
+/// FP for themselves but contributes nothing to the shared
+/// `faction_standings` a winner is picked from - the gate that stops cheap
+/// cloned accounts from voting a faction to victory.
+#[test]
+fn test_min_standings_balance_excludes_underfunded_players() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &None, &None, &Some(100_0000000i128), &None, &None, &None, &None, &None,
+    );
+
+    let game = Address::generate(&env);
+    let whale = Address::generate(&env);
+    let clone1 = Address::generate(&env);
+    let clone2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+
+    // Two cheap clones, both below `min_standings_balance`, pile into faction 0.
+    state.deposit_as(&clone1, 2_0000000).unwrap();
+    state.deposit_as(&clone2, 2_0000000).unwrap();
+    state.select_faction_as(&clone1, 0);
+    state.select_faction_as(&clone2, 0);
+
+    let session1 = BytesN::from_array(&env, &[1u8; 32]);
+    state.client.start_game(&game, &session1, &clone1, &clone2, &1_0000000, &1_0000000);
+    use crate::types::{GameOutcome, MatchResult};
+    use soroban_sdk::Bytes;
+    let outcome1 = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: session1.clone(),
+        player1: clone1.clone(),
+        player2: clone2.clone(),
+        result: MatchResult::Player1,
+    };
+    state.client.end_game(&game, &session1, &Bytes::new(&env), &outcome1);
+
+    let epoch_after_clones = state.client.get_epoch(&None);
+    assert_eq!(
+        epoch_after_clones.faction_standings.get(0).unwrap_or(0),
+        0,
+        "underfunded clones shouldn't move faction_standings at all"
+    );
+
+    // A real whale, above the threshold, backing faction 1.
+    let whale_op = Address::generate(&env);
+    state.deposit_as(&whale, 200_0000000).unwrap();
+    state.deposit_as(&whale_op, 200_0000000).unwrap();
+    state.select_faction_as(&whale, 1);
+    state.select_faction_as(&whale_op, 0);
+
+    let session2 = BytesN::from_array(&env, &[2u8; 32]);
+    state.client.start_game(&game, &session2, &whale, &whale_op, &10_0000000, &10_0000000);
+    let outcome2 = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: session2.clone(),
+        player1: whale.clone(),
+        player2: whale_op.clone(),
+        result: MatchResult::Player1,
+    };
+    state.client.end_game(&game, &session2, &Bytes::new(&env), &outcome2);
+
+    let epoch_after_whale = state.client.get_epoch(&None);
+    assert!(
+        epoch_after_whale.faction_standings.get(1).unwrap_or(0) > 0,
+        "a funded player's win should still move faction_standings"
+    );
 }
 
 // ============================================================================
@@ -473,7 +669,7 @@ fn test_faction_standings_persist_across_queries() {
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
 
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.deposit(&p1, &1000_0000000);
     client.deposit(&p2, &1000_0000000);
     client.select_faction(&p1, &0);
@@ -489,3 +685,699 @@ fn test_faction_standings_persist_across_queries() {
     // Should be consistent
     assert_eq!(standings1, standings2);
 }
+
+/// A deposit timed just before `cycle_epoch` (past `late_deposit_cutoff_bps`)
+/// must not be able to immediately wager its weight into the faction
+/// standings, so it can't dilute whatever the rest of the faction already
+/// earned honestly earlier in the epoch.
+#[test]
+fn test_late_deposit_past_cutoff_cannot_wager_fresh_fp() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env)
+        .epoch_duration(1000)
+        .late_deposit_cutoff_bps(9000) // cutoff at t=900
+        .build_with_mock_vault();
+
+    let game = Address::generate(&env);
+    let early = Address::generate(&env);
+    let opponent = Address::generate(&env);
+    let whale = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.select_faction_as(&early, 0);
+    state.select_faction_as(&opponent, 1);
+    state.select_faction_as(&whale, 0);
+
+    // Early deposits happen well before the cutoff.
+    state.deposit_as(&early, 1000_0000000).unwrap();
+    state.deposit_as(&opponent, 1000_0000000).unwrap();
+
+    state
+        .client
+        .start_game(&game, &1u32, &early, &opponent, &100_0000000, &100_0000000);
+    let outcome = crate::types::GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: early.clone(),
+        player2: opponent.clone(),
+        result: crate::types::MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome);
+
+    let early_fp_before_whale = state.client.get_epoch_player(&early).total_fp_contributed;
+    assert!(early_fp_before_whale > 0, "early player should have won FP");
+
+    // Whale deposits a huge amount after the cutoff, hoping to buy into the
+    // faction's standings before the epoch closes.
+    state.set_timestamp(950);
+    state.deposit_as(&whale, 1_000_000_0000000).unwrap();
+
+    // Its fresh balance earns zero weight past the cutoff, so it has no FP
+    // to wager at all.
+    let result = state
+        .client
+        .try_start_game(&game, &2u32, &whale, &opponent, &1, &100_0000000);
+    assert!(
+        result.is_err(),
+        "whale's post-cutoff deposit should carry no wagerable FP"
+    );
+
+    // The early winner's already-committed contribution is untouched.
+    assert_eq!(
+        state.client.get_epoch_player(&early).total_fp_contributed,
+        early_fp_before_whale
+    );
+}
+
+/// `claim_reward` should accept a valid Merkle proof against a submitted
+/// root, reject a forged one, and refuse to pay out the same leaf twice.
+#[test]
+fn test_merkle_reward_claim_valid_and_forged_proof() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+
+    // A deterministic emission bonus is the simplest way to guarantee a
+    // nonzero reward pool without depending on the mock router/vault's yield.
+    state.client.set_emission_config(&1_000_000_0000000, &0);
+    state.advance_epoch();
+    // Epoch 0 is now finalized and frozen; epoch 1 is the live one.
+
+    let epoch_0 = state.client.get_epoch(&Some(0));
+    assert!(epoch_0.finalized);
+    let reward_pool = epoch_0.reward_pool.rewards;
+    assert!(reward_pool > 0, "emission bonus should have funded the pool");
+
+    let player_a = Address::generate(&env);
+    let player_b = Address::generate(&env);
+    let amount_a = reward_pool / 2;
+    let amount_b = reward_pool - amount_a;
+
+    let leaves = vec![
+        &env,
+        crate::merkle::leaf_hash(&env, &player_a, amount_a),
+        crate::merkle::leaf_hash(&env, &player_b, amount_b),
+    ];
+    let (root, proofs) = crate::merkle::build_tree(&env, &leaves);
+
+    state.client.set_epoch_reward_root(&0u32, &root);
+    assert_eq!(state.client.get_epoch_reward_root(&0u32), Some(root));
+
+    state.mint_usdc(&state.client.address, reward_pool);
+
+    // A forged amount fails proof verification.
+    let forged = state
+        .client
+        .try_claim_reward(&player_a, &0u32, &(amount_a + 1), &proofs.get(0).unwrap());
+    assert!(forged.is_err(), "forged claim amount should be rejected");
+
+    // A valid claim succeeds and pays out the leaf's exact amount.
+    let claimed = state
+        .client
+        .claim_reward(&player_a, &0u32, &amount_a, &proofs.get(0).unwrap());
+    assert_eq!(claimed, amount_a);
+
+    let usdc_client = soroban_sdk::token::Client::new(&env, &state.usdc);
+    assert_eq!(usdc_client.balance(&player_a), amount_a);
+
+    // A second claim against the same leaf is rejected.
+    let double_claim =
+        state
+            .client
+            .try_claim_reward(&player_a, &0u32, &amount_a, &proofs.get(0).unwrap());
+    assert!(double_claim.is_err(), "double claim should be rejected");
+
+    // The other leaf still claims fine against the same root.
+    let claimed_b = state
+        .client
+        .claim_reward(&player_b, &0u32, &amount_b, &proofs.get(1).unwrap());
+    assert_eq!(claimed_b, amount_b);
+}
+
+/// With no fresh reference price, `cycle_epoch` must not swap blind - the
+/// harvest is deferred and the unclaimed BLND rolls forward to whichever
+/// later epoch actually has a usable price.
+#[test]
+fn test_stale_reference_price_defers_swap_and_rolls_yield_forward() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
+
+    // 500 BLND is sitting in the vault, ready to harvest, every epoch.
+    state.set_vault_emission(500_0000000);
+
+    // Epoch 0 has no submitted reference price at all.
+    state.advance_epoch();
+
+    let epoch_0 = state.client.get_epoch(&Some(0));
+    assert!(epoch_0.finalized);
+    assert_eq!(
+        epoch_0.reward_pool.rewards, 0,
+        "swap should be deferred with no fresh reference price, not attempted blind"
+    );
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &state.usdc).balance(&state.client.address),
+        0,
+        "a deferred harvest should not have pulled any BLND through the router"
+    );
+
+    // Epoch 1 has a fresh price, so the same (still-unclaimed) BLND is
+    // harvested and swapped this time.
+    state.set_reference_price_for_epoch(1, 1_0000000); // 1 USDC per BLND
+    state.advance_epoch();
+
+    let epoch_1 = state.client.get_epoch(&Some(1));
+    assert!(epoch_1.finalized);
+    assert_eq!(
+        epoch_1.reward_pool.rewards, 500_0000000,
+        "a fresh price should let the carried-forward BLND finally swap to USDC"
+    );
+}
+
+/// A swap whose realized output falls outside the oracle-bounded slippage
+/// tolerance must revert the whole `cycle_epoch` call rather than accept a
+/// worse-than-allowed rate.
+#[test]
+fn test_slippage_below_bound_reverts_cycle_epoch() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
+
+    state.set_vault_emission(500_0000000);
+    state.set_reference_price_for_epoch(0, 1_0000000); // reference: 1 USDC per BLND
+    // Default slippage_bps is 100 (1%), so a swap paying out only 90% of
+    // input is far outside tolerance.
+    state.set_router_output_bps(9_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += state.client.get_config().epoch_duration;
+    });
+    let result = state.client.try_cycle_epoch(&state.admin);
+    assert!(
+        result.is_err(),
+        "a swap landing outside the oracle-bounded slippage tolerance should revert cycle_epoch"
+    );
+}
+
+/// `cycle_epoch` is permissionless, and pays whoever calls it a configurable
+/// share of the swapped reward pool - a keeper bounty, so epoch finalization
+/// stays timely even if the admin never calls it themselves.
+#[test]
+fn test_non_admin_cycles_ripe_epoch_and_earns_bounty() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).with_emitting_vault().build_with_mock_vault();
+
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &Some(1_000u32), &None, &None, &None, &None, &None, &None, &None, &None, &None, // 10% cycle bounty
+    );
+
+    state.set_vault_emission(500_0000000);
+    state.set_reference_price_for_epoch(0, 1_0000000);
+
+    let keeper = Address::generate(&env);
+    env.ledger().with_mut(|li| {
+        li.timestamp += state.client.get_config().epoch_duration;
+    });
+    state.client.cycle_epoch(&keeper);
+
+    let usdc_client = soroban_sdk::token::Client::new(&env, &state.usdc);
+    assert_eq!(
+        usdc_client.balance(&keeper),
+        50_0000000,
+        "non-admin keeper should be paid 10% of the swapped reward pool"
+    );
+
+    let epoch_0 = state.client.get_epoch(&Some(0));
+    assert_eq!(
+        epoch_0.reward_pool.rewards,
+        450_0000000,
+        "the bounty should come out of the reward pool, not be minted extra"
+    );
+}
+
+/// `min_cycle_interval` is a floor under `epoch_duration` - even once an
+/// epoch's own duration has elapsed, `cycle_epoch` must wait for the floor
+/// too if it's the larger of the two.
+#[test]
+fn test_min_cycle_interval_blocks_early_cycle() {
+    use super::testutils::TestBuilder;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+
+    let epoch_duration = state.client.get_config().epoch_duration;
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &Some(epoch_duration * 2), &None, &None, &None, &None, &None, &None, &None,
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += epoch_duration;
+    });
+    let too_early = state.client.try_cycle_epoch(&state.admin);
+    assert!(
+        too_early.is_err(),
+        "min_cycle_interval should still block cycling once epoch_duration alone has elapsed"
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += epoch_duration;
+    });
+    state.client.cycle_epoch(&state.admin);
+}
+
+/// `min_deposit` is enforced by `vault::apply_tracked_deposit`, but there is
+/// no live `deposit` entrypoint on this contract to call `try_deposit`
+/// against (see the `ARCHITECTURE CHANGE` note in `lib.rs` - players deposit
+/// straight into fee-vault-v2 instead). This drives the bound through the
+/// same white-box `deposit_as` helper `test_late_deposit_past_cutoff_cannot_wager_fresh_fp`
+/// and others already use to exercise the dormant tracked-deposit hook.
+#[test]
+fn test_deposit_below_minimum_rejected() {
+    use super::testutils::TestBuilder;
+    use crate::errors::Error;
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None,
+        &Some(100_0000000i128), &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &None, &None, &None,
+    );
+
+    let player = Address::generate(&env);
+    let result = state.deposit_as(&player, 99_0000000);
+    assert_eq!(result, Err(Error::DepositBelowMinimum));
+
+    let ok = state.deposit_as(&player, 100_0000000);
+    assert!(ok.is_ok());
+}
+
+/// `end_game` should independently recompute `NumberGuessContract`'s
+/// commit-reveal outcome from a non-empty proof rather than trusting the
+/// game's reported `winner` blindly - a forged proof claiming the wrong
+/// winner is rejected even though the outcome struct itself is well-formed.
+#[test]
+fn test_end_game_rejects_forged_number_guess_proof() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+    use soroban_sdk::{Bytes, BytesN};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    let salt1 = BytesN::from_array(&env, &[1u8; 32]);
+    let salt2 = BytesN::from_array(&env, &[2u8; 32]);
+    let mut combined = Bytes::from_array(&env, &salt1.to_array());
+    combined.append(&Bytes::from_array(&env, &salt2.to_array()));
+    let digest: BytesN<32> = env.crypto().sha256(&combined).into();
+    let digest_u64 = u64::from_be_bytes(digest.to_array()[0..8].try_into().unwrap());
+    let winning_number = (digest_u64 % 10) as u8 + 1;
+    // player1 guesses the number exactly, player2 guesses as far away as
+    // possible - player1 genuinely wins this derived outcome.
+    let guess1 = winning_number;
+    let guess2 = if winning_number <= 5 { 10 } else { 1 };
+
+    let mut valid_proof = Bytes::from_array(&env, &[winning_number, guess1, guess2]);
+    valid_proof.append(&Bytes::from_array(&env, &salt1.to_array()));
+    valid_proof.append(&Bytes::from_array(&env, &salt2.to_array()));
+
+    state
+        .client
+        .start_game(&game, &1u32, &p1, &p2, &100_0000000, &100_0000000);
+
+    // Forged outcome claims player2 won despite the proof showing player1
+    // was closer - the recomputed winner disagrees, so this is rejected.
+    let forged_outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player2,
+    };
+    let result = state
+        .client
+        .try_end_game(&game, &1u32, &valid_proof, &forged_outcome);
+    assert!(result.is_err(), "forged winner should be rejected");
+
+    // The honestly-reported outcome for the same proof succeeds.
+    let honest_outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &valid_proof, &honest_outcome);
+}
+
+// ============================================================================
+// Dispute Window / Slashing
+// ============================================================================
+
+/// Set `Config.dispute_window_secs`/`game_auto_slash_threshold` - the two
+/// fields after `leaderboard_size` in `update_config`'s argument list.
+fn set_dispute_config(state: &super::testutils::TestState, dispute_window_secs: u64, auto_slash_threshold: u32) {
+    state.client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &None, &None, &None, &None, &None, &None, &None, &Some(dispute_window_secs),
+        &Some(auto_slash_threshold),
+    );
+}
+
+/// With a nonzero dispute window, `end_game` must leave the session
+/// `Provisional` rather than `Settled` - neither player's FP moves until
+/// `finalize_game`/`dispute_game` resolves it.
+#[test]
+fn test_end_game_with_dispute_window_stays_provisional() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+    set_dispute_config(&state, 50, 0);
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    state.client.start_game(&game, &1u32, &p1, &p2, &50_0000000, &50_0000000);
+    let outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome);
+
+    // Nothing credited yet - the session is still Provisional.
+    state.assert_fp(&p1, 0);
+
+    // Finalizing too early is rejected.
+    let result = state.client.try_finalize_game(&1u32);
+    assert!(result.is_err(), "dispute window hasn't elapsed yet");
+
+    state.set_timestamp(env.ledger().timestamp() + 50);
+    state.client.finalize_game(&1u32);
+    state.assert_fp(&p1, 50_0000000);
+}
+
+/// `dispute_game` reverts a still-`Provisional` session as a no-fault Draw,
+/// refunding both wagers rather than applying the reported outcome.
+#[test]
+fn test_dispute_game_refunds_wagers_instead_of_applying_outcome() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+    set_dispute_config(&state, 50, 0);
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    state.client.start_game(&game, &1u32, &p1, &p2, &50_0000000, &50_0000000);
+    let outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome);
+
+    state.client.dispute_game(&game, &1u32);
+
+    // Neither player gained FP, and both wagers are refunded to available_fp.
+    state.assert_fp(&p1, 0);
+    assert_eq!(state.client.get_epoch_player(&p1).available_fp, 100_0000000);
+    assert_eq!(state.client.get_epoch_player(&p2).available_fp, 100_0000000);
+
+    // A disputed session can no longer be finalized.
+    let result = state.client.try_finalize_game(&1u32);
+    assert!(result.is_err());
+}
+
+/// Disputing `game_auto_slash_threshold` sessions in a row must automatically
+/// slash the game (delisting it and reverting every other still-`Provisional`
+/// session it has open).
+#[test]
+fn test_dispute_game_auto_slashes_at_threshold() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).build_with_mock_vault();
+    set_dispute_config(&state, 50, 1);
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let p3 = Address::generate(&env);
+    let p4 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    for p in [&p1, &p2, &p3, &p4] {
+        state.deposit_as(p, 100_0000000).unwrap();
+    }
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+    state.select_faction_as(&p3, 0);
+    state.select_faction_as(&p4, 1);
+
+    state.client.start_game(&game, &1u32, &p1, &p2, &50_0000000, &50_0000000);
+    state.client.start_game(&game, &2u32, &p3, &p4, &50_0000000, &50_0000000);
+
+    let outcome1 = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome1);
+    let outcome2 = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 2u32,
+        player1: p3.clone(),
+        player2: p4.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &2u32, &soroban_sdk::Bytes::new(&env), &outcome2);
+
+    assert!(state.client.is_game(&game));
+
+    // threshold is 1, so disputing session 1 slashes the game outright,
+    // reverting session 2 along with it even though nobody disputed it.
+    state.client.dispute_game(&game, &1u32);
+
+    assert!(!state.client.is_game(&game));
+    let result = state.client.try_finalize_game(&2u32);
+    assert!(result.is_err(), "slash_game should have reverted session 2 too");
+    assert_eq!(state.client.get_epoch_player(&p3).available_fp, 100_0000000);
+    assert_eq!(state.client.get_epoch_player(&p4).available_fp, 100_0000000);
+}
+
+/// Regression test: a `Provisional` session whose `dispute_window_secs`
+/// outlives its own epoch (because `epoch::rotate_to_next_epoch` sealed it in
+/// the meantime) must still be settleable by `finalize_game` instead of
+/// permanently stranding both players' wagers behind `Error::EpochSealed`.
+#[test]
+fn test_finalize_game_settles_after_its_epoch_has_sealed() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env).epoch_duration(100).build_with_mock_vault();
+    // Longer than the epoch itself, so finalize_after_ts lands after rotation.
+    set_dispute_config(&state, 150, 0);
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    state.deposit_as(&p1, 100_0000000).unwrap();
+    state.deposit_as(&p2, 100_0000000).unwrap();
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+
+    state.client.start_game(&game, &1u32, &p1, &p2, &50_0000000, &50_0000000);
+    let session_epoch = state.client.get_epoch(&None).epoch_id;
+
+    let outcome = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome);
+
+    // Roll the epoch forward - this seals `session_epoch` well before the
+    // session's own dispute window elapses.
+    state.advance_epoch();
+    assert_eq!(state.client.get_epoch(&None).epoch_id, session_epoch + 1);
+
+    state.set_timestamp(env.ledger().timestamp() + 150);
+    state.client.finalize_game(&1u32);
+
+    let contract_id = state.client.address.clone();
+    let credited: crate::types::EpochPlayer = env
+        .as_contract(&contract_id, || crate::storage::get_epoch_player(&env, session_epoch, &p1))
+        .unwrap()
+        .unwrap();
+    assert_eq!(credited.total_fp_contributed, 50_0000000);
+}
+
+/// Regression test: a late `finalize_game` settlement against an
+/// already-sealed epoch must bump that epoch's frozen `reward_pool.points`
+/// denominator in lockstep with the credited player's `total_fp_contributed`
+/// numerator - not just land the credit. Otherwise the sum of `total_fp_contributed`
+/// across the winning faction's players can exceed the frozen `points` the
+/// reward pool was divided by, which trips `claim_epoch_reward`'s overspend
+/// panic for some other, uninvolved claimant.
+#[test]
+fn test_finalize_game_after_seal_keeps_reward_pool_points_in_sync() {
+    use super::testutils::TestBuilder;
+    use crate::types::{GameOutcome, MatchResult};
+
+    let env = setup_test_env();
+    let state = TestBuilder::new(&env)
+        .epoch_duration(100)
+        .build_with_mock_vault();
+
+    let game = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let p3 = Address::generate(&env);
+    let p4 = Address::generate(&env);
+
+    super::testutils::add_game_via_admin(&state.client, &state.admin, &game);
+    for p in [&p1, &p2, &p3, &p4] {
+        state.deposit_as(p, 100_0000000).unwrap();
+    }
+    // p1 and p3 both back faction 0, the eventual winner; p2 and p4 back
+    // faction 1.
+    state.select_faction_as(&p1, 0);
+    state.select_faction_as(&p2, 1);
+    state.select_faction_as(&p3, 0);
+    state.select_faction_as(&p4, 1);
+
+    // Game A settles immediately (no dispute window), crediting faction 0's
+    // standings before the epoch seals - this is what fixes `winning_faction`
+    // and `reward_pool.points` at seal time.
+    set_dispute_config(&state, 0, 0);
+    state
+        .client
+        .start_game(&game, &1u32, &p1, &p2, &40_0000000, &40_0000000);
+    let session_epoch = state.client.get_epoch(&None).epoch_id;
+    let outcome_a = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 1u32,
+        player1: p1.clone(),
+        player2: p2.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &1u32, &soroban_sdk::Bytes::new(&env), &outcome_a);
+
+    // Game B's dispute window outlives the epoch, so it's still Provisional
+    // when the epoch seals.
+    set_dispute_config(&state, 150, 0);
+    state
+        .client
+        .start_game(&game, &2u32, &p3, &p4, &25_0000000, &25_0000000);
+    let outcome_b = GameOutcome::Winner {
+        game_id: game.clone(),
+        session_id: 2u32,
+        player1: p3.clone(),
+        player2: p4.clone(),
+        result: MatchResult::Player1,
+    };
+    state
+        .client
+        .end_game(&game, &2u32, &soroban_sdk::Bytes::new(&env), &outcome_b);
+
+    state.advance_epoch();
+    assert_eq!(state.client.get_epoch(&None).epoch_id, session_epoch + 1);
+
+    let epoch_before = state.client.get_epoch(&Some(session_epoch));
+    assert_eq!(epoch_before.winning_faction, Some(0));
+    assert_eq!(epoch_before.reward_pool.points, 40_0000000);
+
+    // Settle game B's Provisional session against the now-sealed epoch.
+    state.set_timestamp(env.ledger().timestamp() + 150);
+    state.client.finalize_game(&2u32);
+
+    let contract_id = state.client.address.clone();
+    let (p1_contributed, p3_contributed) = env.as_contract(&contract_id, || {
+        (
+            crate::storage::get_epoch_player(&env, session_epoch, &p1)
+                .unwrap()
+                .unwrap()
+                .total_fp_contributed,
+            crate::storage::get_epoch_player(&env, session_epoch, &p3)
+                .unwrap()
+                .unwrap()
+                .total_fp_contributed,
+        )
+    });
+
+    let epoch_after = state.client.get_epoch(&Some(session_epoch));
+    // The frozen denominator must have grown by exactly as much as the late
+    // credit grew the numerator - not diverged from it.
+    assert_eq!(
+        epoch_after.reward_pool.points,
+        p1_contributed + p3_contributed
+    );
+    assert_eq!(
+        epoch_after.reward_pool.points,
+        epoch_before.reward_pool.points + 25_0000000
+    );
+}
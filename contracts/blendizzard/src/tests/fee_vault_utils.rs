@@ -94,6 +94,19 @@ impl MockVault {
         0
     }
 
+    /// Mock get_underlying_balance - configurable per user via `set_balance`,
+    /// defaulting to 0. Lets tests that need `vault::get_vault_balance` to
+    /// see a real balance (e.g. `TestState::deposit_as`) configure one
+    /// without a real fee-vault-v2 deposit.
+    pub fn get_underlying_balance(env: Env, user: Address) -> i128 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+
+    /// Test hook: configure the balance `get_underlying_balance` reports for `user`.
+    pub fn set_balance(env: Env, user: Address, amount: i128) {
+        env.storage().instance().set(&user, &amount);
+    }
+
     /// Mock admin_withdraw - returns the requested amount
     /// This allows epoch cycling tests to simulate BLND withdrawal
     ///
@@ -125,6 +138,24 @@ pub fn create_mock_vault(env: &Env) -> Address {
     env.register(MockVault, ())
 }
 
+impl crate::vault_backend::VaultBackend for MockVaultClient<'_> {
+    fn deposit(&self, from: &Address, amount: i128) -> i128 {
+        self.deposit(from, &amount)
+    }
+
+    fn withdraw(&self, from: &Address, amount: i128) -> i128 {
+        self.withdraw(from, &amount)
+    }
+
+    fn admin_withdraw(&self, reserve_token_ids: &soroban_sdk::Vec<u32>) -> i128 {
+        self.claim_emissions(reserve_token_ids, &self.address)
+    }
+
+    fn balance(&self, _user: &Address) -> i128 {
+        self.get_shares(_user)
+    }
+}
+
 // ============================================================================
 // Mock Pool (for real vault)
 // ============================================================================
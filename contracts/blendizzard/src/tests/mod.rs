@@ -0,0 +1,8 @@
+mod comprehensive;
+mod epoch_integration;
+mod fee_vault_utils;
+mod proposals_integration;
+mod security;
+mod smoke;
+mod testutils;
+mod vault_integration;
@@ -1,6 +1,6 @@
 use super::testutils::{create_test_blendizzard, setup_test_env};
 use crate::storage::DataKey;
-use crate::types::{EpochPlayer, EpochPlayerV0, PlayerV1};
+use crate::types::{EpochPlayer, EpochPlayerV0, PlayerV1, StorageStatus};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::Address;
 
@@ -72,6 +72,34 @@ fn test_invalid_faction() {
     client.select_faction(&player, &99);
 }
 
+#[test]
+fn test_max_players_reached() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let client = create_test_blendizzard(&env, &admin);
+
+    client.update_config(
+        &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None, &None,
+        &Some(2u32), &None, &None, &None, &None, &None, &None, &None, &None,
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    client.select_faction(&first, &0);
+    client.select_faction(&second, &1);
+
+    // Cap is reached - a brand-new player is rejected...
+    let result = client.try_select_faction(&third, &0);
+    assert!(result.is_err());
+
+    // ...but an existing player changing faction is still fine, since they
+    // don't count against the cap a second time.
+    client.select_faction(&first, &1);
+    assert_eq!(client.get_player(&first).selected_faction, 1);
+}
+
 // ============================================================================
 // Game Registry Tests
 // ============================================================================
@@ -88,7 +116,7 @@ fn test_add_game() {
     assert!(!client.is_game(&game_contract));
 
     // Add game
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
 
     // Now whitelisted
     assert!(client.is_game(&game_contract));
@@ -103,7 +131,7 @@ fn test_remove_game() {
     let client = create_test_blendizzard(&env, &admin);
 
     // Add game
-    client.add_game(&game_contract);
+    super::testutils::add_game_via_admin(&client, &admin, &game_contract);
     assert!(client.is_game(&game_contract));
 
     // Remove game
@@ -263,3 +291,84 @@ fn test_migration_nonexistent_epoch_player() {
         "Migration should return false for nonexistent epoch player"
     );
 }
+
+// ============================================================================
+// Storage Diagnosis Tests
+// ============================================================================
+
+#[test]
+fn test_diagnose_player_absent() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+
+    let client = create_test_blendizzard(&env, &admin);
+
+    assert_eq!(client.diagnose_player(&player), StorageStatus::Absent);
+}
+
+#[test]
+fn test_diagnose_player_current() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+
+    let client = create_test_blendizzard(&env, &admin);
+    client.select_faction(&player, &0);
+
+    assert_eq!(client.diagnose_player(&player), StorageStatus::Current);
+}
+
+#[test]
+fn test_diagnose_player_needs_migration() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+
+    let client = create_test_blendizzard(&env, &admin);
+
+    // Manually write legacy V1 data with old DataKey::User, same as
+    // test_player_v1_migration, but don't migrate it.
+    let contract_id = client.address.clone();
+    env.as_contract(&contract_id, || {
+        let v1_data = PlayerV1 {
+            selected_faction: 2,
+            deposit_timestamp: 54321,
+            last_epoch_balance: 5000,
+        };
+        let old_key = DataKey::User(player.clone());
+        env.storage().persistent().set(&old_key, &v1_data);
+    });
+
+    assert_eq!(
+        client.diagnose_player(&player),
+        StorageStatus::NeedsMigration(0)
+    );
+}
+
+#[test]
+fn test_diagnose_player_corrupt() {
+    let env = setup_test_env();
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+
+    let client = create_test_blendizzard(&env, &admin);
+    client.select_faction(&player, &0);
+
+    // Tag the now-current entry with a schema version no `PLAYER_STEPS`
+    // entry knows how to read.
+    let contract_id = client.address.clone();
+    env.as_contract(&contract_id, || {
+        let key = DataKey::PlayerSchema(player.clone());
+        env.storage().persistent().set(&key, &99u32);
+    });
+
+    assert_eq!(
+        client.diagnose_player(&player),
+        StorageStatus::Corrupt(99)
+    );
+
+    // Reads that rely on the schema tag now fail closed instead of trapping.
+    let result = client.try_get_player(&player);
+    assert!(result.is_err());
+}
@@ -0,0 +1,502 @@
+#![allow(dead_code)]
+
+/// Shared contract-setup helpers for the test suite.
+///
+/// `setup_test_env`/`create_blendizzard_contract`/`create_blendizzard_with_mock_vault`/
+/// `create_test_blendizzard` are the original ad-hoc helpers each test file used to
+/// hand-roll inline. `TestBuilder`/`TestState` below wrap the same plumbing behind a
+/// single configurable entry point so new tests don't need to repeat it.
+use super::fee_vault_utils::{create_mock_pool, create_mock_vault, MockVaultClient};
+use crate::types::BPS_DENOM;
+use crate::{Blendizzard, BlendizzardClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, Address, Env, Vec};
+
+/// A fresh test `Env` with auths mocked, the way every test in this suite wants it.
+pub fn setup_test_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env
+}
+
+// ============================================================================
+// Mock Router
+// ============================================================================
+
+/// Stands in for the Soroswap router so contract construction and epoch
+/// cycling don't need a real AMM deployed. Only implements the one entrypoint
+/// `router::SoroswapRouterTrait` calls, `swap_exact_tokens_for_tokens`, and
+/// does so as a 1:1 no-op swap - it does not move any tokens, so it is not
+/// sufficient for tests that need a real swap to actually execute. Those
+/// belong with the real Soroswap contracts (see `soroswap_utils`).
+#[contract]
+pub struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        _amount_out_min: i128,
+        path: Vec<Address>,
+        _to: Address,
+        _deadline: u64,
+    ) -> Vec<i128> {
+        vec![&env, amount_in, amount_in * (path.len() as i128 - 1).max(0)]
+    }
+}
+
+/// Create a mock Soroswap router for testing.
+pub fn create_mock_router(env: &Env) -> Address {
+    env.register(MockRouter, ())
+}
+
+// ============================================================================
+// Mock Emitting Vault / Funded Router
+// ============================================================================
+// `create_mock_vault`/`MockRouter` above are zero-emission, no-op-swap
+// stubs - fine for tests that don't care whether `cycle_epoch`'s BLND ->
+// USDC harvest path actually runs. Testing the oracle staleness/slippage
+// behavior in `rewards::withdraw_yield`/`swap_withdrawn_yield` needs a vault
+// that can report a real BLND claim and a router that actually pays out
+// USDC and enforces `amount_out_min` the way a real AMM would, rather than
+// ignoring it - `MockRouter` can't be reused for that without breaking every
+// test that relies on its always-succeeds, moves-nothing behavior.
+
+/// Stands in for fee-vault-v2 for tests that need `claim_emissions` to
+/// report a configurable nonzero amount. Implements this crate's own
+/// narrower `fee_vault_v2::FeeVaultV2Trait` ABI (the only shape
+/// `withdraw_yield` calls), not the real vault's wider interface that
+/// `fee_vault_utils::MockVault` mimics.
+#[contract]
+pub struct MockEmittingVault;
+
+#[contractimpl]
+impl MockEmittingVault {
+    pub fn deposit(_env: Env, _from: Address, amount: i128) -> i128 {
+        amount
+    }
+
+    pub fn withdraw(_env: Env, _from: Address, amount: i128) -> i128 {
+        amount
+    }
+
+    pub fn get_underlying_balance(_env: Env, _user: Address) -> i128 {
+        0
+    }
+
+    /// Returns whatever `set_emission_amount` last configured (0 until then).
+    pub fn claim_emissions(env: Env, _reserve_token_ids: Vec<u32>) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("emit_amt"))
+            .unwrap_or(0)
+    }
+
+    /// Test hook: configure the amount the next `claim_emissions` reports.
+    pub fn set_emission_amount(env: Env, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("emit_amt"), &amount);
+    }
+}
+
+/// Create a mock vault whose `claim_emissions` reports a configurable
+/// amount - see `MockEmittingVault::set_emission_amount`.
+pub fn create_mock_emitting_vault(env: &Env) -> Address {
+    env.register(MockEmittingVault, ())
+}
+
+/// Like `MockRouter`, but mints the USDC leg of the swap to `to` (so a
+/// harvest's `usdc_after - usdc_before` delta is observable) and enforces
+/// `amount_out_min`, reverting the swap if it isn't met.
+#[contract]
+pub struct MockSwapRouter;
+
+#[contractimpl]
+impl MockSwapRouter {
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        _deadline: u64,
+    ) -> Vec<i128> {
+        let bps: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("out_bps"))
+            .unwrap_or(BPS_DENOM);
+        let amount_out = amount_in * bps as i128 / BPS_DENOM as i128;
+        if amount_out < amount_out_min {
+            panic!("MockSwapRouter: amount_out below amount_out_min");
+        }
+
+        let usdc = path.get(path.len() - 1).expect("swap path must not be empty");
+        token::StellarAssetClient::new(&env, &usdc).mint(&to, &amount_out);
+
+        vec![&env, amount_in, amount_out]
+    }
+
+    /// Test hook: set the swap's output ratio in basis points of
+    /// `amount_in` (default `10_000` == 1:1), to simulate a worse rate.
+    pub fn set_output_bps(env: Env, bps: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("out_bps"), &bps);
+    }
+}
+
+/// Create a mock router whose swap actually mints USDC and enforces
+/// `amount_out_min` - see `MockSwapRouter::set_output_bps`.
+pub fn create_mock_swap_router(env: &Env) -> Address {
+    env.register(MockSwapRouter, ())
+}
+
+// ============================================================================
+// Contract Construction
+// ============================================================================
+
+/// Register and construct a Blendizzard contract against whatever
+/// vault/router/token addresses the caller provides.
+pub fn create_blendizzard_contract<'a>(
+    env: &Env,
+    admin: &Address,
+    vault: &Address,
+    router: &Address,
+    blnd: &Address,
+    usdc: &Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+) -> BlendizzardClient<'a> {
+    let contract_id = env.register(
+        Blendizzard,
+        (
+            admin.clone(),
+            vault.clone(),
+            router.clone(),
+            blnd.clone(),
+            usdc.clone(),
+            epoch_duration,
+            reserve_token_ids,
+            100u32, // 1% default max slippage, matching the old hardcoded constructor default
+            vec![env, admin.clone()], // admin is the sole multisig signer by default
+            1u32, // threshold 1 - admin alone can propose+execute
+            0u64, // no timelock delay by default
+            0u32, // no FP-governance quorum required by default
+            5_000u32, // 50% majority required by default
+            604_800u64, // 7-day default voting period
+            0u64, // no FP-governance timelock delay by default
+        ),
+    );
+    BlendizzardClient::new(env, &contract_id)
+}
+
+/// Like `create_blendizzard_contract`, but with `governance_quorum_bps` set
+/// to a caller-chosen value instead of `0` - for tests exercising the FP-
+/// weighted governance quorum check, which `create_blendizzard_contract`'s
+/// hardcoded `0` (no quorum required) can't exercise.
+pub fn create_blendizzard_contract_with_quorum<'a>(
+    env: &Env,
+    admin: &Address,
+    vault: &Address,
+    router: &Address,
+    blnd: &Address,
+    usdc: &Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+    governance_quorum_bps: u32,
+) -> BlendizzardClient<'a> {
+    let contract_id = env.register(
+        Blendizzard,
+        (
+            admin.clone(),
+            vault.clone(),
+            router.clone(),
+            blnd.clone(),
+            usdc.clone(),
+            epoch_duration,
+            reserve_token_ids,
+            100u32,
+            vec![env, admin.clone()],
+            1u32,
+            0u64,
+            governance_quorum_bps,
+            5_000u32,
+            604_800u64,
+            0u64,
+        ),
+    );
+    BlendizzardClient::new(env, &contract_id)
+}
+
+/// Propose and immediately execute `add_game(game)` through the multisig
+/// path, with `signer` as the sole approver - the test-helper equivalent of
+/// the old single-call `client.add_game(game)`, for deployments (the
+/// default) where `signer` alone already meets `admin_threshold` and
+/// `admin_timelock` is `0`.
+pub fn add_game_via_admin(client: &BlendizzardClient, signer: &Address, game: &Address) {
+    let action_hash = client.hash_add_game_action(game);
+    client.propose_action(signer, &action_hash);
+    client.add_game(game, &action_hash);
+}
+
+/// Register a Stellar Asset Contract to stand in for a test token, so tests
+/// can mint balances for it rather than only holding a bare address.
+fn create_test_token(env: &Env, admin: &Address) -> Address {
+    env.register_stellar_asset_contract_v2(admin.clone())
+        .address()
+}
+
+/// Construct a Blendizzard contract wired to a mock vault, mock router, and
+/// mock pool - the common case for tests that exercise game/epoch/faction
+/// logic rather than real fee-vault-v2 behavior.
+pub fn create_blendizzard_with_mock_vault<'a>(env: &Env, admin: &Address) -> BlendizzardClient<'a> {
+    let vault = create_mock_vault(env);
+    let router = create_mock_router(env);
+    let blnd = create_test_token(env, admin);
+    let usdc = create_test_token(env, admin);
+    let reserve_token_ids = vec![env, 0u32];
+
+    create_blendizzard_contract(
+        env,
+        admin,
+        &vault,
+        &router,
+        &blnd,
+        &usdc,
+        100,
+        reserve_token_ids,
+    )
+}
+
+/// Alias for `create_blendizzard_with_mock_vault`, kept separate so smoke
+/// tests that only care about construction succeeding aren't coupled to the
+/// mock-vault name if that ever changes.
+pub fn create_test_blendizzard<'a>(env: &Env, admin: &Address) -> BlendizzardClient<'a> {
+    create_blendizzard_with_mock_vault(env, admin)
+}
+
+// ============================================================================
+// TestBuilder / TestState
+// ============================================================================
+
+/// Configures a Blendizzard deployment before building it. Defaults match
+/// `create_blendizzard_with_mock_vault`'s defaults (100-second epochs, a
+/// single reserve token id); override with `epoch_duration`/`reserve_token_ids`
+/// before calling a `build_with_*` method.
+pub struct TestBuilder<'a> {
+    env: &'a Env,
+    admin: Address,
+    epoch_duration: u64,
+    reserve_token_ids: Vec<u32>,
+    late_deposit_cutoff_bps: Option<u32>,
+    emitting_vault: bool,
+}
+
+impl<'a> TestBuilder<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        let admin = Address::generate(env);
+        TestBuilder {
+            env,
+            admin,
+            epoch_duration: 100,
+            reserve_token_ids: vec![env, 0u32],
+            late_deposit_cutoff_bps: None,
+            emitting_vault: false,
+        }
+    }
+
+    pub fn epoch_duration(mut self, epoch_duration: u64) -> Self {
+        self.epoch_duration = epoch_duration;
+        self
+    }
+
+    pub fn reserve_token_ids(mut self, reserve_token_ids: Vec<u32>) -> Self {
+        self.reserve_token_ids = reserve_token_ids;
+        self
+    }
+
+    /// Override `Config.late_deposit_cutoff_bps` (default leaves it at the
+    /// constructor's default of `10_000`, i.e. the unmodified ramp).
+    pub fn late_deposit_cutoff_bps(mut self, late_deposit_cutoff_bps: u32) -> Self {
+        self.late_deposit_cutoff_bps = Some(late_deposit_cutoff_bps);
+        self
+    }
+
+    /// Swap in `create_mock_emitting_vault`/`create_mock_swap_router` for
+    /// the usual zero-emission, no-op-swap mocks - for tests that need
+    /// `cycle_epoch`'s BLND -> USDC harvest to actually run (oracle
+    /// staleness/slippage behavior).
+    pub fn with_emitting_vault(mut self) -> Self {
+        self.emitting_vault = true;
+        self
+    }
+
+    /// Build against a mock vault/router/pool. This is the only backend
+    /// wired up today; a `build_with_real_vault` counterpart can be added
+    /// the same way once a test needs the real fee-vault-v2 WASM here.
+    pub fn build_with_mock_vault(self) -> TestState<'a> {
+        let env = self.env;
+        let (vault, router) = if self.emitting_vault {
+            (create_mock_emitting_vault(env), create_mock_swap_router(env))
+        } else {
+            (create_mock_vault(env), create_mock_router(env))
+        };
+        let pool = create_mock_pool(env);
+        let blnd = create_test_token(env, &self.admin);
+        let usdc = create_test_token(env, &self.admin);
+
+        let client = create_blendizzard_contract(
+            env,
+            &self.admin,
+            &vault,
+            &router,
+            &blnd,
+            &usdc,
+            self.epoch_duration,
+            self.reserve_token_ids.clone(),
+        );
+
+        if let Some(cutoff_bps) = self.late_deposit_cutoff_bps {
+            client.update_config(
+                &None, &None, &None, &None, &None, &None, &None, &None, &None,
+                &Some(cutoff_bps), &None, &None, &None, &None, &None, &None, &None, &None, &None,
+                &None, &None,
+            );
+        }
+
+        TestState {
+            env,
+            admin: self.admin,
+            client,
+            vault,
+            pool,
+            router,
+            blnd,
+            usdc,
+        }
+    }
+}
+
+/// A built Blendizzard deployment plus the addresses it was wired to, with
+/// typed helpers over the common test actions. `env.mock_all_auths()` (set
+/// by `setup_test_env`) covers the authorization chain for every helper
+/// below, so none of them need to construct auth entries by hand.
+pub struct TestState<'a> {
+    pub env: &'a Env,
+    pub admin: Address,
+    pub client: BlendizzardClient<'a>,
+    pub vault: Address,
+    pub pool: Address,
+    pub router: Address,
+    pub blnd: Address,
+    pub usdc: Address,
+}
+
+impl<'a> TestState<'a> {
+    /// Lock in `player`'s faction for this epoch.
+    pub fn select_faction_as(&self, player: &Address, faction: u32) {
+        self.client.select_faction(player, &faction);
+    }
+
+    /// Record a tracked deposit for `player` via the vault's yield-accumulator
+    /// hook, the same one `client.track_deposit` drives. `vault::record_deposit`
+    /// caps the amount against the player's real mock-vault balance, so this
+    /// tops up that mock balance first - tests don't otherwise have a way to
+    /// simulate a real fee-vault-v2 deposit landing. Calls `record_deposit`
+    /// directly in the contract's storage context rather than through the
+    /// client - it is a white-box helper, not a simulation of a real call.
+    pub fn deposit_as(&self, player: &Address, amount: i128) -> Result<(), crate::errors::Error> {
+        let mock_vault = MockVaultClient::new(self.env, &self.vault);
+        let funded = mock_vault.get_underlying_balance(player) + amount;
+        mock_vault.set_balance(player, &funded);
+
+        self.env.as_contract(&self.client.address, || {
+            crate::vault::record_deposit(self.env, player, amount)
+        })
+    }
+
+    /// Queue a withdrawal for `player` through the live two-phase unbonding
+    /// path (`request_withdraw`). Does not itself advance time to maturity -
+    /// call `advance_epoch` and then `client.withdraw_unbonded` once the
+    /// cooldown has elapsed.
+    pub fn withdraw_as(&self, player: &Address, amount: i128) {
+        self.client.request_withdraw(player, &amount);
+    }
+
+    /// Set the ledger clock to an absolute timestamp, without cycling the
+    /// epoch - for tests that need to land on a specific point inside the
+    /// current epoch (e.g. just before or after a late-deposit cutoff).
+    pub fn set_timestamp(&self, timestamp: u64) {
+        self.env.ledger().with_mut(|li| {
+            li.timestamp = timestamp;
+        });
+    }
+
+    /// Advance the ledger clock by one epoch duration and cycle the epoch,
+    /// crediting any cycle bounty to the admin.
+    pub fn advance_epoch(&self) -> u32 {
+        self.advance_epoch_as(&self.admin.clone())
+    }
+
+    /// Advance the ledger clock by one epoch duration and cycle the epoch,
+    /// crediting the cycle bounty (if any) to `caller` instead of the admin -
+    /// for tests exercising `cycle_epoch`'s keeper-bounty path.
+    pub fn advance_epoch_as(&self, caller: &Address) -> u32 {
+        let duration = self.client.get_config().epoch_duration;
+        self.env.ledger().with_mut(|li| {
+            li.timestamp += duration;
+        });
+        self.client.cycle_epoch(caller)
+    }
+
+    /// Mint BLND to an address. `build_with_mock_vault` registers `blnd` as a
+    /// Stellar Asset Contract specifically so this works out of the box.
+    pub fn mint_blnd(&self, to: &Address, amount: i128) {
+        soroban_sdk::token::StellarAssetClient::new(self.env, &self.blnd).mint(to, &amount);
+    }
+
+    /// Mint USDC to an address - e.g. to fund the contract itself before a
+    /// reward claim, since `claim_reward`/`claim_epoch_reward` transfer real
+    /// USDC out rather than just updating accounting state.
+    pub fn mint_usdc(&self, to: &Address, amount: i128) {
+        soroban_sdk::token::StellarAssetClient::new(self.env, &self.usdc).mint(to, &amount);
+    }
+
+    /// Configure a `TestBuilder::with_emitting_vault` vault's reported BLND
+    /// claim amount for the next `cycle_epoch`.
+    pub fn set_vault_emission(&self, amount: i128) {
+        MockEmittingVaultClient::new(self.env, &self.vault).set_emission_amount(&amount);
+    }
+
+    /// Configure a `TestBuilder::with_emitting_vault` router's swap output
+    /// ratio in basis points of the input amount - see
+    /// `MockSwapRouter::set_output_bps`.
+    pub fn set_router_output_bps(&self, bps: u32) {
+        MockSwapRouterClient::new(self.env, &self.router).set_output_bps(&bps);
+    }
+
+    /// Stamp a reference price directly into storage for `epoch`, bypassing
+    /// the signed `submit_prices` flow - there is no in-test way to produce
+    /// real oracle signatures, so this drives the same storage
+    /// `withdraw_yield` reads, the way `deposit_as` drives
+    /// `vault::record_deposit` directly. Not a simulation of a real
+    /// `submit_prices` call.
+    pub fn set_reference_price_for_epoch(&self, epoch: u32, price: i128) {
+        self.env.as_contract(&self.client.address, || {
+            crate::storage::set_reference_price(self.env, epoch, price);
+        });
+    }
+
+    /// Assert `player`'s total faction-point contribution this epoch equals
+    /// `expected`.
+    pub fn assert_fp(&self, player: &Address, expected: u128) {
+        let epoch_player = self.client.get_epoch_player(player);
+        assert_eq!(
+            epoch_player.total_fp_contributed, expected,
+            "unexpected total_fp_contributed for player"
+        );
+    }
+}
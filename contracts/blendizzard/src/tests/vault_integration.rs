@@ -210,7 +210,7 @@ fn test_deposit_withdraw_with_games() {
     let client = create_blendizzard_with_mock_vault(&env, &admin);
 
     // Setup
-    client.add_game(&game);
+    super::testutils::add_game_via_admin(&client, &admin, &game);
     client.deposit(&user1, &1000_0000000);
     client.deposit(&user2, &1000_0000000);
     client.select_faction(&user1, &0);
@@ -0,0 +1,712 @@
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Fixed-point scalar used by the legacy (pre-bps) withdrawal threshold math.
+pub const SCALAR_7: i128 = 10_000_000;
+
+/// 50% of `initial_epoch_balance`, expressed in `SCALAR_7` fixed point.
+pub const WITHDRAWAL_RESET_THRESHOLD: i128 = 5_000_000;
+
+/// Denominator for all basis-point quantities in this contract (10_000 == 1.0x / 100%).
+pub const BPS_DENOM: u32 = 10_000;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    pub fee_vault: Address,
+    pub soroswap_router: Address,
+    pub blnd_token: Address,
+    pub usdc_token: Address,
+    pub epoch_duration: u64,
+    pub reserve_token_ids: Vec<u32>,
+    /// Authorized oracle ed25519 public keys allowed to sign BLND/USDC
+    /// reference prices via `submit_prices`.
+    pub oracle_keys: Vec<BytesN<32>>,
+    /// Number of distinct registered keys that must sign a price before it
+    /// becomes the epoch's reference price.
+    pub oracle_threshold: u32,
+    /// Maximum allowed slippage (in basis points) between the oracle
+    /// reference price and the Soroswap swap executed during epoch cycling.
+    pub slippage_bps: u32,
+    /// Share (in basis points) of the swapped `reward_pool` paid to whoever
+    /// calls `cycle_epoch`, to keep epoch cycling timely even if the admin
+    /// goes offline - see `epoch::cycle_epoch`. `0` disables the bounty.
+    pub cycle_bounty_bps: u32,
+    /// Minimum number of seconds that must elapse since an epoch started
+    /// before `cycle_epoch` may cycle it, as a floor under `epoch_duration` -
+    /// see `epoch::should_end_epoch`. `0` disables the check (`epoch_duration`
+    /// alone gates cycling).
+    pub min_cycle_interval: u64,
+    /// How long (in seconds) an epoch's oracle reference price stays usable
+    /// after it was set - see `oracle::get_fresh_reference_price`. `0`
+    /// disables the check (a submitted price is usable forever), matching
+    /// this contract's convention of `0` meaning "feature off" (see
+    /// `EmissionConfig::halving_interval_epochs`).
+    pub price_staleness_window: u64,
+    /// Epochs a queued withdrawal must wait before `withdraw_unbonded` can
+    /// release it - see `vault::request_withdraw`.
+    pub unbonding_epochs: u32,
+    /// Minimum amount a player's first tracked deposit must meet - see
+    /// `vault::record_deposit`.
+    pub min_deposit: i128,
+    /// Minimum amount a player's subsequent tracked deposits must meet.
+    /// Typically lower than `min_deposit`, since topping up an existing
+    /// position doesn't carry the same spam-account risk as opening one.
+    pub min_deposit_add: i128,
+    /// Cap on `TotalTrackedDeposits` - a deposit that would push the global
+    /// total above this is rejected.
+    pub max_total_deposited: i128,
+    /// Cap on the number of distinct players who may ever select a faction -
+    /// see `storage::get_total_players`/`faction::select_faction`. `0`
+    /// disables the cap.
+    pub max_players: u32,
+    /// How far into an epoch (in basis points of `epoch_duration`) a deposit
+    /// may still accrue any FP for that epoch - see
+    /// `faction_points::fresh_deposit_weight_bps`. `10_000` (the default)
+    /// matches the unmodified ramp, which already reaches zero weight
+    /// exactly at epoch end; setting this lower hard-zeroes fresh deposits
+    /// earlier, e.g. `9_000` cuts off accrual for the last 10% of the epoch.
+    pub late_deposit_cutoff_bps: u32,
+    /// Maximum allowed deviation (in basis points) between a fresh oracle
+    /// reference price and the BLND/USDC TWAP derived from
+    /// `PriceAccumulator` - see `oracle::check_twap_deviation`. `0` disables
+    /// the check (a fresh price is accepted regardless of TWAP drift).
+    pub max_price_deviation_bps: u32,
+    /// Which `yield_source::YieldSource` adapter `rewards::withdraw_yield`
+    /// constructs from `fee_vault`/`blnd_token` - see `yield_source::make_yield_source`.
+    pub yield_source_kind: YieldSourceKind,
+    /// Which `swap_router::SwapRouter` adapter `rewards::swap_withdrawn_yield`
+    /// constructs from `soroswap_router` - see `swap_router::make_swap_router`.
+    pub swap_router_kind: SwapRouterKind,
+    /// Minimum effective vault balance a player must hold for their games'
+    /// FP to count toward `faction_standings` - see
+    /// `game::credit_player`/`faction_points::standings_weight_bps`. `0`
+    /// disables the gate (and the sqrt weighting it unlocks), so every
+    /// credit counts in full, matching this contract's convention of `0`
+    /// meaning "feature off".
+    pub min_standings_balance: i128,
+    /// Minimum number of an epoch's distinct roster participants (see
+    /// `storage::roster_len`) required before `epoch::cycle_epoch_step` will
+    /// declare a non-default winning faction - below quorum, the epoch still
+    /// finalizes and its reward pool still funds, but with no winner, so the
+    /// pool rolls forward to the next epoch's payout rather than being
+    /// claimed by however few players showed up. `0` disables the check.
+    pub epoch_quorum: u32,
+    /// Number of epochs a winning player's reward share linearly unlocks
+    /// over, once a `VestingEntry` is created for it - see
+    /// `rewards::claim_epoch_reward`. `0` disables vesting, paying a
+    /// player's entire share the moment they first claim it, matching this
+    /// contract's convention of `0` meaning "feature off".
+    pub vesting_epochs: u32,
+    /// Maximum number of entries kept in `leaderboard`'s per-epoch and
+    /// all-time top-N standings - see `leaderboard::record_contribution`.
+    /// `0` disables leaderboard tracking entirely, matching this contract's
+    /// convention of `0` meaning "feature off".
+    pub leaderboard_size: u32,
+    /// How long (in seconds) a freshly-`end_game`'d session stays
+    /// `SessionStatus::Provisional` before `game::finalize_game` may settle
+    /// it - see `game::end_game`/`game::dispute_game`. `0` disables the
+    /// window, settling (and crediting faction standings) immediately as
+    /// this contract did before dispute windows existed, matching this
+    /// contract's convention of `0` meaning "feature off".
+    pub dispute_window_secs: u64,
+    /// Number of a game contract's disputed sessions (see
+    /// `storage::get_game_dispute_count`) that triggers automatic
+    /// `game::slash_game`. `0` disables auto-slashing, matching this
+    /// contract's convention of `0` meaning "feature off".
+    pub game_auto_slash_threshold: u32,
+}
+
+/// Selects which concrete `YieldSource` adapter to construct from `Config` -
+/// see `yield_source::make_yield_source`. Add a variant here (and a matching
+/// adapter in `yield_source.rs`) to support a yield source other than
+/// fee-vault-v2.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YieldSourceKind {
+    FeeVaultV2,
+}
+
+/// Selects which concrete `SwapRouter` adapter to construct from `Config` -
+/// see `swap_router::make_swap_router`. Add a variant here (and a matching
+/// adapter in `swap_router.rs`) to support a DEX other than Soroswap (e.g.
+/// Aquarius or Phoenix).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SwapRouterKind {
+    Soroswap,
+}
+
+/// A running time-weighted cumulative-price observation for the BLND/USDC
+/// pair, in the same style as a constant-product AMM's price accumulator:
+/// `cumulative` is the sum of `last_price * seconds_elapsed` across every
+/// update before this one, so a TWAP over any window since a prior
+/// observation is `(cumulative_now - cumulative_then) / (now - then)`. Used
+/// both for the live accumulator (`DataKey::PriceAccumulator`) and for the
+/// snapshot taken at the last TWAP check (`DataKey::PriceAccumulatorSnapshot`) -
+/// see `oracle::check_twap_deviation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceAccumulator {
+    pub cumulative: i128,
+    pub last_update: u64,
+    pub last_price: i128,
+}
+
+/// Admin-configured emission bonus schedule, added to swapped yield on top
+/// of whatever the vault produced that epoch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionConfig {
+    /// Reward budget per calendar year, before halving, in the reward
+    /// token's smallest unit.
+    pub annual_reward: i128,
+    /// Halve `annual_reward` once per this many epochs that have elapsed.
+    /// `0` disables halving (a flat rate forever).
+    pub halving_interval_epochs: u32,
+}
+
+// ============================================================================
+// Factions
+// ============================================================================
+
+/// Faction identifiers (0=WholeNoodle, 1=PointyStick, 2=SpecialRock)
+pub struct Faction;
+
+impl Faction {
+    pub const WHOLE_NOODLE: u32 = 0;
+    pub const POINTY_STICK: u32 = 1;
+    pub const SPECIAL_ROCK: u32 = 2;
+    pub const COUNT: u32 = 3;
+
+    pub fn is_valid(faction: u32) -> bool {
+        faction < Self::COUNT
+    }
+}
+
+// ============================================================================
+// Player (persistent, cross-epoch)
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Player {
+    pub selected_faction: u32,
+    /// Ledger timestamp the current time-multiplier ramp started from. Reset
+    /// to `now` whenever a large withdrawal triggers the FP reset rule.
+    pub time_multiplier_start: u64,
+    /// Vault balance snapshot as of the end of the previous epoch.
+    pub last_epoch_balance: i128,
+}
+
+/// V0 player schema (pre-Nov 10): `total_deposited` instead of `last_epoch_balance`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerV0 {
+    pub selected_faction: u32,
+    pub deposit_timestamp: u64,
+    pub total_deposited: i128,
+}
+
+/// V1 player schema (Nov 10-12): `deposit_timestamp` instead of `time_multiplier_start`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerV1 {
+    pub selected_faction: u32,
+    pub deposit_timestamp: u64,
+    pub last_epoch_balance: i128,
+}
+
+/// Liveness outcome of checking a persistent entry (`Player` or `Game`)
+/// that may have gone dormant long enough to archive. Reading an archived
+/// entry directly traps rather than returning `None`, so
+/// `storage::restore_player`/`restore_game` check liveness first and
+/// report which of these happened instead of letting the transaction trap.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayerAccess {
+    /// Entry exists and had plenty of TTL remaining.
+    Live,
+    /// Entry exists but was within its archival threshold - its TTL has now
+    /// been renewed.
+    Restored,
+    /// No entry exists for this key.
+    Missing,
+}
+
+/// Fail-closed diagnosis of a player's persistent entry, for front-ends and
+/// the `migrate_player` flow to route around a bad record deterministically
+/// instead of discovering it through a trapped transaction - see
+/// `storage::diagnose_player`/`errors::Error::CorruptStorage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageStatus {
+    /// Entry exists and is tagged at `migrate::PLAYER_CURRENT_VERSION` -
+    /// reads normally without triggering a migration step.
+    Current,
+    /// Entry exists but is tagged below `migrate::PLAYER_CURRENT_VERSION` -
+    /// holds the detected version; `migrate_player` will upgrade it in place.
+    NeedsMigration(u32),
+    /// Entry's schema tag is higher than `migrate::PLAYER_CURRENT_VERSION` -
+    /// outside every layout `migrate` knows how to read. Holds the
+    /// unrecognized tag value itself.
+    Corrupt(u32),
+    /// No entry exists under any known key, current or legacy.
+    Absent,
+}
+
+// ============================================================================
+// EpochPlayer (temporary, per-epoch)
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EpochPlayer {
+    /// Faction locked in for this epoch on the player's first game. `None`
+    /// until then, at which point it is set from `Player.selected_faction`.
+    pub epoch_faction: Option<u32>,
+    pub epoch_balance_snapshot: i128,
+    /// FP available to wager right now (total FP minus whatever is locked in
+    /// active game sessions).
+    pub available_fp: u128,
+    /// Total FP this player has contributed to their faction's standings
+    /// this epoch (wins only - this is what rewards are split by).
+    pub total_fp_contributed: u128,
+}
+
+/// V0 epoch-player schema (pre-Nov 13): tracked `locked_fp` explicitly.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochPlayerV0 {
+    pub epoch_faction: Option<u32>,
+    pub epoch_balance_snapshot: i128,
+    pub available_fp: u128,
+    pub locked_fp: u128,
+    pub total_fp_contributed: u128,
+}
+
+// ============================================================================
+// Epoch
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochInfo {
+    pub epoch_id: u32,
+    pub start_time: u64,
+    pub finalized: bool,
+    pub winning_faction: Option<u32>,
+    /// Total FP contributed to each faction's standings this epoch, indexed
+    /// by faction ID.
+    pub faction_standings: [u128; Faction::COUNT as usize],
+    /// Winning faction's reward round for this epoch, once finalized.
+    pub reward_pool: PointValue,
+}
+
+/// Compact, permanently-archived record of a finalized epoch - written once
+/// by `epoch::cycle_epoch_step` and served back by `get_epoch_summary`, so a
+/// leaderboard/analytics frontend can read an epoch's outcome without
+/// racing `EpochInfo`'s temporary storage TTL or replaying events.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochSummary {
+    pub epoch_number: u32,
+    pub winning_faction: Option<u32>,
+    pub reward_pool: i128,
+    /// Total FP contributed to each faction's standings this epoch, indexed
+    /// by faction ID.
+    pub faction_standings: [u128; Faction::COUNT as usize],
+    /// Count of distinct players who played at least one game this epoch.
+    pub participant_count: u32,
+}
+
+/// A proposed multisig action's current approval/timelock state, served by
+/// `get_action_status` so off-chain callers can tell whether it's ready to
+/// execute without replaying `ActionProposed`/`ActionApproved` events.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ActionStatus {
+    /// Ledger timestamp it was first proposed at, or `0` if nobody has.
+    pub proposed_at: u64,
+    /// Distinct admin signers who have approved it so far.
+    pub approvals: u32,
+    /// Approvals required before it may execute.
+    pub threshold: u32,
+    /// Ledger timestamp it becomes executable at (`proposed_at +
+    /// admin_timelock`), or `0` if `proposed_at` is `0`.
+    pub executable_at: u64,
+    /// Whether it has already been consumed by its gated entrypoint.
+    pub executed: bool,
+}
+
+// ============================================================================
+// FP-Weighted Governance
+// ============================================================================
+
+/// The subset of `update_config`'s fields a `ProposalAction::UpdateConfig`
+/// may change, in the same order and with the same "only provided fields
+/// change" semantics as the admin entrypoint - see `config::apply_update`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdate {
+    pub new_blnd_token: Option<Address>,
+    pub new_usdc_token: Option<Address>,
+    pub new_epoch_duration: Option<u64>,
+    pub new_reserve_token_ids: Option<Vec<u32>>,
+    pub new_slippage_bps: Option<u32>,
+    pub new_unbonding_epochs: Option<u32>,
+    pub new_min_deposit: Option<i128>,
+    pub new_min_deposit_add: Option<i128>,
+    pub new_max_total_deposited: Option<i128>,
+    pub new_late_deposit_cutoff_bps: Option<u32>,
+    pub new_price_staleness_window: Option<u64>,
+    pub new_cycle_bounty_bps: Option<u32>,
+    pub new_max_players: Option<u32>,
+    pub new_min_cycle_interval: Option<u64>,
+    pub new_max_price_deviation_bps: Option<u32>,
+    pub new_min_standings_balance: Option<i128>,
+    pub new_epoch_quorum: Option<u32>,
+    pub new_vesting_epochs: Option<u32>,
+    pub new_leaderboard_size: Option<u32>,
+    pub new_dispute_window_secs: Option<u64>,
+    pub new_game_auto_slash_threshold: Option<u32>,
+}
+
+/// The action a governance `Proposal`, once passed, dispatches through the
+/// same code path the matching admin entrypoint uses - see
+/// `proposals::execute`. `add_game`'s multisig path (see `governance`) is
+/// untouched; this is a second, player-facing way to reach it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    UpdateConfig(ConfigUpdate),
+    AddGame(Address),
+    RemoveGame(Address),
+    Upgrade(BytesN<32>),
+    Pause,
+    Unpause,
+}
+
+/// Where a `Proposal` stands - served by `proposals::get_proposal_status` so
+/// off-chain callers don't have to replicate the quorum/majority math
+/// themselves.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Voting deadline hasn't passed yet.
+    Voting,
+    /// Voting closed with quorum and majority met, timelock not yet elapsed.
+    Passed,
+    /// Voting closed without quorum and/or majority met - can never execute.
+    Failed,
+    /// `proposals::execute` has already dispatched this proposal's action.
+    Executed,
+    /// The admin canceled this proposal via `proposals::cancel`.
+    Canceled,
+}
+
+/// An FP-weighted governance proposal - see `proposals`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub proposer: Address,
+    pub action: ProposalAction,
+    /// Epoch a voter's `EpochPlayer.total_fp_contributed` is read from to
+    /// weight their vote - fixed at proposal creation so voting power can't
+    /// be farmed by playing games after the proposal is already up.
+    pub snapshot_epoch: u32,
+    /// Ledger timestamp voting closes at.
+    pub voting_deadline: u64,
+    /// Ledger timestamp a passed proposal may be executed at
+    /// (`voting_deadline + governance_timelock`).
+    pub earliest_execution_ts: u64,
+    pub yes_votes: u128,
+    pub no_votes: u128,
+    pub executed: bool,
+    pub canceled: bool,
+}
+
+// ============================================================================
+// Resumable Epoch Finalization
+// ============================================================================
+
+/// Where `epoch::cycle_epoch_step` is in closing out an epoch - see
+/// `FinalizationCursor`. Phases that hand something to the next one carry it
+/// as field data (`Swapping`) rather than re-deriving or re-storing it, so a
+/// step can never see a value go stale between transactions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FinalizationPhase {
+    /// Freeze `faction_standings` and pick the winning faction.
+    TallyingFactions,
+    /// Claim yield from the configured `YieldSource` - see `rewards::withdraw_yield`.
+    WithdrawingYield,
+    /// Swap the yield `WithdrawingYield` claimed to USDC, using the reference
+    /// price and amount it recorded - see `rewards::swap_withdrawn_yield`.
+    Swapping { reference_price: i128, yield_claimed: i128 },
+    /// Pay the cycle bounty, fold in emission/dust, freeze the reward pool,
+    /// archive the summary, and open the next epoch.
+    SettingPool { reward_pool_usdc: i128 },
+    /// Finalization is complete; no cursor remains in storage.
+    Done,
+}
+
+/// An in-flight `cycle_epoch_step` finalization - singleton (Instance
+/// storage). Its mere presence is the "finalization in progress" signal
+/// `game::start_game_multi`/`rewards::claim_epoch_reward` guard against with
+/// `Error::EpochFinalizationInProgress`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FinalizationCursor {
+    pub epoch_id: u32,
+    pub phase: FinalizationPhase,
+}
+
+/// One epoch's reward round: `rewards` (USDC pool) split pro-rata across
+/// `points` (total FP of the winning faction).
+///
+/// `claim_epoch_reward` computes each claimant's share as
+/// `(player_fp * rewards) / points`, truncating down; the resulting dust
+/// (at most `points - 1` units) stays in the pool rather than being
+/// distributed, and a running invariant enforces that distributed shares
+/// never exceed `rewards`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PointValue {
+    pub rewards: i128,
+    pub points: u128,
+}
+
+impl PointValue {
+    pub const ZERO: PointValue = PointValue {
+        rewards: 0,
+        points: 0,
+    };
+}
+
+/// Real-time settlement-progress view over a finalized epoch's reward round,
+/// returned by `get_epoch_rewards`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EpochRewards {
+    pub total_reward_pool: i128,
+    pub distributed_amount: i128,
+    pub remaining_amount: i128,
+    pub claimant_count: u32,
+}
+
+// ============================================================================
+// Reward Vesting
+// ============================================================================
+
+/// A player's linear unlock schedule for one epoch's reward share, created
+/// the first time `rewards::claim_epoch_reward` is called against that epoch
+/// while `Config.vesting_epochs` is nonzero - see `rewards::vested_amount`.
+/// `total_amount` and `start_epoch` are fixed at creation; only `withdrawn`
+/// grows, as later calls withdraw newly-unlocked amounts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingEntry {
+    pub total_amount: i128,
+    pub start_epoch: u32,
+    pub vesting_epochs: u32,
+    pub withdrawn: i128,
+}
+
+/// Read-only vesting preview for one player's epoch reward claim, returned
+/// by `rewards::get_vesting`. `vested_amount - withdrawn_amount` is the
+/// amount `claim_epoch_reward` would transfer right now.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VestingStatus {
+    pub total_amount: i128,
+    pub vested_amount: i128,
+    pub withdrawn_amount: i128,
+    pub unvested_amount: i128,
+}
+
+// ============================================================================
+// Leaderboard
+// ============================================================================
+// Entries are `(player, score)`, sorted descending by score and capped at
+// `Config.leaderboard_size` - see `leaderboard::record_contribution`. Plain
+// `Vec<(Address, i128)>` rather than a dedicated `#[contracttype]` struct,
+// since this is exactly what gets returned to callers and there's no extra
+// field to carry alongside it (contrast `EpochRewards`/`VestingStatus`,
+// which bundle several derived fields together).
+
+// ============================================================================
+// Deposit-Weighted Yield Accumulator
+// ============================================================================
+
+/// A player's position in `vault`'s MasterChef-style yield accumulator:
+/// `tracked_balance` recorded via `vault::record_deposit`/`record_withdrawal`,
+/// `reward_debt` to subtract off `tracked_balance * acc_yield_per_token /
+/// SCALAR_7` so already-settled yield isn't paid out twice, and `claimable`
+/// already-settled yield awaiting `vault::claim_yield`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct YieldPosition {
+    pub tracked_balance: i128,
+    pub reward_debt: i128,
+    pub claimable: i128,
+}
+
+impl YieldPosition {
+    pub const ZERO: YieldPosition = YieldPosition {
+        tracked_balance: 0,
+        reward_debt: 0,
+        claimable: 0,
+    };
+}
+
+// ============================================================================
+// Multi-Asset Deposit Routing
+// ============================================================================
+
+/// One registered asset's routing target - `vault` is the fee-vault-v2
+/// deployment that holds deposits of that asset, `pool` is the Blend pool
+/// backing it, queried via `pool::Client::get_reserve` to normalize the
+/// asset's deposits to a common unit in `vault::record_deposit_for_asset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetVaultEntry {
+    pub vault: Address,
+    pub pool: Address,
+}
+
+// ============================================================================
+// Lockup-Tiered Deposits
+// ============================================================================
+
+/// How long a deposit is committed before it can be withdrawn, in exchange
+/// for a boosted reward weight while locked - see
+/// `faction_points::lockup_bonus_bps`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockupKind {
+    /// No commitment - withdrawable any time, no weight bonus.
+    None,
+    /// Unlocks at a fixed timestamp regardless of when it was created.
+    Cliff { release_ts: u64 },
+    /// Unlocks `duration` seconds after it was created.
+    Constant { duration: u64 },
+}
+
+/// A player's current lockup commitment - one active lockup per player
+/// (not per-deposit; a second `vault::record_locked_deposit` call tops up
+/// `amount` and resets `kind`/`created_ts` to the new commitment rather than
+/// tracking multiple independent lockups).
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Lockup {
+    pub kind: LockupKind,
+    pub amount: i128,
+    pub created_ts: u64,
+}
+
+impl Lockup {
+    pub const NONE: Lockup = Lockup {
+        kind: LockupKind::None,
+        amount: 0,
+        created_ts: 0,
+    };
+}
+
+// ============================================================================
+// Games
+// ============================================================================
+
+/// Denominator for payout-share basis points in a `GameOutcome::Split`.
+pub const DENOM: u32 = 10_000;
+
+/// Tri-state result of a 1v1 `GameOutcome::Winner` session.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchResult {
+    Player1,
+    Player2,
+    Draw,
+}
+
+/// Outcome of a finished game session.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    /// 1v1 session. Carries the full session identity so `end_game` can
+    /// verify it against the stored `GameSession` before settling. On
+    /// `Player1`/`Player2` the pot is winner-takes-all and the loser's wager
+    /// is burned, with the winner's wager contributing to their faction's
+    /// standings for the epoch. On `Draw` each player's own wager is
+    /// refunded to their available FP instead - neither side's wager is
+    /// burned, and nothing is credited to faction standings.
+    Winner {
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        result: MatchResult,
+    },
+    /// Multi-participant payout split for free-for-alls and team matches.
+    /// Each entry is `(player, share_bps)`; shares must sum to exactly
+    /// `DENOM` and every player must be a participant of the session.
+    Split(Vec<(Address, u32)>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Pending,
+    /// `end_game` has computed an outcome and recorded `pending_actions`, but
+    /// `config.dispute_window_secs` hasn't elapsed yet - see
+    /// `game::finalize_game`/`game::dispute_game`. Never reached when the
+    /// window is disabled (`0`), which settles straight from `Pending`.
+    Provisional { finalize_after_ts: u64 },
+    Settled,
+    /// `game::dispute_game`/`game::slash_game` discarded this session's
+    /// `pending_actions` before they were ever applied - permanently
+    /// terminal, like `Settled`.
+    Disputed,
+}
+
+/// One effect `end_game` computed but hasn't applied to storage yet, held on
+/// a `Provisional` session until `game::finalize_game` applies it or
+/// `game::dispute_game`/`game::slash_game` discards it instead - see
+/// `game::end_game`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PendingAction {
+    /// Mirrors `game::credit_player`'s effect.
+    Credit { player: Address, amount: i128 },
+    /// Mirrors `game::refund_wager`'s effect.
+    Refund { player: Address, amount: i128 },
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSession {
+    pub game_id: Address,
+    pub epoch: u32,
+    pub players: Vec<Address>,
+    pub wagers: Vec<i128>,
+    pub status: SessionStatus,
+    /// Effects computed by `end_game`, applied by `finalize_game` once
+    /// `Provisional` settles - empty once `Settled`/`Disputed`. Always empty
+    /// when `config.dispute_window_secs` is `0`, since `end_game` applies
+    /// them itself in that case.
+    pub pending_actions: Vec<PendingAction>,
+    /// The `end_game` outcome's winner (mirrors `events::GameEnded.winner`),
+    /// carried on the session so `finalize_game` can emit it once the
+    /// session actually settles rather than when `end_game` merely proposed
+    /// it.
+    pub winner: Option<Address>,
+    /// The `end_game` outcome's `fp_awarded` (mirrors `events::GameEnded.fp_awarded`).
+    pub fp_awarded: i128,
+}
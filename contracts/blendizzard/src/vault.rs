@@ -1,11 +1,23 @@
 use soroban_fixed_point_math::FixedPoint;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{token, Address, Env, Vec};
 
 use crate::errors::Error;
-use crate::events::{emit_deposit, emit_withdraw};
+use crate::events::{
+    emit_deposit, emit_withdraw, emit_withdraw_requested, emit_withdraw_unbonded,
+    emit_yield_claimed, emit_yield_distributed,
+};
 use crate::fee_vault_v2::Client as FeeVaultClient;
+use crate::pool::Client as PoolClient;
 use crate::storage;
-use crate::types::{EpochUser, User, SCALAR_7, WITHDRAWAL_RESET_THRESHOLD};
+use crate::types::{
+    AssetVaultEntry, EpochUser, LockupKind, User, YieldPosition, SCALAR_7,
+    WITHDRAWAL_RESET_THRESHOLD,
+};
+use crate::vault_backend;
+
+/// Max outstanding unbonding chunks per player, bounding how much of
+/// `storage::get_unbonding_queue` any one player can make a caller read.
+const MAX_UNBONDING_CHUNKS: u32 = 10;
 
 // ============================================================================
 // Vault Operations
@@ -216,6 +228,510 @@ fn check_and_handle_withdrawal_reset(
     Ok(reset)
 }
 
+// ============================================================================
+// Unbonding Withdrawals
+// ============================================================================
+// Players deposit/withdraw straight against fee-vault-v2 (see the
+// ARCHITECTURE CHANGE note in `lib.rs`), so a plain synchronous withdrawal
+// bypasses Blendizzard entirely and dodges the FP/time-multiplier
+// consequences of leaving - a player could time an instant withdrawal right
+// at an epoch boundary. `request_withdraw`/`withdraw_unbonded` give players
+// an alternative two-phase path through Blendizzard itself, modeled on
+// nomination-pool unbonding: a request queues the amount rather than
+// releasing it, and it only leaves fee-vault-v2 once `config.unbonding_epochs`
+// have passed.
+
+/// Total amount across a player's queued-but-unclaimed unbonding chunks
+pub(crate) fn outstanding_unbonding_total(env: &Env, player: &Address) -> i128 {
+    storage::get_unbonding_queue(env, player)
+        .iter()
+        .map(|(_, amount)| amount)
+        .sum()
+}
+
+/// A player's fee-vault balance, minus whatever they've already queued for
+/// withdrawal. FP accrual (`faction_points::calculate_faction_points`) reads
+/// this instead of the raw vault balance, so a queued-but-unreleased
+/// withdrawal stops earning the time multiplier the moment it's requested
+/// rather than only once `withdraw_unbonded` actually moves the funds.
+pub(crate) fn get_effective_vault_balance(env: &Env, player: &Address) -> i128 {
+    get_vault_balance(env, player).saturating_sub(outstanding_unbonding_total(env, player))
+}
+
+/// A player's currently-still-locked amount, `0` if they have no lockup or
+/// it has expired (`LockupKind::None` always reads as unlocked).
+pub(crate) fn locked_balance(env: &Env, player: &Address) -> i128 {
+    let lockup = storage::get_lockup(env, player);
+    if lockup.amount <= 0 {
+        return 0;
+    }
+
+    let now = env.ledger().timestamp();
+    let unlocked = match lockup.kind {
+        LockupKind::None => true,
+        LockupKind::Cliff { release_ts } => now >= release_ts,
+        LockupKind::Constant { duration } => now >= lockup.created_ts.saturating_add(duration),
+    };
+
+    if unlocked {
+        0
+    } else {
+        lockup.amount
+    }
+}
+
+/// Queue `amount` of a player's fee-vault balance for withdrawal
+///
+/// Debits the player's effective balance immediately (so FP accrual reflects
+/// the exit right away) but doesn't call fee-vault-v2 yet - the amount sits
+/// as a chunk tagged with the epoch it matures at
+/// (`current_epoch + config.unbonding_epochs`) until `withdraw_unbonded`
+/// releases it.
+///
+/// # Errors
+/// * `InvalidAmount` - If amount is <= 0
+/// * `InsufficientBalance` - If amount exceeds the player's withdrawable
+///   balance (effective balance minus whatever is still locked)
+/// * `DepositLocked` - If amount exceeds the withdrawable balance
+///   specifically because a lockup commitment covers the rest
+/// * `UnbondingQueueFull` - If the player already has `MAX_UNBONDING_CHUNKS`
+///   chunks outstanding
+pub(crate) fn request_withdraw(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    player.require_auth();
+
+    let effective_balance = get_effective_vault_balance(env, player);
+    if amount > effective_balance {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let withdrawable = effective_balance.saturating_sub(locked_balance(env, player));
+    if amount > withdrawable {
+        return Err(Error::DepositLocked);
+    }
+
+    let mut queue = storage::get_unbonding_queue(env, player);
+    if queue.len() >= MAX_UNBONDING_CHUNKS {
+        return Err(Error::UnbondingQueueFull);
+    }
+
+    let config = storage::get_config(env);
+    let unlock_epoch = storage::get_current_epoch(env) + config.unbonding_epochs;
+    queue.push_back((unlock_epoch, amount));
+    storage::set_unbonding_queue(env, player, &queue);
+
+    emit_withdraw_requested(env, player, amount, unlock_epoch);
+
+    Ok(())
+}
+
+/// Release every queued chunk whose unlock epoch has passed
+///
+/// Sums the matured chunks, withdraws that total from fee-vault-v2 in a
+/// single call, and drops them from the queue; unmatured chunks are left in
+/// place for a later call.
+///
+/// # Errors
+/// * `NoMaturedWithdrawals` - If no queued chunk has reached its unlock epoch
+/// * `FeeVaultError` - If the fee-vault withdrawal fails
+///
+/// # Returns
+/// The total amount released
+pub(crate) fn withdraw_unbonded(env: &Env, player: &Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let current_epoch = storage::get_current_epoch(env);
+    let queue = storage::get_unbonding_queue(env, player);
+
+    let mut matured_total: i128 = 0;
+    let mut remaining = Vec::new(env);
+    for (unlock_epoch, amount) in queue.iter() {
+        if unlock_epoch <= current_epoch {
+            matured_total = matured_total.saturating_add(amount);
+        } else {
+            remaining.push_back((unlock_epoch, amount));
+        }
+    }
+
+    if matured_total <= 0 {
+        return Err(Error::NoMaturedWithdrawals);
+    }
+
+    let _underlying_withdrawn = vault_backend::backend(env).withdraw(player, matured_total);
+
+    storage::set_unbonding_queue(env, player, &remaining);
+
+    emit_withdraw_unbonded(env, player, matured_total);
+
+    Ok(matured_total)
+}
+
+// ============================================================================
+// Deposit-Weighted Yield Accumulator
+// ============================================================================
+// A MasterChef-style accumulator, kept independent of the FP/epoch reward
+// system in `rewards.rs` (which splits USDC pro-rata by faction points, not
+// by deposit size). `acc_yield_per_token` only ever grows, scaled by
+// `SCALAR_7`; each player's `YieldPosition.reward_debt` is how much of that
+// growth they've already been credited for, so `settle_yield` only ever pays
+// out the delta since their balance last changed.
+//
+// Players deposit/withdraw straight against fee-vault-v2 (see the
+// ARCHITECTURE CHANGE note in `lib.rs`), so Blendizzard can't observe a
+// deposit landing the way it would if it sat in front of the vault. Instead
+// `record_deposit`/`record_locked_deposit`/`record_deposit_for_asset`/
+// `record_withdrawal` are player-authorized self-reports of a deposit the
+// caller already holds in fee-vault-v2 - `lib.rs`'s `track_deposit`/
+// `lock_deposit`/`track_deposit_asset`/`track_withdrawal` wrap them -
+// capped in `apply_tracked_deposit` against the caller's real effective
+// vault balance so nobody can track more than they actually hold.
+// `distribute_yield`/`claim_yield` are fully real: an admin can credit yield
+// against whatever has been recorded, and a player with a tracked balance can
+// claim their share, in USDC to match how `rewards.rs` pays out.
+
+/// Settle a player's pending yield into `claimable` and roll their
+/// `reward_debt` forward to the current `acc_yield_per_token`.
+fn settle_yield(env: &Env, player: &Address) -> YieldPosition {
+    let acc = storage::get_acc_yield_per_token(env);
+    let mut position = storage::get_yield_position(env, player);
+
+    let accrued = position.tracked_balance * acc / SCALAR_7;
+    let pending = accrued - position.reward_debt;
+    position.claimable = position.claimable.saturating_add(pending);
+    position.reward_debt = accrued;
+
+    storage::set_yield_position(env, player, &position);
+    position
+}
+
+/// Record an increase to `player`'s yield-tracked balance.
+///
+/// `player` must authorize the call - this is a self-reported registration
+/// of a deposit the caller already holds in fee-vault-v2, not a transfer, so
+/// nobody but the player themselves can register it. Settles any pending
+/// yield first so the increase doesn't retroactively earn yield accrued
+/// before it existed. Enforces `config.min_deposit` / `config.min_deposit_add`
+/// (whichever applies depends on whether the player already has a tracked
+/// balance) and `config.max_total_deposited`, in the spirit of nomination
+/// pools' `MinJoinBond`/`MinCreateBond`/`MaxPools`, so spam accounts diluting
+/// faction-point math and unbounded vault exposure are rejected up front.
+///
+/// # Errors
+/// * `InvalidAmount` - If amount is <= 0
+/// * `DepositBelowMinimum` - If amount is below the applicable minimum
+/// * `VaultCapacityExceeded` - If amount would push total tracked deposits
+///   above `config.max_total_deposited`
+/// * `InsufficientBalance` - If amount would track more than the player's
+///   real effective fee-vault balance
+pub(crate) fn record_deposit(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    player.require_auth();
+    apply_tracked_deposit(
+        env,
+        player,
+        amount,
+        get_effective_vault_balance(env, player),
+    )
+}
+
+/// Shared bookkeeping for `record_deposit` and `record_deposit_for_asset`:
+/// settles pending yield, enforces `config.min_deposit` /
+/// `config.min_deposit_add` / `config.max_total_deposited` against `amount`
+/// (already normalized to the common unit by the caller), caps the result
+/// against `balance_cap` so a registration can never claim more than the
+/// player actually holds, and folds it into `player`'s tracked balance.
+///
+/// `balance_cap` is the caller's real balance in whatever vault backs
+/// `amount` - `record_deposit`/`record_locked_deposit` pass the default
+/// vault's `get_effective_vault_balance`, while `record_deposit_for_asset`
+/// already checked `amount` against its own asset vault before normalizing,
+/// so it passes `i128::MAX` here rather than re-checking a normalized amount
+/// against a balance denominated in a different vault entirely.
+///
+/// # Errors
+/// * `DepositBelowMinimum` - If amount is below the applicable minimum
+/// * `VaultCapacityExceeded` - If amount would push total tracked deposits
+///   above `config.max_total_deposited`
+/// * `InsufficientBalance` - If amount would track more than `balance_cap`
+fn apply_tracked_deposit(
+    env: &Env,
+    player: &Address,
+    amount: i128,
+    balance_cap: i128,
+) -> Result<(), Error> {
+    let mut position = settle_yield(env, player);
+
+    let config = storage::get_config(env);
+    let min_required = if position.tracked_balance == 0 {
+        config.min_deposit
+    } else {
+        config.min_deposit_add
+    };
+    if amount < min_required {
+        return Err(Error::DepositBelowMinimum);
+    }
+
+    let total = storage::get_total_tracked_deposits(env)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    if total > config.max_total_deposited {
+        return Err(Error::VaultCapacityExceeded);
+    }
+
+    let new_tracked_balance = position
+        .tracked_balance
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    if new_tracked_balance > balance_cap {
+        return Err(Error::InsufficientBalance);
+    }
+
+    position.tracked_balance = new_tracked_balance;
+    position.reward_debt =
+        position.tracked_balance * storage::get_acc_yield_per_token(env) / SCALAR_7;
+    storage::set_yield_position(env, player, &position);
+    storage::set_total_tracked_deposits(env, total);
+
+    Ok(())
+}
+
+/// Record a deposit of `amount` committed under `kind` (a non-`None`
+/// lockup), on top of the usual tracked-balance bookkeeping `record_deposit`
+/// does. Tops up any existing lockup rather than tracking multiple
+/// independent ones - see `Lockup`'s doc comment. `player` must authorize
+/// the call, same as `record_deposit`.
+///
+/// # Errors
+/// * `InvalidAmount` - If amount is <= 0
+/// * `DepositBelowMinimum` / `VaultCapacityExceeded` / `InsufficientBalance` -
+///   See `apply_tracked_deposit`
+pub(crate) fn record_locked_deposit(
+    env: &Env,
+    player: &Address,
+    amount: i128,
+    kind: LockupKind,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    player.require_auth();
+    apply_tracked_deposit(
+        env,
+        player,
+        amount,
+        get_effective_vault_balance(env, player),
+    )?;
+
+    let mut lockup = storage::get_lockup(env, player);
+    lockup.amount = lockup.amount.saturating_add(amount);
+    lockup.kind = kind;
+    lockup.created_ts = env.ledger().timestamp();
+    storage::set_lockup(env, player, &lockup);
+
+    Ok(())
+}
+
+// ============================================================================
+// Multi-Asset Deposit Routing
+// ============================================================================
+
+/// Register (or replace) the fee-vault/pool pair that deposits of `asset`
+/// route to.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn register_asset_vault(
+    env: &Env,
+    asset: &Address,
+    vault: &Address,
+    pool: &Address,
+) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::set_asset_vault(
+        env,
+        asset,
+        &AssetVaultEntry {
+            vault: vault.clone(),
+            pool: pool.clone(),
+        },
+    );
+    Ok(())
+}
+
+/// Deregister `asset`, rejecting future deposits of it until re-registered.
+///
+/// # Errors
+/// * `NotAdmin` - If caller is not the admin
+pub(crate) fn deregister_asset_vault(env: &Env, asset: &Address) -> Result<(), Error> {
+    let admin = storage::get_admin(env);
+    admin.require_auth();
+
+    storage::remove_asset_vault(env, asset);
+    Ok(())
+}
+
+/// Look up the vault address registered for `asset`.
+pub(crate) fn get_asset_vault(env: &Env, asset: &Address) -> Option<Address> {
+    storage::get_asset_vault(env, asset).map(|entry| entry.vault)
+}
+
+/// Convert `amount` of `asset` into the common unit `TotalTrackedDeposits`
+/// and faction-point math are denominated in, via `pool`'s reserve scalar -
+/// `normalized = amount * SCALAR_7 / reserve.scalar`, so a reserve with
+/// fewer decimals than `SCALAR_7` counts for proportionally more per raw
+/// unit and vice versa.
+fn normalize_to_common_unit(
+    env: &Env,
+    pool: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    let reserve = PoolClient::new(env, pool).get_reserve(asset);
+    amount
+        .fixed_mul_floor(SCALAR_7, reserve.scalar)
+        .ok_or(Error::OverflowError)
+}
+
+/// Record a deposit of `asset` (rather than the vault's default asset),
+/// routing it to whatever fee-vault/pool pair is registered for it and
+/// normalizing the amount to the common unit before folding it into the
+/// same tracked-balance ledger `record_deposit` uses - so a player's
+/// deposits across assets contribute comparably to faction points. `player`
+/// must authorize the call, same as `record_deposit`; `amount` is capped
+/// against the player's real balance in `asset`'s own registered vault
+/// (rather than the default vault `apply_tracked_deposit` otherwise checks
+/// against, which holds a different asset entirely).
+///
+/// # Errors
+/// * `InvalidAmount` - If amount is <= 0
+/// * `UnsupportedAsset` - If `asset` has no registered vault/pool
+/// * `InsufficientBalance` - If amount exceeds the player's real balance in
+///   `asset`'s registered vault
+/// * `DepositBelowMinimum` / `VaultCapacityExceeded` - See `apply_tracked_deposit`
+pub(crate) fn record_deposit_for_asset(
+    env: &Env,
+    player: &Address,
+    asset: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    player.require_auth();
+
+    let entry = storage::get_asset_vault(env, asset).ok_or(Error::UnsupportedAsset)?;
+    if amount > FeeVaultClient::new(env, &entry.vault).get_underlying_balance(player) {
+        return Err(Error::InsufficientBalance);
+    }
+    let normalized = normalize_to_common_unit(env, &entry.pool, asset, amount)?;
+
+    apply_tracked_deposit(env, player, normalized, i128::MAX)?;
+
+    let asset_total = storage::get_asset_total_deposited(env, asset)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    storage::set_asset_total_deposited(env, asset, asset_total);
+
+    Ok(())
+}
+
+/// Record a decrease to `player`'s yield-tracked balance.
+///
+/// `player` must authorize the call. Settles any pending yield first so the
+/// player keeps what they've already earned on the departing balance.
+pub(crate) fn record_withdrawal(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    player.require_auth();
+
+    let mut position = settle_yield(env, player);
+    if amount > position.tracked_balance {
+        return Err(Error::InsufficientBalance);
+    }
+    position.tracked_balance -= amount;
+    position.reward_debt =
+        position.tracked_balance * storage::get_acc_yield_per_token(env) / SCALAR_7;
+    storage::set_yield_position(env, player, &position);
+
+    let total = storage::get_total_tracked_deposits(env).saturating_sub(amount);
+    storage::set_total_tracked_deposits(env, total);
+
+    Ok(())
+}
+
+/// Credit `amount` of yield across every yield-tracked deposit, proportional
+/// to each player's `tracked_balance`.
+///
+/// If nothing is currently tracked (`total_tracked_deposits == 0`), there's
+/// no balance to divide by - `amount` is parked in a carry and folded into
+/// the next call that sees a nonzero total, rather than being silently
+/// dropped or causing a division by zero.
+///
+/// # Errors
+/// * `InvalidAmount` - If amount is <= 0
+pub(crate) fn distribute_yield(env: &Env, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let total = storage::get_total_tracked_deposits(env);
+    if total == 0 {
+        let carry = storage::get_pending_yield_carry(env)
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+        storage::set_pending_yield_carry(env, carry);
+        return Ok(());
+    }
+
+    let carried = storage::take_pending_yield_carry(env);
+    let total_amount = amount.checked_add(carried).ok_or(Error::OverflowError)?;
+
+    let acc = storage::get_acc_yield_per_token(env);
+    let acc = acc
+        .checked_add(total_amount * SCALAR_7 / total)
+        .ok_or(Error::OverflowError)?;
+    storage::set_acc_yield_per_token(env, acc);
+
+    emit_yield_distributed(env, total_amount);
+
+    Ok(())
+}
+
+/// Pay out a player's settled yield and zero their `claimable` balance.
+///
+/// Denominated in USDC, matching how `rewards.rs`'s epoch rewards pay out.
+///
+/// # Errors
+/// * `NoRewardsAvailable` - If the player has nothing claimable
+pub(crate) fn claim_yield(env: &Env, player: &Address) -> Result<i128, Error> {
+    player.require_auth();
+
+    let mut position = settle_yield(env, player);
+    let amount = position.claimable;
+    if amount <= 0 {
+        return Err(Error::NoRewardsAvailable);
+    }
+
+    position.claimable = 0;
+    storage::set_yield_position(env, player, &position);
+
+    let config = storage::get_config(env);
+    let usdc_client = token::Client::new(env, &config.usdc_token);
+    usdc_client.transfer(&env.current_contract_address(), player, &amount);
+
+    emit_yield_claimed(env, player, amount);
+
+    Ok(amount)
+}
+
 // ============================================================================
 // Query Functions
 // ============================================================================
@@ -235,3 +751,13 @@ pub(crate) fn get_deposit_timestamp(env: &Env, user: &Address) -> u64 {
         .map(|u| u.deposit_timestamp)
         .unwrap_or(0)
 }
+
+/// Get a player's current underlying balance directly from fee-vault-v2.
+///
+/// Players deposit/withdraw straight against fee-vault-v2 now (see the
+/// ARCHITECTURE CHANGE note in `lib.rs`), so Blendizzard no longer tracks
+/// balances itself - it queries the vault on demand whenever FP needs to be
+/// computed.
+pub(crate) fn get_vault_balance(env: &Env, player: &Address) -> i128 {
+    vault_backend::backend(env).balance(player)
+}
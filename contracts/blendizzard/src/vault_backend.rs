@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::Error;
+use crate::fee_vault_v2::Client as FeeVaultClient;
+use crate::storage;
+use crate::vault;
+
+// ============================================================================
+// Vault Backend
+// ============================================================================
+// `vault.rs`'s real entrypoints (`request_withdraw`, `withdraw_unbonded`,
+// `get_vault_balance`) go through this trait instead of constructing
+// `FeeVaultClient::new` inline, so Blendizzard can target a different Blend
+// pool - or eventually a non-Blend vault - by changing only `backend()`
+// below. `deposit`/`withdraw`/`admin_withdraw`/`balance` move funds against
+// whichever contract backs the vault; `debit`/`credit` never call the
+// backend at all - following the separation a Centrifuge investment
+// accountant draws between ledger bookkeeping and transfer, they only touch
+// the yield accumulator's own tracked balances
+// (`vault::record_withdrawal`/`record_deposit`), so they're the same for
+// every backend and ship as default methods. `lib.rs`'s `track_deposit`/
+// `track_withdrawal` entrypoints go through `credit`/`debit` rather than
+// calling `vault::record_deposit`/`record_withdrawal` directly, keeping this
+// trait the single seam between "move real funds" and "update bookkeeping"
+// even though today both halves happen to settle against the same backend.
+
+pub(crate) trait VaultBackend {
+    /// Deposit `amount` on behalf of `from`, returning shares/units credited.
+    fn deposit(&self, from: &Address, amount: i128) -> i128;
+
+    /// Withdraw `amount` on behalf of `from`, returning the underlying
+    /// amount actually released.
+    fn withdraw(&self, from: &Address, amount: i128) -> i128;
+
+    /// Claim the vault's accrued admin-side yield for `reserve_token_ids`,
+    /// returning the amount claimed.
+    fn admin_withdraw(&self, reserve_token_ids: &Vec<u32>) -> i128;
+
+    /// Current underlying-denominated balance for `user`.
+    fn balance(&self, user: &Address) -> i128;
+
+    /// Debit `amount` from `from`'s yield-tracked balance - bookkeeping
+    /// only, never reaches the backend itself. See `vault::record_withdrawal`.
+    fn debit(&self, env: &Env, from: &Address, amount: i128) -> Result<(), Error> {
+        vault::record_withdrawal(env, from, amount)
+    }
+
+    /// Credit `amount` to `to`'s yield-tracked balance - bookkeeping only,
+    /// never reaches the backend itself. See `vault::record_deposit`.
+    fn credit(&self, env: &Env, to: &Address, amount: i128) -> Result<(), Error> {
+        vault::record_deposit(env, to, amount)
+    }
+}
+
+impl VaultBackend for FeeVaultClient<'_> {
+    fn deposit(&self, from: &Address, amount: i128) -> i128 {
+        self.deposit(from, &amount)
+    }
+
+    fn withdraw(&self, from: &Address, amount: i128) -> i128 {
+        self.withdraw(from, &amount)
+    }
+
+    fn admin_withdraw(&self, reserve_token_ids: &Vec<u32>) -> i128 {
+        self.claim_emissions(reserve_token_ids)
+    }
+
+    fn balance(&self, user: &Address) -> i128 {
+        self.get_underlying_balance(user)
+    }
+}
+
+/// Construct the configured vault backend - the single place `vault.rs`
+/// reaches for fee-vault-v2, so a future alternate backend is a one-function
+/// change rather than a call-site-by-call-site rewrite.
+pub(crate) fn backend(env: &Env) -> impl VaultBackend + '_ {
+    let config = storage::get_config(env);
+    FeeVaultClient::new(env, &config.fee_vault)
+}
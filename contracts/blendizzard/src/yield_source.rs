@@ -0,0 +1,82 @@
+//! Pluggable yield-source abstraction for epoch harvesting.
+//!
+//! `rewards::withdraw_yield` used to call `fee_vault_v2::Client`
+//! directly, hardcoding fee-vault-v2 as the only place epoch yield could ever
+//! come from. `YieldSource` decouples that: harvesting only ever calls
+//! through the trait, and a future non-Blend vault just needs its own
+//! adapter here plus a new `YieldSourceKind` variant (see
+//! `types::YieldSourceKind`) - no change to `rewards`/`epoch` itself. Adapter
+//! instances are dispatched through `YieldSourceAdapter` (a plain enum match,
+//! not a heap-allocated trait object - this crate is `no_std` with no
+//! allocator available for one).
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::fee_vault_v2;
+use crate::types::YieldSourceKind;
+
+/// A source of claimable on-chain yield, in whatever token it natively pays
+/// out (BLND, for fee-vault-v2's emissions).
+pub(crate) trait YieldSource {
+    /// Claim whatever yield is currently available, returning the amount
+    /// claimed in `underlying_token()`'s units.
+    fn claim_yield(&self, env: &Env) -> i128;
+
+    /// The token `claim_yield` pays out in.
+    fn underlying_token(&self) -> Address;
+}
+
+/// `YieldSource` adapter over fee-vault-v2's BLND emissions.
+pub(crate) struct FeeVaultYieldSource {
+    vault: Address,
+    reserve_token_ids: Vec<u32>,
+    blnd_token: Address,
+}
+
+impl YieldSource for FeeVaultYieldSource {
+    fn claim_yield(&self, env: &Env) -> i128 {
+        let client = fee_vault_v2::Client::new(env, &self.vault);
+        client.claim_emissions(&self.reserve_token_ids)
+    }
+
+    fn underlying_token(&self) -> Address {
+        self.blnd_token.clone()
+    }
+}
+
+/// Dispatches to whichever concrete `YieldSource` adapter `Config.yield_source_kind`
+/// selects - see `make_yield_source`.
+pub(crate) enum YieldSourceAdapter {
+    FeeVaultV2(FeeVaultYieldSource),
+}
+
+impl YieldSource for YieldSourceAdapter {
+    fn claim_yield(&self, env: &Env) -> i128 {
+        match self {
+            YieldSourceAdapter::FeeVaultV2(inner) => inner.claim_yield(env),
+        }
+    }
+
+    fn underlying_token(&self) -> Address {
+        match self {
+            YieldSourceAdapter::FeeVaultV2(inner) => inner.underlying_token(),
+        }
+    }
+}
+
+/// Construct the `YieldSource` adapter `kind` selects, wired to `vault`'s
+/// address and the reserve token IDs/BLND token this epoch's harvest needs.
+pub(crate) fn make_yield_source(
+    kind: &YieldSourceKind,
+    vault: Address,
+    reserve_token_ids: Vec<u32>,
+    blnd_token: Address,
+) -> YieldSourceAdapter {
+    match kind {
+        YieldSourceKind::FeeVaultV2 => YieldSourceAdapter::FeeVaultV2(FeeVaultYieldSource {
+            vault,
+            reserve_token_ids,
+            blnd_token,
+        }),
+    }
+}
@@ -3,7 +3,11 @@
 //! # Number Guess Game
 //!
 //! A simple two-player guessing game where players guess a number between 1 and 10.
-//! The player whose guess is closest to the randomly generated number wins.
+//! The player whose guess is closest to the target number wins. Guesses go
+//! through a commit-reveal flow (`commit_guess` then `reveal_guess`) so
+//! neither player can see the other's guess before both have locked one in,
+//! and the target number itself is derived only after both reveal, from
+//! their combined salts - so it can't be known or biased ahead of time.
 //!
 //! **Blendizzard Integration:**
 //! This game is Blendizzard-aware and enforces all games to be played through the
@@ -11,9 +15,19 @@
 
 use soroban_sdk::{
     contract, contractclient, contracterror, contractevent, contractimpl, contracttype, Address,
-    Bytes, BytesN, Env,
+    Bytes, BytesN, Env, Vec,
 };
 
+/// Derives a commit-reveal `Game`'s guess commitment: `sha256(guess_byte ||
+/// salt_bytes)`. `commit_guess` stores the client-computed digest;
+/// `reveal_guess` recomputes it here from the revealed guess/salt and checks
+/// it matches before trusting either value.
+fn guess_commitment(env: &Env, guess: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut bytes = Bytes::from_array(env, &[guess as u8]);
+    bytes.append(&Bytes::from_array(env, &salt.to_array()));
+    env.crypto().sha256(&bytes).into()
+}
+
 // Import Blendizzard contract interface
 // This allows us to call into the Blendizzard contract
 #[contractclient(name = "BlendizzardClient")]
@@ -31,15 +45,29 @@ pub trait Blendizzard {
     fn end_game(env: Env, game_id: Address, session_id: u32, proof: Bytes, outcome: GameOutcome);
 }
 
-// GameOutcome must match Blendizzard's definition
+/// Tri-state result of a session - must match Blendizzard's `MatchResult`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchResult {
+    Player1,
+    Player2,
+    Draw,
+}
+
+/// Must match Blendizzard's `GameOutcome` - only the `Winner` variant is
+/// reproduced here since that's the only one this game ever constructs;
+/// Blendizzard decodes by variant name, so the two definitions don't need to
+/// be otherwise identical.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct GameOutcome {
-    pub game_id: Address,
-    pub session_id: u32,
-    pub player1: Address,
-    pub player2: Address,
-    pub winner: bool,
+pub enum GameOutcome {
+    Winner {
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        result: MatchResult,
+    },
 }
 
 // ============================================================================
@@ -59,6 +87,15 @@ pub enum Error {
     NotInitialized = 7,
     AlreadyInitialized = 8,
     NotAdmin = 9,
+    /// `reveal_guess`'s `sha256(guess || salt)` didn't match the stored commitment.
+    BadReveal = 10,
+    /// `reveal_guess` called before both players have `commit_guess`'d.
+    BothPlayersNotCommitted = 11,
+    /// `claim_timeout` called before `GUESS_TIMEOUT_LEDGERS` has elapsed
+    /// since `start_game`.
+    TimeoutNotReached = 12,
+    /// `start_game` called with `rounds = Some(0)`.
+    InvalidRounds = 13,
 }
 
 // ============================================================================
@@ -72,6 +109,12 @@ pub struct GameStartedEvent {
     pub player2: Address,
 }
 
+#[contractevent]
+pub struct GuessCommittedEvent {
+    pub game_id: u32,
+    pub player: Address,
+}
+
 #[contractevent]
 pub struct GuessMadeEvent {
     pub game_id: u32,
@@ -86,6 +129,48 @@ pub struct WinnerRevealedEvent {
     pub winning_number: u32,
 }
 
+#[contractevent]
+pub struct GameDrawEvent {
+    pub game_id: u32,
+    pub winning_number: u32,
+}
+
+#[contractevent]
+pub struct RoundCompletedEvent {
+    pub game_id: u32,
+    pub round: u32,
+    /// The round's winner, or `None` if the round itself was a tie - either
+    /// way, the match continues unless this round also clinched the series.
+    pub winner: Option<Address>,
+    pub winning_number: u32,
+}
+
+#[contractevent]
+pub struct LeaderboardUpdatedEvent {
+    pub player: Address,
+    pub net_fp_won: i128,
+}
+
+#[contractevent]
+pub struct GameTimedOutEvent {
+    pub game_id: u32,
+    /// The player who did submit a guess, awarded this round's forfeit win -
+    /// `None` if neither player did (or both did, but nobody called
+    /// `reveal_winner` to derive a fair winning number). Published every
+    /// time `claim_timeout` forfeits a round, whether or not that round also
+    /// clinches (or exhausts) the series.
+    pub winner: Option<Address>,
+    /// Whether this round's forfeit also decided (or exhausted) the whole
+    /// best-of-`rounds` series, i.e. `match_winner` reflects the match's
+    /// final result rather than just this round's - so a listener doesn't
+    /// have to separately poll `get_game` to tell a mid-series forfeit
+    /// apart from the match actually ending.
+    pub match_over: bool,
+    /// The match's overall winner once `match_over` is `true` - `None` both
+    /// before that (unset) and for an overall draw.
+    pub match_winner: Option<Address>,
+}
+
 // ============================================================================
 // Data Types
 // ============================================================================
@@ -95,6 +180,9 @@ pub struct WinnerRevealedEvent {
 pub enum GameStatus {
     Active,
     Ended,
+    /// Both players revealed equidistant guesses - each player's own wager
+    /// is refunded by Blendizzard rather than one side taking the pot.
+    Draw,
 }
 
 #[contracttype]
@@ -105,11 +193,68 @@ pub struct Game {
     pub player2: Address,
     pub player1_wager: i128,
     pub player2_wager: i128,
+    /// `sha256(guess_byte || salt_bytes)` submitted by each player via
+    /// `commit_guess`, before either guess is known to the other player or
+    /// to the contract itself.
+    pub commitment1: Option<BytesN<32>>,
+    pub commitment2: Option<BytesN<32>>,
+    /// Guesses, filled in by `reveal_guess` once it's verified the revealed
+    /// value against the matching commitment above.
     pub guess1: Option<u32>,
     pub guess2: Option<u32>,
-    pub winning_number: u32,
+    /// Salts revealed alongside each guess above - combined in `reveal_guess`
+    /// to derive `winning_number` once both are known.
+    pub revealed_salt1: Option<BytesN<32>>,
+    pub revealed_salt2: Option<BytesN<32>>,
+    /// Set once both players have revealed, derived from their combined
+    /// revealed salts - see `reveal_guess`. `None` beforehand, so neither
+    /// the contract nor a player can learn or bias it ahead of time.
+    pub winning_number: Option<u32>,
     pub status: GameStatus,
     pub winner: Option<Address>,
+    /// Ledger sequence the *current round* started at - `claim_timeout`
+    /// uses this to tell whether `GUESS_TIMEOUT_LEDGERS` has elapsed since
+    /// then. Set at `start_game`, then reset every time `reveal_winner`/
+    /// `claim_timeout` advance to a new round without deciding the match, so
+    /// each round gets its own fresh timeout window instead of inheriting
+    /// whatever ledgers already elapsed in earlier rounds.
+    pub start_ledger: u32,
+    /// Total rounds in this best-of-N series. The match is decided once
+    /// either player's `wins1`/`wins2` reaches `rounds / 2 + 1`, or as an
+    /// overall draw if all `rounds` are played without either reaching it.
+    pub rounds: u32,
+    /// The round currently being played, starting at 1.
+    pub current_round: u32,
+    pub wins1: u32,
+    pub wins2: u32,
+}
+
+/// A player's all-time match record, accumulated in `record_match_result` at
+/// settlement - unlike `Game`, this survives a `Game`'s temporary-storage
+/// TTL expiry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub total_fp_wagered: i128,
+    /// Net FP gained across all settled games - a win adds the opponent's
+    /// wager, a loss subtracts the player's own, a draw is unchanged (its
+    /// wager is refunded rather than won or lost).
+    pub net_fp_won: i128,
+}
+
+impl PlayerStats {
+    pub const ZERO: PlayerStats = PlayerStats {
+        games_played: 0,
+        wins: 0,
+        losses: 0,
+        draws: 0,
+        total_fp_wagered: 0,
+        net_fp_won: 0,
+    };
 }
 
 #[contracttype]
@@ -119,6 +264,10 @@ pub enum DataKey {
     GameCounter,
     BlendizzardAddress,
     Admin,
+    PlayerStats(Address),
+    /// `(player, net_fp_won)` pairs, sorted by `net_fp_won` descending and
+    /// capped at `LEADERBOARD_CAP` entries - see `update_leaderboard`.
+    Leaderboard,
 }
 
 // ============================================================================
@@ -131,6 +280,20 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// TTL for per-player stats and the leaderboard, refreshed on every write -
+/// much longer-lived than a single `Game`'s record, so a year is generous.
+/// 365 days = 365 * 24 * 60 * 60 / 5 = 6,307,200 ledgers
+const STATS_TTL_LEDGERS: u32 = 6_307_200;
+
+/// Cap on `DataKey::Leaderboard`'s entries - the lowest-ranked entry is
+/// evicted once a new or promoted player would push the list past this.
+const LEADERBOARD_CAP: u32 = 100;
+
+/// Ledgers a game may sit without both players revealing before
+/// `claim_timeout` can forfeit-settle it (7 days in ledgers, ~5 seconds per
+/// ledger). 7 days = 7 * 24 * 60 * 60 / 5 = 120,960 ledgers
+const GUESS_TIMEOUT_LEDGERS: u32 = 120_960;
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -170,6 +333,9 @@ impl NumberGuessContract {
     /// * `player2` - Address of second player
     /// * `player1_wager` - FP amount player1 is wagering
     /// * `player2_wager` - FP amount player2 is wagering
+    /// * `rounds` - Best-of-N series length; `None` plays a single round,
+    ///   matching this method's original behavior. The match settles once a
+    ///   player reaches `rounds / 2 + 1` round wins.
     ///
     /// # Returns
     /// * `u32` - The game ID
@@ -180,7 +346,13 @@ impl NumberGuessContract {
         player2: Address,
         player1_wager: i128,
         player2_wager: i128,
+        rounds: Option<u32>,
     ) -> Result<u32, Error> {
+        let rounds = rounds.unwrap_or(1);
+        if rounds == 0 {
+            return Err(Error::InvalidRounds);
+        }
+
         // Require authentication from both players (they consent to wagering FP)
         player1.require_auth();
         player2.require_auth();
@@ -198,9 +370,6 @@ impl NumberGuessContract {
         // Get next game ID
         let game_id = Self::get_next_game_id(&env);
 
-        // Generate random number between 1 and 10 using PRNG
-        let winning_number = env.prng().gen_range::<u64>(1..=10) as u32;
-
         // Call Blendizzard to start the session and lock FP
         // This requires THIS contract's authorization (env.current_contract_address())
         blendizzard.start_game(
@@ -219,11 +388,20 @@ impl NumberGuessContract {
             player2: player2.clone(),
             player1_wager,
             player2_wager,
+            commitment1: None,
+            commitment2: None,
             guess1: None,
             guess2: None,
-            winning_number,
+            revealed_salt1: None,
+            revealed_salt2: None,
+            winning_number: None,
             status: GameStatus::Active,
             winner: None,
+            start_ledger: env.ledger().sequence(),
+            rounds,
+            current_round: 1,
+            wins1: 0,
+            wins2: 0,
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -246,14 +424,86 @@ impl NumberGuessContract {
         Ok(game_id)
     }
 
-    /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Commit to a guess for the current game without revealing it.
+    ///
+    /// `commitment` must be `sha256(guess_byte || salt_bytes)`, computed
+    /// client-side over the guess (1-10, as a single byte) and a random
+    /// 32-byte salt the player keeps secret until `reveal_guess`. Storing
+    /// only the commitment means neither the other player nor anyone
+    /// reading `get_game` can learn a guess before both players commit.
     ///
     /// # Arguments
     /// * `game_id` - The ID of the game
-    /// * `player` - Address of the player making the guess
+    /// * `player` - Address of the committing player
+    /// * `commitment` - `sha256(guess_byte || salt_bytes)`
+    pub fn commit_guess(
+        env: Env,
+        game_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(game_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is active - a settled match (decisive or drawn) rejects
+        // further commits/reveals.
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Store commitment for the appropriate player
+        if player == game.player1 {
+            if game.commitment1.is_some() {
+                return Err(Error::AlreadyGuessed);
+            }
+            game.commitment1 = Some(commitment);
+        } else if player == game.player2 {
+            if game.commitment2.is_some() {
+                return Err(Error::AlreadyGuessed);
+            }
+            game.commitment2 = Some(commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        // Store updated game in temporary storage
+        env.storage().temporary().set(&key, &game);
+
+        // Emit event
+        GuessCommittedEvent { game_id, player }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Reveal a previously committed guess.
+    ///
+    /// Only callable once both players have `commit_guess`'d. Recomputes
+    /// `sha256(guess || salt)` and checks it matches the stored commitment
+    /// (`Error::BadReveal` otherwise) before trusting `guess`. Once both
+    /// players have revealed, derives `winning_number` from
+    /// `sha256(salt1 || salt2) % 10 + 1` - combined entropy neither player
+    /// controlled alone, since each committed before seeing the other's
+    /// salt.
+    ///
+    /// # Arguments
+    /// * `game_id` - The ID of the game
+    /// * `player` - Address of the revealing player
     /// * `guess` - The guessed number (1-10)
-    pub fn make_guess(env: Env, game_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+    /// * `salt` - The salt used in the player's `commit_guess` call
+    pub fn reveal_guess(
+        env: Env,
+        game_id: u32,
+        player: Address,
+        guess: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Validate guess is in range
@@ -269,24 +519,50 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is active
-        if game.status == GameStatus::Ended {
+        // Check game is active - a settled match (decisive or drawn) rejects
+        // further commits/reveals.
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update guess for the appropriate player
-        if player == game.player1 {
+        // Both players must have committed before either can reveal
+        let commitment1 = game.commitment1.clone().ok_or(Error::BothPlayersNotCommitted)?;
+        let commitment2 = game.commitment2.clone().ok_or(Error::BothPlayersNotCommitted)?;
+
+        let expected = if player == game.player1 {
             if game.guess1.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.guess1 = Some(guess);
+            &commitment1
         } else if player == game.player2 {
             if game.guess2.is_some() {
                 return Err(Error::AlreadyGuessed);
             }
-            game.guess2 = Some(guess);
+            &commitment2
         } else {
             return Err(Error::NotPlayer);
+        };
+
+        if guess_commitment(&env, guess, &salt) != *expected {
+            return Err(Error::BadReveal);
+        }
+
+        if player == game.player1 {
+            game.guess1 = Some(guess);
+            game.revealed_salt1 = Some(salt);
+        } else {
+            game.guess2 = Some(guess);
+            game.revealed_salt2 = Some(salt);
+        }
+
+        // Once both players have revealed, derive winning_number from their
+        // combined salts - neither player knew the other's salt at commit time.
+        if let (Some(salt1), Some(salt2)) = (&game.revealed_salt1, &game.revealed_salt2) {
+            let mut combined = Bytes::from_array(&env, &salt1.to_array());
+            combined.append(&Bytes::from_array(&env, &salt2.to_array()));
+            let digest: BytesN<32> = env.crypto().sha256(&combined).into();
+            let digest_u64 = u64::from_be_bytes(digest.to_array()[0..8].try_into().unwrap());
+            game.winning_number = Some((digest_u64 % 10) as u32 + 1);
         }
 
         // Store updated game in temporary storage
@@ -303,16 +579,26 @@ impl NumberGuessContract {
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to Blendizzard.
-    /// Can only be called after both players have made their guesses.
-    /// This ends the Blendizzard session, unlocks FP, and updates faction standings.
+    /// Reveal the winner of the current round.
+    ///
+    /// If this round doesn't yet clinch the best-of-`rounds` series (neither
+    /// player has reached `rounds / 2 + 1` round wins and rounds remain),
+    /// this resets the commit-reveal state for the next round, emits
+    /// `RoundCompletedEvent`, leaves the game `Active`, and returns `None` -
+    /// the wagered FP stays locked in Blendizzard for the whole match. Once
+    /// the series is decided (or exhausted without a majority), this settles
+    /// with Blendizzard exactly once; on a genuine draw (either an
+    /// equal-distance final round, or an exhausted series with no majority),
+    /// Blendizzard refunds each player's own wager instead of crediting a
+    /// winner.
     ///
     /// # Arguments
     /// * `game_id` - The ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, game_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - The match winner, or `None` if the series isn't
+    ///   decided yet or ended in an overall draw
+    pub fn reveal_winner(env: Env, game_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(game_id);
         let mut game: Game = env
@@ -321,40 +607,100 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is active
-        if game.status == GameStatus::Ended {
-            return Ok(game.winner.unwrap());
+        // Already settled - return the stored result without re-submitting.
+        if game.status != GameStatus::Active {
+            return Ok(game.winner.clone());
         }
 
-        // Check both players have guessed
+        // Check both players have revealed - winning_number is only set once
+        // both have, so checking it covers both at once.
         let guess1 = game.guess1.ok_or(Error::BothPlayersNotGuessed)?;
         let guess2 = game.guess2.ok_or(Error::BothPlayersNotGuessed)?;
+        let winning_number = game.winning_number.ok_or(Error::BothPlayersNotGuessed)?;
 
         // Calculate distances
-        let distance1 = if guess1 > game.winning_number {
-            guess1 - game.winning_number
+        let distance1 = if guess1 > winning_number {
+            guess1 - winning_number
         } else {
-            game.winning_number - guess1
+            winning_number - guess1
         };
 
-        let distance2 = if guess2 > game.winning_number {
-            guess2 - game.winning_number
+        let distance2 = if guess2 > winning_number {
+            guess2 - winning_number
         } else {
-            game.winning_number - guess2
+            winning_number - guess2
         };
 
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
-            game.player1.clone()
+        // Determine this round's outcome - equal distances are a genuine tie
+        // rather than an automatic win for player1, and don't count toward
+        // either player's series win total.
+        let round_winner = if distance1 == distance2 {
+            None
+        } else if distance1 < distance2 {
+            Some(game.player1.clone())
         } else {
-            game.player2.clone()
+            Some(game.player2.clone())
         };
 
-        // Update game status
-        game.status = GameStatus::Ended;
-        game.winner = Some(winner.clone());
+        if let Some(w) = &round_winner {
+            if *w == game.player1 {
+                game.wins1 += 1;
+            } else {
+                game.wins2 += 1;
+            }
+        }
+
+        RoundCompletedEvent {
+            game_id,
+            round: game.current_round,
+            winner: round_winner,
+            winning_number,
+        }
+        .publish(&env);
+
+        let majority = game.rounds / 2 + 1;
+        let series_decided = game.wins1 >= majority || game.wins2 >= majority;
+        let rounds_exhausted = game.current_round >= game.rounds;
+
+        if !series_decided && !rounds_exhausted {
+            // Series continues - reset this round's commit-reveal state and
+            // move on. The match (and its locked FP) stays open. Also reset
+            // start_ledger so the new round gets its own fresh
+            // GUESS_TIMEOUT_LEDGERS window, rather than claim_timeout seeing
+            // a stale deadline already elapsed from earlier rounds.
+            game.current_round += 1;
+            game.commitment1 = None;
+            game.commitment2 = None;
+            game.guess1 = None;
+            game.guess2 = None;
+            game.revealed_salt1 = None;
+            game.revealed_salt2 = None;
+            game.winning_number = None;
+            game.start_ledger = env.ledger().sequence();
+            env.storage().temporary().set(&key, &game);
+            return Ok(None);
+        }
+
+        // The series is decided (or exhausted without a majority) - settle
+        // with Blendizzard exactly once for the whole match.
+        let single_round = game.rounds == 1;
+        let final_salt1 = game.revealed_salt1.clone();
+        let final_salt2 = game.revealed_salt2.clone();
+
+        let (status, winner, result) = if game.wins1 >= majority {
+            (GameStatus::Ended, Some(game.player1.clone()), MatchResult::Player1)
+        } else if game.wins2 >= majority {
+            (GameStatus::Ended, Some(game.player2.clone()), MatchResult::Player2)
+        } else {
+            (GameStatus::Draw, None, MatchResult::Draw)
+        };
+
+        game.status = status;
+        game.winner = winner.clone();
         env.storage().temporary().set(&key, &game);
 
+        Self::record_match_result(&env, &game, &result);
+
         // Get Blendizzard address
         let blendizzard_addr: Address = env
             .storage()
@@ -366,19 +712,39 @@ impl NumberGuessContract {
         let blendizzard = BlendizzardClient::new(&env, &blendizzard_addr);
 
         // Create game outcome for Blendizzard
-        let outcome = GameOutcome {
+        let outcome = GameOutcome::Winner {
             game_id: env.current_contract_address(),
             session_id: game.session_id,
             player1: game.player1.clone(),
             player2: game.player2.clone(),
-            winner: winner == game.player1, // true if player1 won
+            result,
         };
 
-        // Empty proof (MVP phase - verification handled client-side)
-        let proof = Bytes::new(&env);
+        // Proof Blendizzard independently recomputes this outcome from: the
+        // final round's winning number, both guesses (1 byte each), then
+        // both revealed salts (32 bytes each) - see
+        // `game::verify_number_guess_proof`. That layout only covers a
+        // single round, so it can't attest a best-of-N series' aggregate
+        // win count; multi-round matches settle with an empty proof instead,
+        // the same backward-compatible path other non-number-guess games use.
+        let proof = if single_round {
+            let mut proof =
+                Bytes::from_array(&env, &[winning_number as u8, guess1 as u8, guess2 as u8]);
+            proof.append(&Bytes::from_array(
+                &env,
+                &final_salt1.as_ref().unwrap().to_array(),
+            ));
+            proof.append(&Bytes::from_array(
+                &env,
+                &final_salt2.as_ref().unwrap().to_array(),
+            ));
+            proof
+        } else {
+            Bytes::new(&env)
+        };
 
         // Call Blendizzard to end the session
-        // This unlocks FP and updates faction standings
+        // This unlocks FP and, on a decisive result, updates faction standings
         blendizzard.end_game(
             &env.current_contract_address(),
             &game.session_id,
@@ -387,13 +753,173 @@ impl NumberGuessContract {
         );
 
         // Emit event
-        WinnerRevealedEvent {
+        match &winner {
+            Some(w) => WinnerRevealedEvent {
+                game_id,
+                winner: w.clone(),
+                winning_number,
+            }
+            .publish(&env),
+            None => GameDrawEvent {
+                game_id,
+                winning_number,
+            }
+            .publish(&env),
+        }
+
+        Ok(winner)
+    }
+
+    /// Forfeit-settle the *current round* of a game that's sat without both
+    /// players revealing for longer than `GUESS_TIMEOUT_LEDGERS`, so a
+    /// stalled opponent can't lock a player's wagered FP forever. Whichever
+    /// player did reveal a guess is awarded this round's win; if neither did
+    /// (or both did but nobody called `reveal_winner`), the round is a
+    /// no-decision, the same as a genuine tied round in `reveal_winner`.
+    /// Only once that clinches or exhausts the best-of-`rounds` series (see
+    /// `reveal_winner`) does this settle the whole match with Blendizzard -
+    /// otherwise it resets the round state and leaves the match `Active`,
+    /// letting play continue or a later `claim_timeout` forfeit the next
+    /// round too. Callable by anyone once the timeout has passed - there's
+    /// no stake in who calls it, only in when.
+    ///
+    /// # Arguments
+    /// * `game_id` - The ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The match winner, or `None` if the series isn't
+    ///   decided yet or ended in an overall draw
+    pub fn claim_timeout(env: Env, game_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(game_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Active {
+            return Ok(game.winner.clone());
+        }
+
+        if env.ledger().sequence() <= game.start_ledger + GUESS_TIMEOUT_LEDGERS {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let p1_revealed = game.guess1.is_some();
+        let p2_revealed = game.guess2.is_some();
+
+        // Forfeit only this round to whichever player did reveal - if
+        // neither did, or both did but reveal_winner was never called to
+        // derive a fair winning number, there's no fair way to pick a side
+        // for the round, so it's a no-decision (same as an equal-distance
+        // tie in reveal_winner).
+        let round_winner = if p1_revealed && !p2_revealed {
+            Some(game.player1.clone())
+        } else if p2_revealed && !p1_revealed {
+            Some(game.player2.clone())
+        } else {
+            None
+        };
+
+        if let Some(w) = &round_winner {
+            if *w == game.player1 {
+                game.wins1 += 1;
+            } else {
+                game.wins2 += 1;
+            }
+        }
+
+        let majority = game.rounds / 2 + 1;
+        let series_decided = game.wins1 >= majority || game.wins2 >= majority;
+        let rounds_exhausted = game.current_round >= game.rounds;
+        let match_over = series_decided || rounds_exhausted;
+        let match_winner = if game.wins1 >= majority {
+            Some(game.player1.clone())
+        } else if game.wins2 >= majority {
+            Some(game.player2.clone())
+        } else {
+            None
+        };
+
+        GameTimedOutEvent {
             game_id,
-            winner: winner.clone(),
-            winning_number: game.winning_number,
+            winner: round_winner,
+            match_over,
+            match_winner: if match_over { match_winner } else { None },
         }
         .publish(&env);
 
+        if !series_decided && !rounds_exhausted {
+            // Series continues - reset this round's commit-reveal state and
+            // move on, same as reveal_winner. The match (and its locked FP)
+            // stays open. Also reset start_ledger so the new round gets its
+            // own fresh timeout window instead of one already elapsed from
+            // forfeiting this round.
+            game.current_round += 1;
+            game.commitment1 = None;
+            game.commitment2 = None;
+            game.guess1 = None;
+            game.guess2 = None;
+            game.revealed_salt1 = None;
+            game.revealed_salt2 = None;
+            game.winning_number = None;
+            game.start_ledger = env.ledger().sequence();
+            env.storage().temporary().set(&key, &game);
+            return Ok(None);
+        }
+
+        // The series is decided (or exhausted without a majority) - settle
+        // with Blendizzard exactly once for the whole match.
+        let (status, winner, result) = if game.wins1 >= majority {
+            (
+                GameStatus::Ended,
+                match_winner.clone(),
+                MatchResult::Player1,
+            )
+        } else if game.wins2 >= majority {
+            (
+                GameStatus::Ended,
+                match_winner.clone(),
+                MatchResult::Player2,
+            )
+        } else {
+            (GameStatus::Draw, None, MatchResult::Draw)
+        };
+
+        game.status = status;
+        game.winner = winner.clone();
+        env.storage().temporary().set(&key, &game);
+
+        Self::record_match_result(&env, &game, &result);
+
+        // Get Blendizzard address
+        let blendizzard_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendizzardAddress)
+            .ok_or(Error::NotInitialized)?;
+
+        // Create Blendizzard client
+        let blendizzard = BlendizzardClient::new(&env, &blendizzard_addr);
+
+        // Create game outcome for Blendizzard
+        let outcome = GameOutcome::Winner {
+            game_id: env.current_contract_address(),
+            session_id: game.session_id,
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            result,
+        };
+
+        // A timeout forfeit has no commit-reveal data to prove - Blendizzard
+        // skips verification for an empty proof.
+        blendizzard.end_game(
+            &env.current_contract_address(),
+            &game.session_id,
+            &Bytes::new(&env),
+            &outcome,
+        );
+
         Ok(winner)
     }
 
@@ -412,6 +938,44 @@ impl NumberGuessContract {
             .ok_or(Error::GameNotFound)
     }
 
+    /// Get a player's all-time match record.
+    ///
+    /// # Arguments
+    /// * `player` - The player to look up
+    ///
+    /// # Returns
+    /// * `PlayerStats` - `PlayerStats::ZERO` if the player has never settled a game
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerStats(player))
+            .unwrap_or(PlayerStats::ZERO)
+    }
+
+    /// Get the top players by net FP won.
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of entries to return
+    ///
+    /// # Returns
+    /// * `Vec<(Address, i128)>` - `(player, net_fp_won)` pairs, descending by
+    ///   `net_fp_won`. Only ever contains up to `LEADERBOARD_CAP` players
+    ///   regardless of `limit`.
+    pub fn get_leaderboard(env: Env, limit: u32) -> Vec<(Address, i128)> {
+        let board: Vec<(Address, i128)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Leaderboard)
+            .unwrap_or(Vec::new(&env));
+
+        let take = limit.min(board.len());
+        let mut top = Vec::new(&env);
+        for i in 0..take {
+            top.push_back(board.get_unchecked(i));
+        }
+        top
+    }
+
     // ========================================================================
     // Admin Functions
     // ========================================================================
@@ -447,6 +1011,109 @@ impl NumberGuessContract {
         env.storage().instance().set(&key, &next_id);
         next_id
     }
+
+    /// Update both players' `PlayerStats` and the leaderboard for a settled
+    /// `game` - called once from `reveal_winner`, guarded by its own
+    /// already-settled check so a game is never recorded twice.
+    fn record_match_result(env: &Env, game: &Game, result: &MatchResult) {
+        let (p1_net, p2_net, p1_won, p2_won, is_draw) = match result {
+            MatchResult::Player1 => (game.player2_wager, -game.player2_wager, true, false, false),
+            MatchResult::Player2 => (-game.player1_wager, game.player1_wager, false, true, false),
+            MatchResult::Draw => (0, 0, false, false, true),
+        };
+
+        Self::settle_player_stats(env, &game.player1, game.player1_wager, p1_net, p1_won, is_draw);
+        Self::settle_player_stats(env, &game.player2, game.player2_wager, p2_net, p2_won, is_draw);
+    }
+
+    /// Accumulate one settled game into `player`'s `PlayerStats` and refresh
+    /// their leaderboard position.
+    fn settle_player_stats(
+        env: &Env,
+        player: &Address,
+        wager: i128,
+        net_delta: i128,
+        won: bool,
+        drew: bool,
+    ) {
+        let key = DataKey::PlayerStats(player.clone());
+        let mut stats: PlayerStats = env.storage().persistent().get(&key).unwrap_or(PlayerStats::ZERO);
+
+        stats.games_played += 1;
+        if drew {
+            stats.draws += 1;
+        } else if won {
+            stats.wins += 1;
+        } else {
+            stats.losses += 1;
+        }
+        stats.total_fp_wagered += wager;
+        stats.net_fp_won += net_delta;
+
+        env.storage().persistent().set(&key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+
+        Self::update_leaderboard(env, player, stats.net_fp_won);
+    }
+
+    /// Re-rank `player` on the leaderboard by `net_fp_won`, dropping their
+    /// prior entry first. Only writes (and emits `LeaderboardUpdatedEvent`)
+    /// if the player is already ranked or now beats the lowest-ranked entry -
+    /// otherwise this settlement didn't change the visible top
+    /// `LEADERBOARD_CAP`, so there's nothing to update.
+    fn update_leaderboard(env: &Env, player: &Address, net_fp_won: i128) {
+        let mut board: Vec<(Address, i128)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Leaderboard)
+            .unwrap_or(Vec::new(env));
+
+        for i in 0..board.len() {
+            if board.get_unchecked(i).0 == *player {
+                board.remove(i);
+                break;
+            }
+        }
+
+        let has_room = board.len() < LEADERBOARD_CAP;
+        let beats_last = !has_room && net_fp_won > board.get_unchecked(board.len() - 1).1;
+        if !has_room && !beats_last {
+            env.storage().persistent().set(&DataKey::Leaderboard, &board);
+            env.storage().persistent().extend_ttl(
+                &DataKey::Leaderboard,
+                STATS_TTL_LEDGERS,
+                STATS_TTL_LEDGERS,
+            );
+            return;
+        }
+
+        let mut insert_at = board.len();
+        for i in 0..board.len() {
+            if net_fp_won > board.get_unchecked(i).1 {
+                insert_at = i;
+                break;
+            }
+        }
+        board.insert(insert_at, (player.clone(), net_fp_won));
+        if board.len() > LEADERBOARD_CAP {
+            board.remove(board.len() - 1);
+        }
+
+        env.storage().persistent().set(&DataKey::Leaderboard, &board);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Leaderboard,
+            STATS_TTL_LEDGERS,
+            STATS_TTL_LEDGERS,
+        );
+
+        LeaderboardUpdatedEvent {
+            player: player.clone(),
+            net_fp_won,
+        }
+        .publish(env);
+    }
 }
 
 // ============================================================================